@@ -68,7 +68,7 @@ async fn account() {
 
     let limit_buy = OrderRequest {
         symbol: symbol.to_string(),
-        quantity: Some(0.001),
+        quantity: Some("0.001".parse().unwrap()),
         price: Some(price),
         order_type: OrderType::Limit,
         side: OrderSide::Buy,
@@ -82,7 +82,7 @@ async fn account() {
 
     let market_buy = OrderRequest {
         symbol: symbol.to_string(),
-        quantity: Some(0.001),
+        quantity: Some("0.001".parse().unwrap()),
         order_type: OrderType::Market,
         side: OrderSide::Buy,
         ..OrderRequest::default()
@@ -94,7 +94,7 @@ async fn account() {
 
     let limit_sell = OrderRequest {
         symbol: symbol.to_string(),
-        quantity: Some(0.001),
+        quantity: Some("0.001".parse().unwrap()),
         price: Some(price),
         order_type: OrderType::Limit,
         side: OrderSide::Sell,
@@ -108,7 +108,7 @@ async fn account() {
 
     let market_sell = OrderRequest {
         symbol: symbol.to_string(),
-        quantity: Some(0.001),
+        quantity: Some("0.001".parse().unwrap()),
         order_type: OrderType::Market,
         side: OrderSide::Sell,
         ..OrderRequest::default()
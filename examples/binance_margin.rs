@@ -162,43 +162,42 @@ async fn margin_post() {
 
     eprintln!("----------- Margin POST queries ----------");
     let margin: Margin = Binance::new_with_env(&Config::testnet());
+    let qty: Decimal = "0.001".parse().unwrap();
 
-    let transfer = margin
-        .transfer("BTC", 0.001, MarginTransferType::FromMainToMargin)
-        .await;
+    let transfer = margin.transfer("BTC", qty, MarginTransferType::FromMainToMargin).await;
     eprintln!("transfer = {transfer:?}");
     let isolated_transfer = margin
         .isolated_transfer(
             "BTC",
             "ETH",
-            0.001,
+            qty,
             IsolatedMarginTransferType::Spot,
             IsolatedMarginTransferType::IsolatedMargin,
         )
         .await;
     eprintln!("isolated_transfer = {isolated_transfer:?}");
-    let loan = margin.loan("BTC", 0.001).await;
+    let loan = margin.loan("BTC", qty).await;
     eprintln!("loan = {loan:?}");
     let loan_with_isolation = margin
-        .loan_with_isolation("BTC", 0.001, Some(true), Some("BNB".to_string()))
+        .loan_with_isolation("BTC", qty, Some(true), Some("BNB".to_string()))
         .await;
     eprintln!("loan_with_isolation = {loan_with_isolation:?}");
-    let repay = margin.repay("BTC", 0.001).await;
+    let repay = margin.repay("BTC", qty).await;
     eprintln!("repay = {repay:?}");
     let repay_with_isolation = margin
-        .repay_with_isolation("BTCUSDT", 0.001, Some(true), Some("BNB".to_string()))
+        .repay_with_isolation("BTCUSDT", qty, Some(true), Some("BNB".to_string()))
         .await;
     eprintln!("repay_with_isolation = {repay_with_isolation:?}");
     let margin_order = MarginOrder {
         symbol: "BTCUSDT".to_string(),
         side: OrderSide::Sell,
         order_type: OrderType::Limit,
-        quantity: Some(0.001),
+        quantity: Some("0.001".parse().unwrap()),
         quote_order_qty: None,
-        price: Some(10.0),
-        stop_price: Some(10.0),
+        price: Some("10.0".parse().unwrap()),
+        stop_price: Some("10.0".parse().unwrap()),
         new_client_order_id: Some("my_id".to_string()),
-        iceberg_qty: Some(10.0),
+        iceberg_qty: Some("10.0".parse().unwrap()),
         new_order_resp_type: OrderResponse::Ack,
         time_in_force: Some(TimeInForce::FOK),
         side_effect_type: SideEffectType::NoSideEffect,
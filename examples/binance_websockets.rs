@@ -2,16 +2,17 @@
 extern crate tokio;
 
 use binance::api::*;
+use binance::rest_model::KlineInterval;
 use binance::userstream::*;
 use binance::websockets::*;
 use binance::ws_model::{CombinedStreamEvent, WebsocketEvent, WebsocketEventUntag};
+use binance::ws_transport::Message;
 use futures::future::BoxFuture;
 use futures::stream::StreamExt;
 use serde_json::from_str;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::RwLock;
 use tokio::sync::mpsc::UnboundedSender;
-use tokio_tungstenite::tungstenite::Message;
 
 #[tokio::main]
 async fn main() {
@@ -37,6 +38,7 @@ async fn main() {
         Box::pin(book_ticker(logger_tx.clone())),
         Box::pin(combined_orderbook(logger_tx.clone())),
         Box::pin(custom_event_loop(logger_tx.clone())),
+        Box::pin(stream_websocket(logger_tx.clone())),
         Box::pin(mark_price_websocket(logger_tx)),
     ];
 
@@ -139,6 +141,21 @@ async fn market_websocket(logger_tx: UnboundedSender<WebsocketEvent>) {
     println!("disconnected");
 }
 
+#[allow(dead_code)]
+async fn stream_websocket(logger_tx: UnboundedSender<WebsocketEvent>) {
+    let trades = trade_stream("btcusdt");
+    // Streams compose with the usual combinators, dropping the stream closes the connection
+    let stream: WebsocketStream<WebsocketEvent> = WebsocketStream::connect(&Default::default(), &trades).await.unwrap(); // check error
+    let mut trades = stream.take(10);
+    while let Some(Ok(event)) = trades.next().await {
+        logger_tx.send(event.clone()).unwrap();
+        if let WebsocketEvent::Trade(trade) = event {
+            println!("Symbol: {}, price: {}, qty: {}", trade.symbol, trade.price, trade.qty);
+        }
+    }
+    println!("disconnected");
+}
+
 #[allow(dead_code)]
 async fn all_trades_websocket(logger_tx: UnboundedSender<WebsocketEvent>) {
     let keep_running = AtomicBool::new(true); // Used to control the event loop
@@ -170,7 +187,7 @@ async fn all_trades_websocket(logger_tx: UnboundedSender<WebsocketEvent>) {
 #[allow(dead_code)]
 async fn kline_websocket(logger_tx: UnboundedSender<WebsocketEvent>) {
     let keep_running = AtomicBool::new(true);
-    let kline = kline_stream("ethbtc", "1m");
+    let kline = kline_stream("ethbtc", KlineInterval::OneMinute).unwrap();
     let mut web_socket: WebSockets<'_, WebsocketEvent> = WebSockets::new(|event: WebsocketEvent| {
         logger_tx.send(event.clone()).unwrap();
         if let WebsocketEvent::Kline(kline_event) = event {
@@ -322,17 +339,17 @@ async fn custom_event_loop(logger_tx: UnboundedSender<WebsocketEvent>) {
         });
     web_socket.connect_multiple(streams).await.unwrap(); // check error
     loop {
-        if let Some((ref mut socket, _)) = web_socket.socket {
+        if let Some(ref mut socket) = web_socket.socket {
             if let Ok(message) = socket.next().await.unwrap() {
                 match message {
                     Message::Text(msg) => {
                         if msg.is_empty() {
                             continue;
                         }
-                        let event: CombinedStreamEvent<WebsocketEventUntag> = from_str(msg.as_str()).unwrap();
+                        let event: CombinedStreamEvent<WebsocketEventUntag> = from_str(&msg).unwrap();
                         eprintln!("event = {event:?}");
                     }
-                    Message::Ping(_) | Message::Pong(_) | Message::Binary(_) | Message::Frame(_) => {}
+                    Message::Ping(_) | Message::Pong(_) | Message::Binary(_) => {}
                     Message::Close(e) => {
                         eprintln!("closed stream = {e:?}");
                         break;
@@ -1,7 +1,6 @@
 use crate::client::*;
 use crate::errors::*;
 use crate::rest_model::*;
-use crate::util::*;
 
 static API_V3_ACCOUNT: &str = "/api/v3/account";
 static API_V3_OPEN_ORDERS: &str = "/api/v3/openOrders";
@@ -30,15 +29,15 @@ pub struct OrderRequest {
     #[serde(rename = "type")]
     pub order_type: OrderType,
     pub time_in_force: Option<TimeInForce>,
-    pub quantity: Option<f64>,
-    pub quote_order_qty: Option<f64>,
-    pub price: Option<f64>,
+    pub quantity: Option<Decimal>,
+    pub quote_order_qty: Option<Decimal>,
+    pub price: Option<Decimal>,
     /// A unique id for the order, automatically generated if not sent.
     pub new_client_order_id: Option<String>,
     /// Used with stop loss, stop loss limit, take profit and take profit limit order types.
-    pub stop_price: Option<f64>,
+    pub stop_price: Option<Decimal>,
     /// Used with limit, stop loss limit and take profit limit to create an iceberg order.
-    pub iceberg_qty: Option<f64>,
+    pub iceberg_qty: Option<Decimal>,
     /// Set the response json, market and limit default to full others to ack.
     pub new_order_resp_type: Option<OrderResponse>,
     /// Cannot be greater than 60000
@@ -46,7 +45,7 @@ pub struct OrderRequest {
 }
 
 impl OrderRequest {
-    fn valid(&self) -> Result<()> {
+    pub(crate) fn valid(&self) -> Result<()> {
         if self.iceberg_qty.is_some() && self.time_in_force != Some(TimeInForce::GTC) {
             return Err(Error::InvalidOrderError {
                 msg: "Time in force has to be GTC for iceberg orders".to_string(),
@@ -83,15 +82,15 @@ pub struct CancelReplaceRequest {
     pub order_type: OrderType,
     pub cancel_replace_mode: CancelReplaceMode,
     pub time_in_force: Option<TimeInForce>,
-    pub quantity: Option<f64>,
-    pub quote_order_qty: Option<f64>,
-    pub price: Option<f64>,
+    pub quantity: Option<Decimal>,
+    pub quote_order_qty: Option<Decimal>,
+    pub price: Option<Decimal>,
     pub cancel_new_client_order_id: Option<String>,
     pub cancel_orig_client_order_id: Option<String>,
     pub cancel_order_id: Option<u64>,
     pub new_client_order_id: Option<String>,
-    pub stop_price: Option<f64>,
-    pub iceberg_qty: Option<f64>,
+    pub stop_price: Option<Decimal>,
+    pub iceberg_qty: Option<Decimal>,
     pub new_order_resp_type: Option<OrderResponse>,
     /// Cannot be greater than 60000
     pub recv_window: Option<u64>,
@@ -146,7 +145,7 @@ impl Account {
     /// ```
     pub async fn get_account(&self) -> Result<AccountInformation> {
         // TODO: should parameters be Option<>?
-        let request = build_signed_request([("", "")], self.recv_window)?;
+        let request = self.client.build_signed_request([("", "")], self.recv_window)?;
         self.client.get_signed(API_V3_ACCOUNT, &request).await
     }
 
@@ -189,7 +188,7 @@ impl Account {
         S: AsRef<str>,
     {
         let parameters = [("symbol", symbol.as_ref())];
-        let request = build_signed_request(parameters, self.recv_window)?;
+        let request = self.client.build_signed_request(parameters, self.recv_window)?;
         self.client.get_signed(API_V3_OPEN_ORDERS, &request).await
     }
 
@@ -211,7 +210,7 @@ impl Account {
     /// ```
     pub async fn get_all_orders(&self, query: OrdersQuery) -> Result<Vec<Order>> {
         let recv_window = query.recv_window.unwrap_or(self.recv_window);
-        let request = build_signed_request_p(query, recv_window)?;
+        let request = self.client.build_signed_request_p(query, recv_window)?;
         self.client.get_signed(API_V3_ALL_ORDERS, &request).await
     }
 
@@ -224,7 +223,7 @@ impl Account {
     /// assert!(orders.is_ok(), "{:?}", orders);
     /// ```
     pub async fn get_all_open_orders(&self) -> Result<Vec<Order>> {
-        let request = build_signed_request([("", "")], self.recv_window)?;
+        let request = self.client.build_signed_request([("", "")], self.recv_window)?;
         self.client.get_signed(API_V3_OPEN_ORDERS, &request).await
     }
 
@@ -241,7 +240,7 @@ impl Account {
         S: AsRef<str>,
     {
         let params = [("symbol", symbol.as_ref())];
-        let request = build_signed_request(params, self.recv_window)?;
+        let request = self.client.build_signed_request(params, self.recv_window)?;
         self.client.delete_signed(API_V3_OPEN_ORDERS, &request).await
     }
 
@@ -261,7 +260,7 @@ impl Account {
     /// ```
     pub async fn order_status(&self, osr: OrderStatusRequest) -> Result<Order> {
         let recv_window = osr.recv_window.unwrap_or(self.recv_window);
-        let request = build_signed_request_p(osr, recv_window)?;
+        let request = self.client.build_signed_request_p(osr, recv_window)?;
         self.client.get_signed(API_V3_ORDER, &request).await
     }
 
@@ -283,7 +282,7 @@ impl Account {
     /// ```
     pub async fn test_order_status(&self, osr: OrderStatusRequest) -> Result<TestResponse> {
         let recv_window = osr.recv_window.unwrap_or(self.recv_window);
-        let request = build_signed_request_p(osr, recv_window)?;
+        let request = self.client.build_signed_request_p(osr, recv_window)?;
         self.client.get_signed(API_V3_ORDER_TEST, &request).await
     }
 
@@ -296,8 +295,8 @@ impl Account {
     /// let account: Account = Binance::new_with_env(&Config::testnet());
     /// let limit_buy = OrderRequest {
     ///         symbol: "BTCUSDT".to_string(),
-    ///         quantity: Some(10.0),
-    ///         price: Some(0.014000),
+    ///         quantity: Some("10.0".parse().unwrap()),
+    ///         price: Some("0.014000".parse().unwrap()),
     ///         order_type: OrderType::Limit,
     ///         side: OrderSide::Buy,
     ///         time_in_force: Some(TimeInForce::FOK),
@@ -309,7 +308,7 @@ impl Account {
     pub async fn place_order(&self, order: OrderRequest) -> Result<Transaction> {
         order.valid()?;
         let recv_window = order.recv_window.unwrap_or(self.recv_window);
-        let request = build_signed_request_p(order, recv_window)?;
+        let request = self.client.build_signed_request_p(order, recv_window)?;
         self.client.post_signed(API_V3_ORDER, &request).await
     }
 
@@ -323,8 +322,8 @@ impl Account {
     /// let account: Account = Binance::new_with_env(&Config::testnet());
     /// let limit_buy = OrderRequest {
     ///         symbol: "BTCUSDT".to_string(),
-    ///         quantity: Some(10.0),
-    ///         price: Some(0.014000),
+    ///         quantity: Some("10.0".parse().unwrap()),
+    ///         price: Some("0.014000".parse().unwrap()),
     ///         order_type: OrderType::Limit,
     ///         side: OrderSide::Buy,
     ///         time_in_force: Some(TimeInForce::FOK),
@@ -336,7 +335,7 @@ impl Account {
     pub async fn place_test_order(&self, order: OrderRequest) -> Result<TestResponse> {
         order.valid()?;
         let recv_window = order.recv_window.unwrap_or(self.recv_window);
-        let request = build_signed_request_p(order, recv_window)?;
+        let request = self.client.build_signed_request_p(order, recv_window)?;
         self.client.post_signed(API_V3_ORDER_TEST, &request).await
     }
 
@@ -357,14 +356,14 @@ impl Account {
     /// ```
     pub async fn cancel_order(&self, o: OrderCancellation) -> Result<OrderCanceled> {
         let recv_window = o.recv_window.unwrap_or(self.recv_window);
-        let request = build_signed_request_p(o, recv_window)?;
+        let request = self.client.build_signed_request_p(o, recv_window)?;
         self.client.delete_signed(API_V3_ORDER, &request).await
     }
 
     pub async fn cancel_replace_order(&self, order: CancelReplaceRequest) -> Result<OrderCanceledReplaced> {
         order.valid()?;
         let recv_window = order.recv_window.unwrap_or(self.recv_window);
-        let request = build_signed_request_p(order, recv_window)?;
+        let request = self.client.build_signed_request_p(order, recv_window)?;
         self.client.post_signed(API_V3_CANCEL_REPLACE, &request).await
     }
 
@@ -387,7 +386,7 @@ impl Account {
     /// ```
     pub async fn test_cancel_order(&self, o: OrderCancellation) -> Result<TestResponse> {
         let recv_window = o.recv_window.unwrap_or(self.recv_window);
-        let request = build_signed_request_p(o, recv_window)?;
+        let request = self.client.build_signed_request_p(o, recv_window)?;
         self.client.delete_signed(API_V3_ORDER_TEST, &request).await
     }
 
@@ -404,7 +403,7 @@ impl Account {
         S: AsRef<str>,
     {
         let parameters = [("symbol", symbol.as_ref())];
-        let request = build_signed_request(parameters, self.recv_window)?;
+        let request = self.client.build_signed_request(parameters, self.recv_window)?;
         self.client.get_signed(API_V3_MYTRADES, &request).await
     }
 }
@@ -1,8 +1,13 @@
+use std::future::Future;
+
 use crate::account::*;
 use crate::client::*;
 use crate::config::Config;
+use crate::credentials::Secret;
 use crate::general::*;
 use crate::market::*;
+use crate::signer::SharedSigner;
+use crate::transport::{MetaScope, ReqwestTransport, ResponseMeta, SharedTransport};
 use crate::userstream::*;
 
 pub trait Binance: Sized {
@@ -22,10 +27,136 @@ pub trait Binance: Sized {
     fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Self;
 }
 
+/// Entry point handing out the API handles of a set of credentials
+///
+/// Handles created by the same `BinanceClient` share its connection pool, signer, server clock
+/// synchronization and rate limits, and are cheap to create.
+///
+/// # Examples
+/// ```rust,no_run
+/// use binance::{api::*, config::*, time_sync::TimeSync};
+/// # tokio_test::block_on(async {
+/// let conf = Config::default().set_time_sync(TimeSync::default());
+/// let binance = BinanceClient::new_with_config(Some("api_key".into()), Some("secret_key".into()), &conf);
+/// let price = binance.market().get_price("BTCUSDT").await;
+/// let balances = binance.account().get_account().await;
+/// # });
+/// ```
+#[derive(Clone, Debug)]
+pub struct BinanceClient {
+    api_key: Option<Secret>,
+    config: Config,
+}
+
+impl BinanceClient {
+    /// Client sending the requests of every handle with `client`, instead of a client built from
+    /// the timeout and proxy of the configuration
+    pub fn with_reqwest_client(
+        api_key: Option<String>,
+        secret_key: Option<String>,
+        config: &Config,
+        client: reqwest::Client,
+    ) -> Self {
+        let config = config.clone().set_transport(ReqwestTransport::with_client(client));
+        Self::new_with_config(api_key, secret_key, &config)
+    }
+
+    /// Configuration of the handles, holding the shared transport and signer
+    pub fn config(&self) -> &Config { &self.config }
+
+    /// Handle of any API with the shared state of this client
+    pub fn handle<T: Binance>(&self) -> T {
+        let api_key = self.api_key.as_ref().map(|api_key| api_key.expose().to_string());
+        T::new_with_config(api_key, None, &self.config)
+    }
+
+    pub fn general(&self) -> General { self.handle() }
+
+    pub fn market(&self) -> Market { self.handle() }
+
+    pub fn account(&self) -> Account { self.handle() }
+
+    pub fn user_stream(&self) -> UserStream { self.handle() }
+
+    #[cfg(feature = "savings_api")]
+    pub fn savings(&self) -> crate::savings::Savings { self.handle() }
+
+    #[cfg(feature = "margin_api")]
+    pub fn margin(&self) -> crate::margin::Margin { self.handle() }
+
+    #[cfg(feature = "wallet_api")]
+    pub fn wallet(&self) -> crate::wallet::Wallet { self.handle() }
+
+    #[cfg(feature = "futures_api")]
+    pub fn futures_general(&self) -> crate::futures::general::FuturesGeneral { self.handle() }
+
+    #[cfg(feature = "futures_api")]
+    pub fn futures_market(&self) -> crate::futures::market::FuturesMarket { self.handle() }
+
+    #[cfg(feature = "futures_api")]
+    pub fn futures_account(&self) -> crate::futures::account::FuturesAccount { self.handle() }
+
+    #[cfg(feature = "futures_api")]
+    pub fn futures_user_stream(&self) -> crate::futures::userstream::UserStream { self.handle() }
+
+    #[cfg(feature = "coin_futures_api")]
+    pub fn coin_futures_general(&self) -> crate::coin_futures::general::CoinFuturesGeneral { self.handle() }
+
+    #[cfg(feature = "coin_futures_api")]
+    pub fn coin_futures_market(&self) -> crate::coin_futures::market::CoinFuturesMarket { self.handle() }
+
+    #[cfg(feature = "coin_futures_api")]
+    pub fn coin_futures_account(&self) -> crate::coin_futures::account::CoinFuturesAccount { self.handle() }
+
+    #[cfg(feature = "portfolio_margin_api")]
+    pub fn portfolio_margin_account(&self) -> crate::portfolio_margin::account::PortfolioMarginAccount { self.handle() }
+
+    #[cfg(feature = "options_api")]
+    pub fn options_market(&self) -> crate::options::market::OptionsMarket { self.handle() }
+
+    #[cfg(feature = "options_api")]
+    pub fn options_account(&self) -> crate::options::account::OptionsAccount { self.handle() }
+}
+
+impl Binance for BinanceClient {
+    fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> BinanceClient {
+        let mut config = config.clone();
+        // Built once here, so that every handle reuses them instead of building its own
+        config.transport = Some(SharedTransport::for_config(&config));
+        if config.signer.is_none() {
+            let secret_key = Secret::from(secret_key.unwrap_or_default());
+            config.signer = Some(SharedSigner::for_secret_key(secret_key.expose()));
+        }
+        BinanceClient {
+            api_key: api_key.map(Secret::from),
+            config,
+        }
+    }
+}
+
+/// Run `call`, a request of any API handle, and return its output with the metadata of the last response
+/// it received, None if no response was received
+///
+/// The metadata is attached to the call, concurrent calls of clones of a handle each get their own. The scope
+/// is thread-local and only covers the polls of `call`, requests of tasks spawned inside it are not captured.
+///
+/// # Examples
+/// ```rust,no_run
+/// use binance::{api::*, market::*};
+/// # tokio_test::block_on(async {
+/// let market: Market = Binance::new(None, None);
+/// let (price, meta) = with_meta(market.get_price("BTCUSDT")).await;
+/// if let Some(meta) = meta {
+///     println!("{:?} in {:?}, weight {:?}", meta.status, meta.latency, meta.used_weight.get("1m"));
+/// }
+/// # });
+/// ```
+pub async fn with_meta<F: Future>(call: F) -> (F::Output, Option<ResponseMeta>) { MetaScope::new(call).await }
+
 impl Binance for General {
     fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> General {
         General {
-            client: Client::new(api_key, secret_key, config.rest_api_endpoint.clone(), config.timeout),
+            client: Client::with_config(api_key, secret_key, config.rest_api_endpoint.clone(), config),
         }
     }
 }
@@ -33,7 +164,7 @@ impl Binance for General {
 impl Binance for Account {
     fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Account {
         Account {
-            client: Client::new(api_key, secret_key, config.rest_api_endpoint.clone(), config.timeout),
+            client: Client::with_config(api_key, secret_key, config.rest_api_endpoint.clone(), config),
             recv_window: config.recv_window,
         }
     }
@@ -43,7 +174,7 @@ impl Binance for Account {
 impl Binance for crate::savings::Savings {
     fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Self {
         Self {
-            client: Client::new(api_key, secret_key, config.rest_api_endpoint.clone(), config.timeout),
+            client: Client::with_config(api_key, secret_key, config.rest_api_endpoint.clone(), config),
             recv_window: config.recv_window,
         }
     }
@@ -52,7 +183,7 @@ impl Binance for crate::savings::Savings {
 impl Binance for Market {
     fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Market {
         Market {
-            client: Client::new(api_key, secret_key, config.rest_api_endpoint.clone(), config.timeout),
+            client: Client::with_config(api_key, secret_key, config.rest_api_endpoint.clone(), config),
             recv_window: config.recv_window,
         }
     }
@@ -61,7 +192,7 @@ impl Binance for Market {
 impl Binance for UserStream {
     fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> UserStream {
         UserStream {
-            client: Client::new(api_key, secret_key, config.rest_api_endpoint.clone(), config.timeout),
+            client: Client::with_config(api_key, secret_key, config.rest_api_endpoint.clone(), config),
             recv_window: config.recv_window,
         }
     }
@@ -71,12 +202,7 @@ impl Binance for UserStream {
 impl Binance for crate::futures::general::FuturesGeneral {
     fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Self {
         Self {
-            client: Client::new(
-                api_key,
-                secret_key,
-                config.futures_rest_api_endpoint.clone(),
-                config.timeout,
-            ),
+            client: Client::with_config(api_key, secret_key, config.futures_rest_api_endpoint.clone(), config),
         }
     }
 }
@@ -85,12 +211,7 @@ impl Binance for crate::futures::general::FuturesGeneral {
 impl Binance for crate::futures::market::FuturesMarket {
     fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Self {
         Self {
-            client: Client::new(
-                api_key,
-                secret_key,
-                config.futures_rest_api_endpoint.clone(),
-                config.timeout,
-            ),
+            client: Client::with_config(api_key, secret_key, config.futures_rest_api_endpoint.clone(), config),
             recv_window: config.recv_window,
         }
     }
@@ -100,12 +221,7 @@ impl Binance for crate::futures::market::FuturesMarket {
 impl Binance for crate::futures::account::FuturesAccount {
     fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Self {
         Self {
-            client: Client::new(
-                api_key,
-                secret_key,
-                config.futures_rest_api_endpoint.clone(),
-                config.timeout,
-            ),
+            client: Client::with_config(api_key, secret_key, config.futures_rest_api_endpoint.clone(), config),
             recv_window: config.recv_window,
         }
     }
@@ -115,12 +231,7 @@ impl Binance for crate::futures::account::FuturesAccount {
 impl Binance for crate::futures::userstream::UserStream {
     fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Self {
         Self {
-            client: Client::new(
-                api_key,
-                secret_key,
-                config.futures_rest_api_endpoint.clone(),
-                config.timeout,
-            ),
+            client: Client::with_config(api_key, secret_key, config.futures_rest_api_endpoint.clone(), config),
             recv_window: config.recv_window,
         }
     }
@@ -130,7 +241,7 @@ impl Binance for crate::futures::userstream::UserStream {
 impl Binance for crate::margin::Margin {
     fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Self {
         Self {
-            client: Client::new(api_key, secret_key, config.rest_api_endpoint.clone(), config.timeout),
+            client: Client::with_config(api_key, secret_key, config.rest_api_endpoint.clone(), config),
             recv_window: config.recv_window,
         }
     }
@@ -140,7 +251,7 @@ impl Binance for crate::margin::Margin {
 impl Binance for crate::wallet::Wallet {
     fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Self {
         Self {
-            client: Client::new(api_key, secret_key, config.rest_api_endpoint.clone(), config.timeout),
+            client: Client::with_config(api_key, secret_key, config.rest_api_endpoint.clone(), config),
             recv_window: config.recv_window,
             binance_us_api: config.binance_us_api,
         }
@@ -151,7 +262,12 @@ impl Binance for crate::wallet::Wallet {
 impl Binance for crate::coin_futures::general::CoinFuturesGeneral {
     fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Self {
         Self {
-            client: Client::new(api_key, secret_key, config.coin_futures_rest_api_endpoint.clone(), config.timeout),
+            client: Client::with_config(
+                api_key,
+                secret_key,
+                config.coin_futures_rest_api_endpoint.clone(),
+                config,
+            ),
         }
     }
 }
@@ -160,7 +276,12 @@ impl Binance for crate::coin_futures::general::CoinFuturesGeneral {
 impl Binance for crate::coin_futures::market::CoinFuturesMarket {
     fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Self {
         Self {
-            client: Client::new(api_key, secret_key, config.coin_futures_rest_api_endpoint.clone(), config.timeout),
+            client: Client::with_config(
+                api_key,
+                secret_key,
+                config.coin_futures_rest_api_endpoint.clone(),
+                config,
+            ),
             recv_window: config.recv_window,
         }
     }
@@ -170,7 +291,12 @@ impl Binance for crate::coin_futures::market::CoinFuturesMarket {
 impl Binance for crate::coin_futures::account::CoinFuturesAccount {
     fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Self {
         Self {
-            client: Client::new(api_key, secret_key, config.coin_futures_rest_api_endpoint.clone(), config.timeout),
+            client: Client::with_config(
+                api_key,
+                secret_key,
+                config.coin_futures_rest_api_endpoint.clone(),
+                config,
+            ),
             recv_window: config.recv_window,
         }
     }
@@ -180,7 +306,12 @@ impl Binance for crate::coin_futures::account::CoinFuturesAccount {
 impl Binance for crate::coin_futures::userstream::UserStream {
     fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Self {
         Self {
-            client: Client::new(api_key, secret_key, config.coin_futures_rest_api_endpoint.clone(), config.timeout),
+            client: Client::with_config(
+                api_key,
+                secret_key,
+                config.coin_futures_rest_api_endpoint.clone(),
+                config,
+            ),
             recv_window: config.recv_window,
         }
     }
@@ -190,7 +321,12 @@ impl Binance for crate::coin_futures::userstream::UserStream {
 impl Binance for crate::portfolio_margin::general::PortfolioMarginGeneral {
     fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Self {
         Self {
-            client: Client::new(api_key, secret_key, config.portfolio_margin_rest_api_endpoint.clone(), config.timeout),
+            client: Client::with_config(
+                api_key,
+                secret_key,
+                config.portfolio_margin_rest_api_endpoint.clone(),
+                config,
+            ),
             recv_window: config.recv_window,
         }
     }
@@ -200,7 +336,12 @@ impl Binance for crate::portfolio_margin::general::PortfolioMarginGeneral {
 impl Binance for crate::portfolio_margin::account::PortfolioMarginAccount {
     fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Self {
         Self {
-            client: Client::new(api_key, secret_key, config.portfolio_margin_rest_api_endpoint.clone(), config.timeout),
+            client: Client::with_config(
+                api_key,
+                secret_key,
+                config.portfolio_margin_rest_api_endpoint.clone(),
+                config,
+            ),
             recv_window: config.recv_window,
         }
     }
@@ -210,7 +351,12 @@ impl Binance for crate::portfolio_margin::account::PortfolioMarginAccount {
 impl Binance for crate::portfolio_margin::userstream::UserStream {
     fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Self {
         Self {
-            client: Client::new(api_key, secret_key, config.portfolio_margin_rest_api_endpoint.clone(), config.timeout),
+            client: Client::with_config(
+                api_key,
+                secret_key,
+                config.portfolio_margin_rest_api_endpoint.clone(),
+                config,
+            ),
             recv_window: config.recv_window,
         }
     }
@@ -220,7 +366,7 @@ impl Binance for crate::portfolio_margin::userstream::UserStream {
 impl Binance for crate::options::general::OptionsGeneral {
     fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Self {
         Self {
-            client: Client::new(api_key, secret_key, config.options_rest_api_endpoint.clone(), config.timeout),
+            client: Client::with_config(api_key, secret_key, config.options_rest_api_endpoint.clone(), config),
         }
     }
 }
@@ -229,7 +375,7 @@ impl Binance for crate::options::general::OptionsGeneral {
 impl Binance for crate::options::market::OptionsMarket {
     fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Self {
         Self {
-            client: Client::new(api_key, secret_key, config.options_rest_api_endpoint.clone(), config.timeout),
+            client: Client::with_config(api_key, secret_key, config.options_rest_api_endpoint.clone(), config),
             recv_window: config.recv_window,
         }
     }
@@ -239,7 +385,7 @@ impl Binance for crate::options::market::OptionsMarket {
 impl Binance for crate::options::account::OptionsAccount {
     fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Self {
         Self {
-            client: Client::new(api_key, secret_key, config.options_rest_api_endpoint.clone(), config.timeout),
+            client: Client::with_config(api_key, secret_key, config.options_rest_api_endpoint.clone(), config),
             recv_window: config.recv_window,
         }
     }
@@ -249,7 +395,7 @@ impl Binance for crate::options::account::OptionsAccount {
 impl Binance for crate::options::userstream::UserStream {
     fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Self {
         Self {
-            client: Client::new(api_key, secret_key, config.options_rest_api_endpoint.clone(), config.timeout),
+            client: Client::with_config(api_key, secret_key, config.options_rest_api_endpoint.clone(), config),
             recv_window: config.recv_window,
         }
     }
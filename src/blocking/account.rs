@@ -0,0 +1,25 @@
+//! Blocking [`Account`](crate::account::Account)
+
+use crate::account::*;
+use crate::blocking::blocking_api;
+use crate::errors::*;
+use crate::rest_model::*;
+
+blocking_api! {
+    Account => crate::account::Account {
+        fn get_account(&self) -> Result<AccountInformation>;
+        fn get_balance<S>(&self, asset: S) -> Result<Balance> where S: Into<String>;
+        fn get_open_orders<S>(&self, symbol: S) -> Result<Vec<Order>> where S: AsRef<str>;
+        fn get_all_orders(&self, query: OrdersQuery) -> Result<Vec<Order>>;
+        fn get_all_open_orders(&self) -> Result<Vec<Order>>;
+        fn cancel_all_open_orders<S>(&self, symbol: S) -> Result<Vec<Order>> where S: AsRef<str>;
+        fn order_status(&self, osr: OrderStatusRequest) -> Result<Order>;
+        fn test_order_status(&self, osr: OrderStatusRequest) -> Result<TestResponse>;
+        fn place_order(&self, order: OrderRequest) -> Result<Transaction>;
+        fn place_test_order(&self, order: OrderRequest) -> Result<TestResponse>;
+        fn cancel_order(&self, o: OrderCancellation) -> Result<OrderCanceled>;
+        fn cancel_replace_order(&self, order: CancelReplaceRequest) -> Result<OrderCanceledReplaced>;
+        fn test_cancel_order(&self, o: OrderCancellation) -> Result<TestResponse>;
+        fn trade_history<S>(&self, symbol: S) -> Result<Vec<TradeHistory>> where S: AsRef<str>;
+    }
+}
@@ -0,0 +1,339 @@
+//! Blocking USD-M futures API
+
+pub mod general {
+    use crate::blocking::blocking_api;
+    use crate::errors::*;
+    use crate::futures::rest_model::*;
+    use crate::rest_model::ServerTime;
+
+    blocking_api! {
+        FuturesGeneral => crate::futures::general::FuturesGeneral {
+            fn ping(&self) -> Result<String>;
+            fn get_server_time(&self) -> Result<ServerTime>;
+            fn exchange_info(&self) -> Result<ExchangeInformation>;
+            fn get_symbol_info<S>(&self, symbol: S) -> Result<Symbol> where S: Into<String>;
+        }
+    }
+}
+
+pub mod market {
+    use crate::blocking::blocking_api;
+    use crate::errors::*;
+    use crate::futures::rest_model::*;
+    use crate::rest_model::{BookTickers, KlineSummaries, SymbolPrice, Tickers};
+    use serde_json::Value;
+
+    blocking_api! {
+        FuturesMarket => crate::futures::market::FuturesMarket {
+            fn get_depth<S>(&self, symbol: S) -> Result<OrderBook> where S: Into<String>;
+            fn get_trades<S>(&self, symbol: S) -> Result<Trades> where S: Into<String>;
+            fn get_historical_trades<S1, S2, S3>(&self, symbol: S1, from_id: S2, limit: S3) -> Result<Trades>
+                where S1: Into<String>, S2: Into<Option<u64>>, S3: Into<u16>;
+            fn get_agg_trades<S1, S2, S3, S4, S5>(
+                &self,
+                symbol: S1,
+                from_id: S2,
+                start_time: S3,
+                end_time: S4,
+                limit: S5,
+            ) -> Result<AggTrades>
+            where
+                S1: Into<String>,
+                S2: Into<Option<u64>>,
+                S3: Into<Option<u64>>,
+                S4: Into<Option<u64>>,
+                S5: Into<u16>;
+            fn get_funding_rate<S1, S3, S4, S5>(
+                &self,
+                symbol: S1,
+                start_time: S3,
+                end_time: S4,
+                limit: S5,
+            ) -> Result<Vec<FundingRate>>
+            where
+                S1: Into<String>,
+                S3: Into<Option<u64>>,
+                S4: Into<Option<u64>>,
+                S5: Into<u16>;
+            fn get_open_interest_history<S1, S2, S3, S4, S5>(
+                &self,
+                symbol: S1,
+                period: S2,
+                start_time: S3,
+                end_time: S4,
+                limit: S5,
+            ) -> Result<Vec<OpenInterestHistory>>
+            where
+                S1: Into<String>,
+                S2: TryInto<DataPeriod>,
+                Error: From<S2::Error>,
+                S3: Into<Option<u64>>,
+                S4: Into<Option<u64>>,
+                S5: Into<u16>;
+            fn get_trader_account_long_short_ratio<S1, S2, S3, S4, S5>(
+                &self,
+                symbol: S1,
+                period: S2,
+                start_time: S3,
+                end_time: S4,
+                limit: S5,
+            ) -> Result<Vec<LongShortRatio>>
+            where
+                S1: Into<String>,
+                S2: TryInto<DataPeriod>,
+                Error: From<S2::Error>,
+                S3: Into<Option<u64>>,
+                S4: Into<Option<u64>>,
+                S5: Into<u16>;
+            fn get_trader_position_long_short_ratio<S1, S2, S3, S4, S5>(
+                &self,
+                symbol: S1,
+                period: S2,
+                start_time: S3,
+                end_time: S4,
+                limit: S5,
+            ) -> Result<Vec<LongShortRatio>>
+            where
+                S1: Into<String>,
+                S2: TryInto<DataPeriod>,
+                Error: From<S2::Error>,
+                S3: Into<Option<u64>>,
+                S4: Into<Option<u64>>,
+                S5: Into<u16>;
+            fn get_long_short_ratio<S1, S2, S3, S4, S5>(
+                &self,
+                symbol: S1,
+                period: S2,
+                start_time: S3,
+                end_time: S4,
+                limit: S5,
+            ) -> Result<Vec<LongShortRatio>>
+            where
+                S1: Into<String>,
+                S2: TryInto<DataPeriod>,
+                Error: From<S2::Error>,
+                S3: Into<Option<u64>>,
+                S4: Into<Option<u64>>,
+                S5: Into<u16>;
+            fn get_taker_long_short_ratio<S1, S2, S3, S4, S5>(
+                &self,
+                symbol: S1,
+                period: S2,
+                start_time: S3,
+                end_time: S4,
+                limit: S5,
+            ) -> Result<Vec<LongShortRatio>>
+            where
+                S1: Into<String>,
+                S2: TryInto<DataPeriod>,
+                Error: From<S2::Error>,
+                S3: Into<Option<u64>>,
+                S4: Into<Option<u64>>,
+                S5: Into<u16>;
+            fn get_klines<S1, S2, S3, S4, S5>(
+                &self,
+                symbol: S1,
+                interval: S2,
+                limit: S3,
+                start_time: S4,
+                end_time: S5,
+            ) -> Result<KlineSummaries>
+            where
+                S1: Into<String>,
+                S2: TryInto<KlineInterval>,
+                Error: From<S2::Error>,
+                S3: Into<u16>,
+                S4: Into<Option<u64>>,
+                S5: Into<Option<u64>>;
+            fn get_blvt_klines<S1, S2, S3, S4, S5>(
+                &self,
+                symbol: S1,
+                interval: S2,
+                limit: S3,
+                start_time: S4,
+                end_time: S5,
+            ) -> Result<KlineSummaries>
+            where
+                S1: Into<String>,
+                S2: TryInto<KlineInterval>,
+                Error: From<S2::Error>,
+                S3: Into<u16>,
+                S4: Into<Option<u64>>,
+                S5: Into<Option<u64>>;
+            fn get_blvt_klines_v<S1, S2, S3, S4, S5>(
+                &self,
+                symbol: S1,
+                interval: S2,
+                limit: S3,
+                start_time: S4,
+                end_time: S5,
+            ) -> Result<Vec<Vec<Value>>>
+            where
+                S1: Into<String>,
+                S2: TryInto<KlineInterval>,
+                Error: From<S2::Error>,
+                S3: Into<u16>,
+                S4: Into<Option<u64>>,
+                S5: Into<Option<u64>>;
+            fn get_mark_price_klines<S1, S2, S3, S4, S5>(
+                &self,
+                symbol: S1,
+                interval: S2,
+                limit: S3,
+                start_time: S4,
+                end_time: S5,
+            ) -> Result<KlineSummaries>
+            where
+                S1: Into<String>,
+                S2: TryInto<KlineInterval>,
+                Error: From<S2::Error>,
+                S3: Into<u16>,
+                S4: Into<Option<u64>>,
+                S5: Into<Option<u64>>;
+            fn get_mark_price_klines_v<S1, S2, S3, S4, S5>(
+                &self,
+                symbol: S1,
+                interval: S2,
+                limit: S3,
+                start_time: S4,
+                end_time: S5,
+            ) -> Result<Vec<Vec<Value>>>
+            where
+                S1: Into<String>,
+                S2: TryInto<KlineInterval>,
+                Error: From<S2::Error>,
+                S3: Into<u16>,
+                S4: Into<Option<u64>>,
+                S5: Into<Option<u64>>;
+            fn get_index_price_klines<S1, S2, S3, S4, S5>(
+                &self,
+                symbol: S1,
+                interval: S2,
+                limit: S3,
+                start_time: S4,
+                end_time: S5,
+            ) -> Result<KlineSummaries>
+            where
+                S1: Into<String>,
+                S2: TryInto<KlineInterval>,
+                Error: From<S2::Error>,
+                S3: Into<u16>,
+                S4: Into<Option<u64>>,
+                S5: Into<Option<u64>>;
+            fn get_index_price_klines_v<S1, S2, S3, S4, S5>(
+                &self,
+                symbol: S1,
+                interval: S2,
+                limit: S3,
+                start_time: S4,
+                end_time: S5,
+            ) -> Result<Vec<Vec<Value>>>
+            where
+                S1: Into<String>,
+                S2: TryInto<KlineInterval>,
+                Error: From<S2::Error>,
+                S3: Into<u16>,
+                S4: Into<Option<u64>>,
+                S5: Into<Option<u64>>;
+            fn get_continuous_contract_klines<S1, S2, S3, S4, S5>(
+                &self,
+                symbol: S1,
+                interval: S2,
+                limit: S3,
+                start_time: S4,
+                end_time: S5,
+            ) -> Result<KlineSummaries>
+            where
+                S1: Into<String>,
+                S2: TryInto<KlineInterval>,
+                Error: From<S2::Error>,
+                S3: Into<u16>,
+                S4: Into<Option<u64>>,
+                S5: Into<Option<u64>>;
+            fn get_continuous_contract_klines_v<S1, S2, S3, S4, S5>(
+                &self,
+                symbol: S1,
+                interval: S2,
+                limit: S3,
+                start_time: S4,
+                end_time: S5,
+            ) -> Result<Vec<Vec<Value>>>
+            where
+                S1: Into<String>,
+                S2: TryInto<KlineInterval>,
+                Error: From<S2::Error>,
+                S3: Into<u16>,
+                S4: Into<Option<u64>>,
+                S5: Into<Option<u64>>;
+            fn get_notional_leverage_brackets<S>(&self, symbol: S) -> Result<Vec<SymbolBrackets>> where S: Into<String>;
+            fn get_index_info<S>(&self, symbol: Option<S>) -> Result<PriceStats> where S: Into<String>;
+            fn get_24h_price_stats<S>(&self, symbol: S) -> Result<PriceStats> where S: Into<String>;
+            fn get_all_24h_price_stats(&self) -> Result<Vec<PriceStats>>;
+            fn get_price<S>(&self, symbol: S) -> Result<SymbolPrice> where S: Into<String>;
+            fn get_all_book_tickers(&self) -> Result<BookTickers>;
+            fn get_book_ticker<S>(&self, symbol: S) -> Result<Tickers> where S: Into<String>;
+            fn get_mark_prices(&self, symbol: Option<String>) -> Result<Vec<MarkPrice>>;
+            fn get_all_liquidation_orders(&self) -> Result<LiquidationOrders>;
+            fn open_interest<S>(&self, symbol: S) -> Result<OpenInterest> where S: Into<String>;
+        }
+    }
+}
+
+pub mod account {
+    use crate::account::OrderCancellation;
+    use crate::blocking::blocking_api;
+    use crate::errors::*;
+    use crate::futures::account::*;
+    use crate::futures::rest_model::{AccountBalance, AccountInformation, CanceledOrder, ChangeLeverageResponse, Order,
+                                     Position, Transaction};
+    use crate::rest_model::{Decimal, TimeInForce};
+
+    blocking_api! {
+        FuturesAccount => crate::futures::account::FuturesAccount {
+            fn get_order(&self, order: Option<GetOrderRequest>) -> Result<Transaction>;
+            fn place_order(&self, order: OrderRequest) -> Result<Transaction>;
+            fn get_open_orders(&self, symbol: impl Into<String>) -> Result<Vec<Order>>;
+            fn place_order_test(&self, order: OrderRequest) -> Result<Transaction>;
+            fn limit_buy(
+                &self,
+                symbol: impl Into<String>,
+                qty: impl Into<Decimal>,
+                price: Decimal,
+                time_in_force: TimeInForce,
+            ) -> Result<Transaction>;
+            fn limit_sell(
+                &self,
+                symbol: impl Into<String>,
+                qty: impl Into<Decimal>,
+                price: Decimal,
+                time_in_force: TimeInForce,
+            ) -> Result<Transaction>;
+            fn market_buy<S, F>(&self, symbol: S, qty: F) -> Result<Transaction>
+                where S: Into<String>, F: Into<Decimal>;
+            fn market_sell<S, F>(&self, symbol: S, qty: F) -> Result<Transaction>
+                where S: Into<String>, F: Into<Decimal>;
+            fn cancel_order(&self, o: OrderCancellation) -> Result<CanceledOrder>;
+            fn position_information<S>(&self, symbol: S) -> Result<Vec<Position>> where S: Into<String>;
+            fn account_information(&self) -> Result<AccountInformation>;
+            fn account_balance(&self) -> Result<Vec<AccountBalance>>;
+            fn change_initial_leverage<S>(&self, symbol: S, leverage: u8) -> Result<ChangeLeverageResponse>
+                where S: Into<String>;
+            fn change_position_mode(&self, dual_side_position: bool) -> Result<()>;
+            fn cancel_all_open_orders<S>(&self, symbol: S) -> Result<()> where S: Into<String>;
+        }
+    }
+}
+
+pub mod userstream {
+    use crate::blocking::blocking_api;
+    use crate::errors::*;
+    use crate::rest_model::*;
+
+    blocking_api! {
+        UserStream => crate::futures::userstream::UserStream {
+            fn start(&self) -> Result<UserDataStream>;
+            fn keep_alive(&self, listen_key: &str) -> Result<Success>;
+            fn close(&self, listen_key: &str) -> Result<Success>;
+        }
+    }
+}
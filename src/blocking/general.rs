@@ -0,0 +1,13 @@
+//! Blocking [`General`](crate::general::General)
+
+use crate::blocking::blocking_api;
+use crate::errors::*;
+use crate::rest_model::*;
+
+blocking_api! {
+    General => crate::general::General {
+        fn ping(&self) -> Result<&'static str>;
+        fn get_server_time(&self) -> Result<ServerTime>;
+        fn exchange_info(&self) -> Result<ExchangeInformation>;
+    }
+}
@@ -0,0 +1,114 @@
+//! Blocking [`Margin`](crate::margin::Margin)
+
+use crate::blocking::blocking_api;
+use crate::errors::*;
+use crate::rest_model::*;
+
+blocking_api! {
+    Margin => crate::margin::Margin {
+        fn transfer<S, F>(&self, symbol: S, qty: F, transfer_type: MarginTransferType) -> Result<TransactionId>
+            where S: Into<String>, F: Into<Decimal>;
+        fn isolated_transfer<S, F>(
+            &self,
+            asset_symbol: S,
+            symbol: S,
+            qty: F,
+            from: IsolatedMarginTransferType,
+            to: IsolatedMarginTransferType,
+        ) -> Result<TransactionId>
+        where
+            S: Into<String>,
+            F: Into<Decimal>;
+        fn loan<S, F>(&self, symbol: S, qty: F) -> Result<TransactionId> where S: Into<String>, F: Into<Decimal>;
+        fn loan_with_isolation<S, F>(
+            &self,
+            symbol: S,
+            qty: F,
+            is_isolated: Option<bool>,
+            isolated_asset: Option<String>,
+        ) -> Result<TransactionId>
+        where
+            S: Into<String>,
+            F: Into<Decimal>;
+        fn repay<S, F>(&self, symbol: S, qty: F) -> Result<TransactionId> where S: Into<String>, F: Into<Decimal>;
+        fn repay_with_isolation<S, F>(
+            &self,
+            symbol: S,
+            qty: F,
+            is_isolated: Option<bool>,
+            isolated_asset: Option<String>,
+        ) -> Result<TransactionId>
+        where
+            S: Into<String>,
+            F: Into<Decimal>;
+        fn trade(&self, margin_order: MarginOrder) -> Result<MarginOrderResult>;
+        fn new_order(&self, margin_order: MarginOrder) -> Result<MarginOrderResult>;
+        fn new_oco_order(&self, margin_order: MarginOCOOrder) -> Result<MarginOCOOrderResult>;
+        fn cancel_trade<S, F>(
+            &self,
+            symbol: S,
+            order_id: F,
+            orig_client_order_id: String,
+            new_client_order_id: String,
+            is_isolated: Option<bool>,
+        ) -> Result<MarginOrderCancellationResult>
+        where
+            S: Into<String>,
+            F: Into<u64>;
+        fn cancel_oco_order<S, F>(
+            &self,
+            symbol: S,
+            order_list_id: F,
+            list_client_order_id: String,
+            new_client_order_id: String,
+            is_isolated: Option<bool>,
+        ) -> Result<MarginOCOOrderResult>
+        where
+            S: Into<String>,
+            F: Into<u64>;
+        fn cancel_all_orders<S>(&self, symbol: S, is_isolated: Option<bool>) -> Result<MarginOrdersCancellationResult>
+            where S: Into<String>;
+        fn loans(&self, loan_query: RecordsQuery) -> Result<RecordsQueryResult<LoanState>>;
+        fn repays(&self, repays_query: RecordsQuery) -> Result<RecordsQueryResult<RepayState>>;
+        fn details(&self) -> Result<MarginAccountDetails>;
+        fn isolated_details(&self, symbols: Option<Vec<String>>) -> Result<IsolatedMarginAccountDetails>;
+        fn disable_isolated(&self, symbol: String) -> Result<IsolatedMarginAccountDetails>;
+        fn enable_isolated(&self, symbol: String) -> Result<IsolatedMarginAccountDetails>;
+        fn isolated_pair<S>(&self, symbol: S) -> Result<IsolatedPairDetails> where S: Into<String>;
+        fn all_isolated_pairs(&self) -> Result<AllIsolatedPairs>;
+        fn toggle_bnb_burn(&self, query: BnbBurnQuery) -> Result<BnbBurnStatus>;
+        fn bnb_burn_status(&self) -> Result<BnbBurnStatus>;
+        fn interest_rate_history(&self, q: InterestRateHistoryQuery) -> Result<InterestRateHistory>;
+        fn asset<S>(&self, asset: S) -> Result<AssetDetails> where S: Into<String>;
+        fn pair<S>(&self, symbol: S) -> Result<PairDetails> where S: Into<String>;
+        fn all_assets(&self) -> Result<AllAssets>;
+        fn all_pairs(&self) -> Result<AllPairs>;
+        fn price_index<S>(&self, symbol: S) -> Result<PriceIndex> where S: Into<String>;
+        fn transfers(&self, transfers_query: RecordsQuery) -> Result<RecordsQueryResult<OrderState>>;
+        fn isolated_transfers(&self, transfers_query: IsolatedTransfersQuery) -> Result<RecordsQueryResult<OrderState>>;
+        fn interests(&self, interest_query: RecordsQuery) -> Result<RecordsQueryResult<InterestState>>;
+        fn forced_liquidations(
+            &self,
+            forced_liquidations_query: RecordsQuery,
+        ) -> Result<RecordsQueryResult<ForcedLiquidationState>>;
+        fn order(&self, margin_order: MarginOrderQuery) -> Result<MarginOrderState>;
+        fn open_orders<S>(&self, symbol: S, is_isolated: Option<bool>) -> Result<Vec<MarginOrderState>>
+            where S: Into<String>;
+        fn orders(&self, all_orders_query: MarginOrdersQuery) -> Result<Vec<MarginOrderState>>;
+        fn trades(&self, my_trades_query: MarginOwnTradesQuery) -> Result<Vec<OwnTradesState>>;
+        fn oco_order(&self, query: MarginOCOOrderQuery) -> Result<MarginOCOOrderResult>;
+        fn all_oco_orders(&self, query: OCORecordsQuery) -> Result<Vec<MarginOCOOrderResult>>;
+        fn open_oco_orders(&self, query: MarginPairQuery) -> Result<Vec<MarginOCOOrderResult>>;
+        fn max_borrowable<S>(&self, asset: S, isolated_symbol: Option<String>) -> Result<MaxBorrowableAmount>
+            where S: Into<String>;
+        fn max_transferable<S>(&self, asset: S, isolated_symbol: Option<String>) -> Result<MaxTransferableAmount>
+            where S: Into<String>;
+        fn start(&self) -> Result<UserDataStream>;
+        fn keep_alive(&self, listen_key: &str) -> Result<Success>;
+        fn close(&self, listen_key: &str) -> Result<Success>;
+        fn start_isolated(&self, symbol: &str) -> Result<UserDataStream>;
+        fn keep_alive_isolated(&self, listen_key: &str, symbol: &str) -> Result<Success>;
+        fn close_isolated(&self, listen_key: &str, symbol: &str) -> Result<Success>;
+        fn isolated_account_limit(&self) -> Result<IsolatedAccountLimit>;
+    }
+}
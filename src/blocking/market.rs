@@ -0,0 +1,47 @@
+//! Blocking [`Market`](crate::market::Market)
+
+use crate::blocking::blocking_api;
+use crate::errors::*;
+use crate::rest_model::*;
+
+blocking_api! {
+    Market => crate::market::Market {
+        fn get_depth<S>(&self, symbol: S) -> Result<OrderBook> where S: AsRef<str>;
+        fn get_custom_depth<S>(&self, symbol: S, limit: u16) -> Result<OrderBook> where S: AsRef<str>;
+        fn get_all_prices(&self) -> Result<Prices>;
+        fn get_price<S>(&self, symbol: S) -> Result<SymbolPrice> where S: AsRef<str>;
+        fn get_average_price<S>(&self, symbol: S) -> Result<AveragePrice> where S: AsRef<str>;
+        fn get_all_book_tickers(&self) -> Result<BookTickers>;
+        fn get_book_ticker<S>(&self, symbol: S) -> Result<Tickers> where S: AsRef<str>;
+        fn get_24h_price_stats<S>(&self, symbol: S) -> Result<PriceStats> where S: AsRef<str>;
+        fn get_agg_trades<S1, S2, S3, S4, S5>(
+            &self,
+            symbol: S1,
+            from_id: S2,
+            start_time: S3,
+            end_time: S4,
+            limit: S5,
+        ) -> Result<Vec<AggTrade>>
+        where
+            S1: AsRef<str>,
+            S2: Into<Option<u64>>,
+            S3: Into<Option<u64>>,
+            S4: Into<Option<u64>>,
+            S5: Into<Option<u16>>;
+        fn get_klines<S1, S2, S3, S4, S5>(
+            &self,
+            symbol: S1,
+            interval: S2,
+            limit: S3,
+            start_time: S4,
+            end_time: S5,
+        ) -> Result<KlineSummaries>
+        where
+            S1: Into<String>,
+            S2: TryInto<KlineInterval>,
+            Error: From<S2::Error>,
+            S3: Into<Option<u16>>,
+            S4: Into<Option<u64>>,
+            S5: Into<Option<u64>>;
+    }
+}
@@ -0,0 +1,136 @@
+//! Blocking API
+//!
+//! Every REST handle has a blocking mirror with the same constructors and method signatures, returning
+//! the same models, e.g. [`blocking::Account`](Account) for [`account::Account`](crate::account::Account).
+//! Requests are run on a runtime managed by the crate, shared by all the blocking handles, so no tokio
+//! runtime is needed by the caller.
+//!
+//! The blocking handles must not be used from within an async runtime, where they panic.
+//!
+//! # Examples
+//! ```rust,no_run
+//! use binance::{api::*, blocking, config::*};
+//! let conf = Config::default();
+//! let market: blocking::Market = Binance::new_with_config(None, None, &conf);
+//! let price = market.get_price("BTCUSDT");
+//! let account: blocking::Account = Binance::new_with_config(Some("api_key".into()), Some("secret_key".into()), &conf);
+//! let balances = account.get_account();
+//! ```
+
+use std::sync::OnceLock;
+use tokio::runtime::Runtime;
+
+use crate::transport::{in_meta_scope, MetaSlot, ResponseMeta};
+
+pub mod account;
+#[cfg(feature = "coin_futures_api")]
+pub mod coin_futures;
+#[cfg(feature = "futures_api")]
+pub mod futures;
+pub mod general;
+#[cfg(feature = "margin_api")]
+pub mod margin;
+pub mod market;
+#[cfg(feature = "options_api")]
+pub mod options;
+#[cfg(feature = "portfolio_margin_api")]
+pub mod portfolio_margin;
+#[cfg(feature = "savings_api")]
+pub mod savings;
+pub mod userstream;
+#[cfg(feature = "wallet_api")]
+pub mod wallet;
+
+pub use account::Account;
+pub use general::General;
+#[cfg(feature = "margin_api")]
+pub use margin::Margin;
+pub use market::Market;
+#[cfg(feature = "savings_api")]
+pub use savings::Savings;
+pub use userstream::UserStream;
+#[cfg(feature = "wallet_api")]
+pub use wallet::Wallet;
+
+/// Worker threads of the runtime
+const WORKER_THREADS: usize = 2;
+
+/// Runtime of the blocking handles, started on first use
+///
+/// Callers block on their own requests concurrently while the workers drive sockets and timers, so
+/// callers on several threads are not serialized and pooled connections keep being driven between calls.
+pub(crate) fn runtime() -> &'static Runtime {
+    static RUNTIME: OnceLock<Runtime> = OnceLock::new();
+    RUNTIME.get_or_init(|| {
+        tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(WORKER_THREADS)
+            .thread_name("binance-blocking")
+            .enable_all()
+            .build()
+            .expect("failed to start the runtime of the blocking API")
+    })
+}
+
+/// Run `call`, requests of blocking handles, and return its output with the metadata of the last response it
+/// received, see [`api::with_meta`](crate::api::with_meta)
+///
+/// Only requests made on the calling thread are captured.
+///
+/// # Examples
+/// ```rust,no_run
+/// use binance::{api::*, blocking};
+/// let market: blocking::Market = Binance::new(None, None);
+/// let (price, meta) = blocking::with_meta(|| market.get_price("BTCUSDT"));
+/// ```
+pub fn with_meta<T>(call: impl FnOnce() -> T) -> (T, Option<ResponseMeta>) {
+    let slot = MetaSlot::default();
+    let output = in_meta_scope(&slot, call);
+    let meta = slot.lock().unwrap().take();
+    (output, meta)
+}
+
+/// Declare the blocking mirror `$name` of the async handle `$handle`, with the listed methods
+macro_rules! blocking_api {
+    ($($name:ident => $handle:ty {
+        $(fn $method:ident $(<$($generic:ident),+>)? (&self $(, $arg:ident: $ty:ty)* $(,)?) -> $ret:ty
+            $(where $($bounded:ident: $bound:path),+)?;)*
+    })*) => {$(
+        #[doc = concat!("Blocking [`", stringify!($name), "`](", stringify!($handle), ")")]
+        #[derive(Clone)]
+        pub struct $name {
+            inner: $handle,
+        }
+
+        impl $name {
+            /// The async handle
+            pub fn inner(&self) -> &$handle { &self.inner }
+
+            $(
+                #[doc = concat!("See [`", stringify!($method), "`](", stringify!($handle), "::", stringify!($method), ")")]
+                pub fn $method $(<$($generic),+>)? (&self $(, $arg: $ty)*) -> $ret
+                $(where $($bounded: $bound),+)?
+                {
+                    $crate::blocking::runtime().block_on(self.inner.$method($($arg),*))
+                }
+            )*
+        }
+
+        impl From<$handle> for $name {
+            fn from(inner: $handle) -> Self { $name { inner } }
+        }
+
+        impl $crate::api::Binance for $name {
+            fn new_with_config(
+                api_key: Option<String>,
+                secret_key: Option<String>,
+                config: &$crate::config::Config,
+            ) -> Self {
+                $name {
+                    inner: $crate::api::Binance::new_with_config(api_key, secret_key, config),
+                }
+            }
+        }
+    )*};
+}
+
+pub(crate) use blocking_api;
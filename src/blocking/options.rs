@@ -0,0 +1,83 @@
+//! Blocking options API
+
+pub mod general {
+    use crate::blocking::blocking_api;
+    use crate::errors::*;
+    use crate::options::rest_model::*;
+    use crate::rest_model::ServerTime;
+
+    blocking_api! {
+        OptionsGeneral => crate::options::general::OptionsGeneral {
+            fn ping(&self) -> Result<String>;
+            fn get_server_time(&self) -> Result<ServerTime>;
+            fn exchange_info(&self) -> Result<ExchangeInformation>;
+        }
+    }
+}
+
+pub mod market {
+    use crate::blocking::blocking_api;
+    use crate::errors::*;
+    use crate::options::rest_model::*;
+    use crate::rest_model::KlineSummaries;
+
+    blocking_api! {
+        OptionsMarket => crate::options::market::OptionsMarket {
+            fn get_depth(&self, symbol: impl Into<String>) -> Result<OrderBook>;
+            fn get_trades(&self, symbol: impl Into<String>) -> Result<Vec<OptionTrade>>;
+            fn get_ticker(&self, symbol: impl Into<String>) -> Result<OptionTicker>;
+            fn get_mark_price(&self, symbol: impl Into<String>) -> Result<Vec<OptionMarkPrice>>;
+            fn get_klines<I>(
+                &self,
+                symbol: impl Into<String>,
+                interval: I,
+                start_time: Option<u64>,
+                end_time: Option<u64>,
+                limit: Option<u16>,
+            ) -> Result<KlineSummaries>
+            where
+                I: TryInto<KlineInterval>,
+                Error: From<I::Error>;
+            fn get_open_interest(&self, underlying: impl Into<String>) -> Result<Vec<OptionOpenInterest>>;
+        }
+    }
+}
+
+pub mod account {
+    use crate::blocking::blocking_api;
+    use crate::errors::*;
+    use crate::options::rest_model::*;
+
+    blocking_api! {
+        OptionsAccount => crate::options::account::OptionsAccount {
+            fn account_information(&self) -> Result<Vec<AccountInformation>>;
+            fn place_order(&self, order: OptionOrderRequest) -> Result<OptionOrder>;
+            fn get_order(&self, symbol: impl Into<String>, order_id: u64) -> Result<OptionOrder>;
+            fn cancel_order(&self, symbol: impl Into<String>, order_id: u64) -> Result<OptionOrder>;
+            fn get_open_orders(&self, symbol: impl Into<String>) -> Result<Vec<OptionOrder>>;
+            fn cancel_all_open_orders(&self, symbol: impl Into<String>) -> Result<()>;
+            fn get_position(&self, symbol: impl Into<String>) -> Result<Vec<OptionPosition>>;
+            fn get_exercise_record(
+                &self,
+                symbol: impl Into<String>,
+                start_time: Option<u64>,
+                end_time: Option<u64>,
+                limit: Option<u16>,
+            ) -> Result<Vec<ExerciseRecord>>;
+        }
+    }
+}
+
+pub mod userstream {
+    use crate::blocking::blocking_api;
+    use crate::errors::*;
+    use crate::rest_model::*;
+
+    blocking_api! {
+        UserStream => crate::options::userstream::UserStream {
+            fn start(&self) -> Result<UserDataStream>;
+            fn keep_alive(&self, listen_key: &str) -> Result<Success>;
+            fn close(&self, listen_key: &str) -> Result<Success>;
+        }
+    }
+}
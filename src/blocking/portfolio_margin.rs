@@ -0,0 +1,60 @@
+//! Blocking portfolio margin API
+
+pub mod general {
+    use crate::blocking::blocking_api;
+    use crate::errors::*;
+    use crate::portfolio_margin::rest_model::*;
+
+    blocking_api! {
+        PortfolioMarginGeneral => crate::portfolio_margin::general::PortfolioMarginGeneral {
+            fn ping(&self) -> Result<String>;
+            fn account_information(&self) -> Result<AccountInformation>;
+            fn account_balance(&self) -> Result<Vec<AccountBalance>>;
+            fn max_borrowable(&self, asset: impl Into<String>) -> Result<MaxBorrowable>;
+        }
+    }
+}
+
+pub mod account {
+    use crate::blocking::blocking_api;
+    use crate::errors::*;
+    use crate::portfolio_margin::rest_model::{CmOrder, CmOrderRequest, CmPosition, MarginOrder, MarginOrderRequest,
+                                              RepayResult, UmOrder, UmOrderRequest, UmPosition};
+    use crate::rest_model::Decimal;
+
+    blocking_api! {
+        PortfolioMarginAccount => crate::portfolio_margin::account::PortfolioMarginAccount {
+            fn um_place_order(&self, order: UmOrderRequest) -> Result<UmOrder>;
+            fn um_get_order(&self, symbol: impl Into<String>, order_id: u64) -> Result<UmOrder>;
+            fn um_get_open_orders(&self, symbol: impl Into<String>) -> Result<Vec<UmOrder>>;
+            fn um_cancel_order(&self, symbol: impl Into<String>, order_id: u64) -> Result<UmOrder>;
+            fn um_cancel_all_open_orders(&self, symbol: impl Into<String>) -> Result<()>;
+            fn um_position_information(&self, symbol: impl Into<String>) -> Result<Vec<UmPosition>>;
+            fn cm_place_order(&self, order: CmOrderRequest) -> Result<CmOrder>;
+            fn cm_get_order(&self, symbol: impl Into<String>, order_id: u64) -> Result<CmOrder>;
+            fn cm_get_open_orders(&self, symbol: impl Into<String>) -> Result<Vec<CmOrder>>;
+            fn cm_cancel_order(&self, symbol: impl Into<String>, order_id: u64) -> Result<CmOrder>;
+            fn cm_cancel_all_open_orders(&self, symbol: impl Into<String>) -> Result<()>;
+            fn cm_position_information(&self, symbol: impl Into<String>) -> Result<Vec<CmPosition>>;
+            fn margin_place_order(&self, order: MarginOrderRequest) -> Result<MarginOrder>;
+            fn margin_get_open_orders(&self, symbol: impl Into<String>) -> Result<Vec<MarginOrder>>;
+            fn margin_cancel_order(&self, symbol: impl Into<String>, order_id: u64) -> Result<MarginOrder>;
+            fn margin_borrow(&self, asset: impl Into<String>, amount: Decimal) -> Result<RepayResult>;
+            fn margin_repay(&self, asset: impl Into<String>, amount: Decimal) -> Result<RepayResult>;
+        }
+    }
+}
+
+pub mod userstream {
+    use crate::blocking::blocking_api;
+    use crate::errors::*;
+    use crate::rest_model::*;
+
+    blocking_api! {
+        UserStream => crate::portfolio_margin::userstream::UserStream {
+            fn start(&self) -> Result<UserDataStream>;
+            fn keep_alive(&self, listen_key: &str) -> Result<Success>;
+            fn close(&self, listen_key: &str) -> Result<Success>;
+        }
+    }
+}
@@ -0,0 +1,14 @@
+//! Blocking [`Savings`](crate::savings::Savings)
+
+use crate::blocking::blocking_api;
+use crate::errors::*;
+use crate::savings::*;
+use std::collections::BTreeMap;
+
+blocking_api! {
+    Savings => crate::savings::Savings {
+        fn get_all_coins(&self) -> Result<Vec<CoinInfo>>;
+        fn asset_detail(&self, asset: Option<&str>) -> Result<BTreeMap<String, AssetDetail>>;
+        fn deposit_address<S>(&self, coin: S, network: Option<&str>) -> Result<DepositAddress> where S: AsRef<str>;
+    }
+}
@@ -0,0 +1,13 @@
+//! Blocking [`UserStream`](crate::userstream::UserStream)
+
+use crate::blocking::blocking_api;
+use crate::errors::*;
+use crate::rest_model::*;
+
+blocking_api! {
+    UserStream => crate::userstream::UserStream {
+        fn start(&self) -> Result<UserDataStream>;
+        fn keep_alive(&self, listen_key: &str) -> Result<Success>;
+        fn close(&self, listen_key: &str) -> Result<Success>;
+    }
+}
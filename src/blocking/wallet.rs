@@ -0,0 +1,55 @@
+//! Blocking [`Wallet`](crate::wallet::Wallet)
+
+use crate::blocking::blocking_api;
+use crate::errors::*;
+use crate::rest_model::*;
+use chrono::{DateTime, Duration, Utc};
+
+blocking_api! {
+    Wallet => crate::wallet::Wallet {
+        fn system_status(&self) -> Result<SystemStatus>;
+        fn all_coin_info(&self) -> Result<Vec<WalletCoinInfo>>;
+        fn daily_account_snapshot(&self, query: AccountSnapshotQuery) -> Result<AccountSnapshot>;
+        fn disable_fast_withdraw_switch(&self) -> Result<()>;
+        fn enable_fast_withdraw_switch(&self) -> Result<()>;
+        fn withdraw(&self, query: CoinWithdrawalQuery) -> Result<()>;
+        fn deposit_history(&self, query: &DepositHistoryQuery) -> Result<Vec<DepositRecord>>;
+        fn deposit_history_quick(
+            &self,
+            query: DepositHistoryQuery,
+            start_from: Option<DateTime<Utc>>,
+            total_duration: Option<Duration>,
+        ) -> Result<Vec<RecordHistory<DepositRecord>>>;
+        fn withdraw_history(&self, query: &WithdrawalHistoryQuery) -> Result<Vec<WithdrawalRecord>>;
+        fn withdraw_history_quick(
+            &self,
+            query: WithdrawalHistoryQuery,
+            start_from: Option<DateTime<Utc>>,
+            total_duration: Option<Duration>,
+        ) -> Result<Vec<RecordHistory<WithdrawalRecord>>>;
+        fn deposit_address(&self, query: DepositAddressQuery) -> Result<DepositAddress>;
+        fn universal_transfer(
+            &self,
+            asset: String,
+            amount: Decimal,
+            from_symbol: Option<String>,
+            to_symbol: Option<String>,
+            transfer_type: UniversalTransferType,
+        ) -> Result<TransactionId>;
+        fn universal_transfer_history(
+            &self,
+            query: UniversalTransferHistoryQuery,
+        ) -> Result<RecordsQueryResult<UniversalTransferRecord>>;
+        fn account_status(&self) -> Result<AccountStatus>;
+        fn api_trading_status(&self) -> Result<ApiTradingStatus>;
+        fn dust_log(&self, start_time: Option<u64>, end_time: Option<u64>) -> Result<DustLog>;
+        fn convertible_assets(&self) -> Result<ConvertibleAssets>;
+        fn dust_transfer(&self, assets: Vec<String>) -> Result<DustTransfer>;
+        fn asset_dividends(&self, query: AssetDividendQuery) -> Result<RecordsQueryResult<AssetDividend>>;
+        fn asset_detail(&self, asset: Option<String>) -> Result<SupportedAssetDetails>;
+        fn trade_fees(&self, symbol: Option<String>) -> Result<TradeFees>;
+        fn funding_wallet(&self, asset: Option<String>, need_btc_valuation: Option<bool>) -> Result<WalletFundings>;
+        fn api_key_permissions(&self) -> Result<ApiKeyPermissions>;
+        fn wallet_balance(&self) -> Result<WalletBalances>;
+    }
+}
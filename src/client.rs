@@ -1,48 +1,99 @@
-use std::time::Duration;
+use std::future::Future;
+use std::time::{Duration, Instant};
 
-use hex::encode as hex_encode;
-use reqwest::header::{HeaderMap, HeaderName, HeaderValue, CONTENT_TYPE, USER_AGENT};
-use reqwest::Response;
-use reqwest::StatusCode;
-use ring::hmac;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, CONTENT_TYPE, RETRY_AFTER, USER_AGENT};
+use reqwest::{Method, StatusCode};
 use serde::de;
 use serde::de::DeserializeOwned;
+use tracing::Instrument;
 
+use crate::config::{Config, Environment};
+use crate::credentials::ApiKeys;
 use crate::errors::error_messages;
 use crate::errors::*;
-use crate::util::{build_request_p, build_signed_request_p};
+use crate::failover::{can_fail_over, is_host_failure, Failover};
+use crate::rate_limit::{is_order_endpoint, RateLimiter, RetryPolicy};
+use crate::rest_model::RateLimit;
+use crate::runtime;
+use crate::time_sync::{time_endpoint, TimeSync};
+use crate::transport::{record_meta, HttpRequest, HttpResponse, ResponseMeta, SharedTransport};
+use crate::util::{build_request_p, build_signed_request_at, build_signed_request_p_at, get_timestamp};
 
 #[derive(Clone)]
 pub struct Client {
-    api_key: String,
-    secret_key: String,
-    inner: reqwest::Client,
+    keys: ApiKeys,
+    transport: SharedTransport,
     host: String,
+    /// Host of the spot API, which serves the server time of APIs without a time endpoint
+    spot_host: String,
+    rate_limiter: Option<RateLimiter>,
+    retry_policy: Option<RetryPolicy>,
+    time_sync: Option<TimeSync>,
+    environment: Environment,
+    failover: Option<Failover>,
 }
 
 impl Client {
-    /// Returns a client based on the specified host and credentials
+    /// Returns a client based on the specified host and credentials, with the default configuration
+    /// and a timeout in seconds
+    #[deprecated(note = "use `Client::with_config`, the timeout is set with `Config::set_timeout`")]
+    pub fn new(api_key: Option<String>, secret_key: Option<String>, host: String, timeout: Option<u64>) -> Self {
+        let config = Config {
+            timeout,
+            ..Config::default()
+        };
+        Self::with_config(api_key, secret_key, host, &config)
+    }
+
+    /// Returns a client based on the specified host, credentials and configuration
     /// Credentials do not need to be specified when using public endpoints
     /// Host is mandatory
-    pub fn new(api_key: Option<String>, secret_key: Option<String>, host: String, timeout: Option<u64>) -> Self {
-        let mut builder: reqwest::ClientBuilder = reqwest::ClientBuilder::new();
-        if let Some(timeout_secs) = timeout {
-            builder = builder.timeout(Duration::from_secs(timeout_secs))
-        }
+    /// The credentials of the configuration take precedence over the keys
+    /// The signer of the configuration takes precedence over the secret key
+    /// Requests are sent with the transport of the configuration, or reqwest
+    /// The failover of the configuration applies if it includes the host
+    pub fn with_config(api_key: Option<String>, secret_key: Option<String>, host: String, config: &Config) -> Self {
         Client {
             // Does it ever make sense for api_key and secret_key to be ""?
-            api_key: api_key.unwrap_or_else(|| "".into()),
-            secret_key: secret_key.unwrap_or_else(|| "".into()),
-            inner: builder.build().unwrap(),
+            keys: ApiKeys::new(api_key, secret_key, config),
+            transport: SharedTransport::for_config(config),
+            rate_limiter: config.rate_limiter.clone(),
+            retry_policy: config.retry_policy.clone(),
+            time_sync: config.time_sync.clone(),
+            environment: config.environment,
+            failover: config.failover.clone().filter(|failover| failover.contains(&host)),
             host,
+            spot_host: config.rest_api_endpoint.clone(),
         }
     }
 
+    /// Signed request of `parameters`, stamped with the server clock if synchronized
+    pub fn build_signed_request(
+        &self,
+        parameters: impl IntoIterator<Item = (impl AsRef<str>, impl AsRef<str>)>,
+        recv_window: u64,
+    ) -> Result<String> {
+        build_signed_request_at(parameters, recv_window, self.timestamp()?)
+    }
+
+    /// Signed request of `payload`, stamped with the server clock if synchronized
+    pub fn build_signed_request_p<S: serde::Serialize>(&self, payload: S, recv_window: u64) -> Result<String> {
+        build_signed_request_p_at(payload, recv_window, self.timestamp()?)
+    }
+
     pub async fn get_signed<T: DeserializeOwned>(&self, endpoint: &str, request: &str) -> Result<T> {
-        let url = self.sign_request(endpoint, request);
-        let response = self.inner.get(&url).headers(self.build_headers(true)?).send().await?;
+        // Stamped again on retries as the timestamp has to be within the receive window
+        self.with_retries(|| async { self.get_signed_once(endpoint, &self.restamp(request)?).await })
+            .await
+    }
 
-        self.handler(response).await
+    async fn get_signed_once<T: DeserializeOwned>(&self, endpoint: &str, request: &str) -> Result<T> {
+        self.with_time_sync(endpoint, request, |request| async move {
+            self.acquire(Method::GET, endpoint, false, true).await?;
+            let url = self.sign_request(endpoint, &request)?;
+            self.request(Method::GET, url, self.build_headers(true)?).await
+        })
+        .await
     }
 
     pub async fn get_signed_d<T: de::DeserializeOwned>(&self, endpoint: &str, request: &str) -> Result<T> {
@@ -55,15 +106,18 @@ impl Client {
         payload: Option<P>,
         recv_window: u64,
     ) -> Result<T> {
-        let req = build_signed_request_p(payload, recv_window)?;
+        let req = self.build_signed_request_p(payload, recv_window)?;
         self.get_signed(endpoint, &req).await
     }
 
     pub async fn post_signed<T: DeserializeOwned>(&self, endpoint: &str, request: &str) -> Result<T> {
-        let url = self.sign_request(endpoint, request);
-        let response = self.inner.post(&url).headers(self.build_headers(true)?).send().await?;
-
-        self.handler(response).await
+        self.with_time_sync(endpoint, request, |request| async move {
+            self.acquire(Method::POST, endpoint, is_order_endpoint(endpoint), true)
+                .await?;
+            let url = self.sign_request(endpoint, &request)?;
+            self.request(Method::POST, url, self.build_headers(true)?).await
+        })
+        .await
     }
 
     pub async fn post_signed_d<T: de::DeserializeOwned>(&self, endpoint: &str, request: &str) -> Result<T> {
@@ -76,7 +130,7 @@ impl Client {
         payload: P,
         recv_window: u64,
     ) -> Result<T> {
-        let request = build_signed_request_p(payload, recv_window)?;
+        let request = self.build_signed_request_p(payload, recv_window)?;
         self.post_signed(endpoint, &request).await
     }
 
@@ -86,30 +140,30 @@ impl Client {
         payload: P,
         recv_window: u64,
     ) -> Result<T> {
-        let request = build_signed_request_p(payload, recv_window)?;
+        let request = self.build_signed_request_p(payload, recv_window)?;
         self.delete_signed(endpoint, &request).await
     }
 
     pub async fn delete_signed<T: DeserializeOwned>(&self, endpoint: &str, request: &str) -> Result<T> {
-        let url = self.sign_request(endpoint, request);
-        let response = self
-            .inner
-            .delete(&url)
-            .headers(self.build_headers(true)?)
-            .send()
-            .await?;
-
-        self.handler(response).await
+        self.with_time_sync(endpoint, request, |request| async move {
+            self.acquire(Method::DELETE, endpoint, false, true).await?;
+            let url = self.sign_request(endpoint, &request)?;
+            self.request(Method::DELETE, url, self.build_headers(true)?).await
+        })
+        .await
     }
 
     pub async fn get<T: DeserializeOwned>(&self, endpoint: &str, request: Option<&str>) -> Result<T> {
+        self.with_retries(|| self.get_once(endpoint, request)).await
+    }
+
+    async fn get_once<T: DeserializeOwned>(&self, endpoint: &str, request: Option<&str>) -> Result<T> {
+        self.acquire(Method::GET, endpoint, false, false).await?;
         let url = request
             .map(|r| format!("{}{}?{}", self.host, endpoint, r))
             .unwrap_or_else(|| format!("{}{}", self.host, endpoint));
 
-        let response = self.inner.get(&url).send().await?;
-
-        self.handler(response).await
+        self.request(Method::GET, url, HeaderMap::new()).await
     }
 
     pub async fn get_p<T: DeserializeOwned>(&self, endpoint: &str, request: Option<&str>) -> Result<T> {
@@ -130,24 +184,21 @@ impl Client {
     }
 
     pub async fn post<T: DeserializeOwned>(&self, endpoint: &str, symbol: Option<&str>) -> Result<T> {
+        self.acquire(Method::POST, endpoint, false, true).await?;
         let url = symbol
             .map(|s| format!("{}{}?symbol={}", self.host, endpoint, s))
             .unwrap_or_else(|| format!("{}{}", self.host, endpoint));
 
-        let response = self.inner.post(url).headers(self.build_headers(false)?).send().await?;
-
-        self.handler(response).await
+        self.request(Method::POST, url, self.build_headers(false)?).await
     }
 
     pub async fn put<T: DeserializeOwned>(&self, endpoint: &str, listen_key: &str, symbol: Option<&str>) -> Result<T> {
+        self.acquire(Method::PUT, endpoint, false, true).await?;
         let data = symbol
             .map(|s| format!("listenKey={listen_key}&symbol={s}"))
             .unwrap_or_else(|| format!("listenKey={listen_key}"));
-        let headers = self.build_headers(false)?;
         let url = format!("{}{}?{}", self.host, endpoint, data);
-        let response = self.inner.put(&url).headers(headers).send().await?;
-
-        self.handler(response).await
+        self.request(Method::PUT, url, self.build_headers(false)?).await
     }
 
     pub async fn delete<T: DeserializeOwned>(
@@ -156,27 +207,125 @@ impl Client {
         listen_key: &str,
         symbol: Option<&str>,
     ) -> Result<T> {
+        self.acquire(Method::DELETE, endpoint, false, true).await?;
         let data = symbol
             .map(|s| format!("listenKey={listen_key}&symbol={s}"))
             .unwrap_or_else(|| format!("listenKey={listen_key}"));
         let url = format!("{}{}?{}", self.host, endpoint, data);
-        let response = self
-            .inner
-            .delete(url)
-            .headers(self.build_headers(false)?)
-            .send()
-            .await?;
+        self.request(Method::DELETE, url, self.build_headers(false)?).await
+    }
 
-        self.handler(response).await
+    /// Limits of the host, from its exchange information
+    pub fn set_rate_limits(&self, limits: &[RateLimit]) {
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter.set_limits(&self.host, limits);
+        }
+    }
+
+    /// Check that the environment provides the endpoint and wait for the rate limits,
+    /// `authenticated` for requests sent with the API key
+    async fn acquire(&self, method: Method, endpoint: &str, is_order: bool, authenticated: bool) -> Result<()> {
+        self.environment.check_endpoint(endpoint, authenticated)?;
+        match &self.rate_limiter {
+            Some(rate_limiter) => rate_limiter.acquire(&self.host, method, endpoint, is_order).await,
+            None => Ok(()),
+        }
+    }
+
+    /// Retry `request` as long as the retry policy allows it
+    async fn with_retries<T, F, Fut>(&self, request: F) -> Result<T>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let mut retries = 0;
+        loop {
+            match request().await {
+                Err(e) => match self.retry_policy.as_ref().and_then(|p| p.delay(&e, retries)) {
+                    Some(delay) => {
+                        retries += 1;
+                        runtime::sleep(delay).await;
+                    }
+                    None => return Err(e),
+                },
+                result => return result,
+            }
+        }
+    }
+
+    /// Send the signed `request` with the server clock, synchronized first when due.
+    /// A timestamp rejected by the server is retried once after synchronizing again, the request
+    /// was not processed.
+    async fn with_time_sync<T, F, Fut>(&self, endpoint: &str, request: &str, send: F) -> Result<T>
+    where
+        F: Fn(String) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let Some(time_sync) = &self.time_sync else {
+            return send(request.to_string()).await;
+        };
+        if time_sync.is_due() {
+            // The request is sent with the previous estimate if the server time is unavailable
+            let _ = self.sync_time(endpoint).await;
+        }
+        match send(self.restamp(request)?).await {
+            Err(Error::BinanceError { response })
+                if response.error_code() == Some(BinanceErrorCode::InvalidTimestamp) =>
+            {
+                time_sync.invalidate();
+                if self.sync_time(endpoint).await.is_err() {
+                    return Err(Error::BinanceError { response });
+                }
+                send(self.restamp(request)?).await
+            }
+            result => result,
+        }
+    }
+
+    async fn sync_time(&self, endpoint: &str) -> Result<()> {
+        match (&self.time_sync, time_endpoint(endpoint)) {
+            (Some(time_sync), Some(time_endpoint)) if time_endpoint.starts_with("/api/") => {
+                time_sync
+                    .sync(|| async {
+                        self.acquire(Method::GET, time_endpoint, false, false).await?;
+                        let url = format!("{}{}", self.spot_host, time_endpoint);
+                        self.request(Method::GET, url, HeaderMap::new()).await
+                    })
+                    .await
+            }
+            (Some(time_sync), Some(time_endpoint)) => time_sync.sync(|| self.get_once(time_endpoint, None)).await,
+            _ => Ok(()),
+        }
+    }
+
+    fn timestamp(&self) -> Result<u64> {
+        match &self.time_sync {
+            Some(time_sync) => time_sync.now(),
+            None => get_timestamp(),
+        }
+    }
+
+    /// `request` stamped again, it may have been built before the clock was synchronized
+    fn restamp(&self, request: &str) -> Result<String> {
+        let timestamp = self.timestamp()?;
+        Ok(request
+            .split('&')
+            .map(|param| match param.strip_prefix("timestamp=") {
+                Some(_) => format!("timestamp={timestamp}"),
+                None => param.to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join("&"))
     }
 
     // Request must be signed
-    fn sign_request(&self, endpoint: &str, request: &str) -> String {
-        let signed_key = hmac::Key::new(hmac::HMAC_SHA256, self.secret_key.as_bytes());
-        let signature = hex_encode(hmac::sign(&signed_key, request.as_bytes()).as_ref());
+    fn sign_request(&self, endpoint: &str, request: &str) -> Result<String> {
+        let signature = self.keys.signer()?.sign(request)?;
+        // base64 signatures need to be url encoded
+        let signature: String = url::form_urlencoded::byte_serialize(signature.as_bytes()).collect();
         let url = format!("{}{}?{}&signature={}", self.host, endpoint, request, signature);
 
-        url
+        Ok(url)
     }
 
     fn build_headers(&self, content_type: bool) -> Result<HeaderMap> {
@@ -186,7 +335,7 @@ impl Client {
             // Always include API key
             Some((
                 HeaderName::from_static("x-mbx-apikey"),
-                HeaderValue::from_str(&self.api_key)?,
+                HeaderValue::from_str(self.keys.api_key()?.expose())?,
             )),
             // Include content type if needed
             if content_type {
@@ -204,25 +353,171 @@ impl Client {
         Ok(header)
     }
 
-    async fn handler<T: de::DeserializeOwned>(&self, response: Response) -> Result<T> {
-        match response.status() {
-            StatusCode::OK => Ok(response.json().await?),
+    /// Send the request and handle its response, in a `binance.request` span recording the endpoint,
+    /// the parameters without the signature, the status, the used weight, the duration and the error code
+    async fn request<T: DeserializeOwned>(&self, method: Method, url: String, headers: HeaderMap) -> Result<T> {
+        let path = url.strip_prefix(&self.host).unwrap_or(&url);
+        let (endpoint, params) = path.split_once('?').unwrap_or((path, ""));
+        let span = tracing::debug_span!(
+            "binance.request",
+            %method,
+            endpoint,
+            params = %redact_params(params),
+            status = tracing::field::Empty,
+            used_weight = tracing::field::Empty,
+            order_count = tracing::field::Empty,
+            duration_ms = tracing::field::Empty,
+            error_code = tracing::field::Empty,
+        );
+        async move {
+            let sent = Instant::now();
+            let result = match self.send(method, url, headers).await {
+                Ok(response) => {
+                    let meta = ResponseMeta::new(&response, sent.elapsed());
+                    let span = tracing::Span::current();
+                    span.record("status", meta.status.as_u16());
+                    span.record("used_weight", tracing::field::debug(&meta.used_weight));
+                    span.record("order_count", tracing::field::debug(&meta.order_count));
+                    record_meta(|| meta);
+                    self.handler(response).await
+                }
+                Err(e) => Err(e),
+            };
+            let span = tracing::Span::current();
+            span.record("duration_ms", sent.elapsed().as_millis() as u64);
+            match &result {
+                Ok(_) => tracing::debug!("request succeeded"),
+                Err(e) => {
+                    let code = match e {
+                        Error::BinanceError { response } => Some(response.code),
+                        e => e.error_code().map(BinanceErrorCode::code),
+                    };
+                    if let Some(code) = code {
+                        span.record("error_code", code);
+                    }
+                    tracing::warn!(error = %e, "request failed");
+                }
+            }
+            result
+        }
+        .instrument(span)
+        .await
+    }
+
+    async fn send(&self, method: Method, url: String, headers: HeaderMap) -> Result<HttpResponse> {
+        match &self.failover {
+            Some(failover) => self.send_with_failover(failover, method, url, headers).await,
+            None => self.transport.send(HttpRequest { method, url, headers }).await,
+        }
+    }
+
+    /// Send to the healthy hosts in turn, as long as the request can safely be sent again
+    async fn send_with_failover(
+        &self,
+        failover: &Failover,
+        method: Method,
+        url: String,
+        headers: HeaderMap,
+    ) -> Result<HttpResponse> {
+        // Urls are built with the host of the client
+        let path = url.strip_prefix(&self.host).unwrap_or_default();
+        let mut result = Err(Error::Msg(format!("no host to send {path} to")));
+        for host in failover.candidates() {
+            let request = HttpRequest {
+                method: method.clone(),
+                url: format!("{host}{path}"),
+                headers: headers.clone(),
+            };
+            result = self.transport.send(request).await;
+            if !is_host_failure(&result) {
+                failover.mark_up(&host);
+                break;
+            }
+            failover.mark_down(&host);
+            if !can_fail_over(&method, &result) {
+                break;
+            }
+        }
+        result
+    }
+
+    async fn handler<T: de::DeserializeOwned>(&self, response: HttpResponse) -> Result<T> {
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter.update(&self.host, &response.headers);
+        }
+        match response.status {
+            StatusCode::OK => Ok(serde_json::from_slice(&response.body)?),
             StatusCode::INTERNAL_SERVER_ERROR => Err(Error::InternalServerError),
             StatusCode::SERVICE_UNAVAILABLE => Err(Error::ServiceUnavailable),
             StatusCode::UNAUTHORIZED => Err(Error::Unauthorized),
             StatusCode::BAD_REQUEST => {
-                let error: BinanceContentError = response.json().await?;
+                let error: BinanceContentError = serde_json::from_slice(&response.body)?;
                 Err(handle_content_error(error))
             }
+            StatusCode::TOO_MANY_REQUESTS => {
+                let retry_after = retry_after(&response);
+                let response = serde_json::from_slice(&response.body).ok();
+                Err(Error::RateLimited { retry_after, response })
+            }
+            StatusCode::IM_A_TEAPOT => {
+                let retry_after = retry_after(&response);
+                let response: Option<BinanceContentError> = serde_json::from_slice(&response.body).ok();
+                // The ban's end is only given in the message, "IP banned until 1659146400000."
+                let until = response
+                    .as_ref()
+                    .and_then(|r| r.msg.split("banned until ").nth(1))
+                    .and_then(|m| m.split(|c: char| !c.is_ascii_digit()).next())
+                    .and_then(|until| until.parse().ok())
+                    .or_else(|| retry_after.map(|d| get_timestamp().unwrap_or_default() + d.as_millis() as u64));
+                Err(Error::IpBanned { until, response })
+            }
             s => Err(Error::Msg(format!("Received response: {s:?}"))),
         }
     }
 }
 
-fn handle_content_error(error: BinanceContentError) -> crate::errors::Error {
+/// Delay of the `Retry-After` header, in seconds
+fn retry_after(response: &HttpResponse) -> Option<Duration> {
+    response
+        .headers
+        .get(RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .parse()
+        .ok()
+        .map(Duration::from_secs)
+}
+
+/// Query parameters with the signature and the API key redacted
+fn redact_params(params: &str) -> String {
+    params
+        .split('&')
+        .map(|param| match param.split_once('=') {
+            Some((name @ ("signature" | "apiKey"), _)) => format!("{name}=***"),
+            _ => param.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+pub(crate) fn handle_content_error(error: BinanceContentError) -> crate::errors::Error {
     match (error.code, error.msg.as_ref()) {
         (-1013, error_messages::INVALID_PRICE) => Error::InvalidPrice,
         (-1125, msg) => Error::InvalidListenKey(msg.to_string()),
         _ => Error::BinanceError { response: error },
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn redacted_params() {
+        assert_eq!(
+            redact_params("symbol=BTCUSDT&apiKey=key&timestamp=1&signature=abc%3D"),
+            "symbol=BTCUSDT&apiKey=***&timestamp=1&signature=***"
+        );
+        assert_eq!(redact_params(""), "");
+    }
+}
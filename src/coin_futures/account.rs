@@ -5,9 +5,8 @@ use crate::client::Client;
 use crate::coin_futures::rest_model::{AccountBalance, AccountInformation, CanceledOrder, ChangeLeverageResponse,
                                       Order, OrderType, Position, PositionSide, Transaction, WorkingType};
 use crate::errors::*;
-use crate::rest_model::{OrderSide, TimeInForce};
+use crate::rest_model::{Decimal, OrderSide, TimeInForce};
 use crate::rest_model::{PairAndWindowQuery, PairQuery};
-use crate::util::*;
 use serde::Serializer;
 use std::fmt;
 
@@ -58,13 +57,13 @@ pub struct OrderRequest {
     pub order_type: OrderType,
     pub time_in_force: Option<TimeInForce>,
     #[serde(rename = "quantity")]
-    pub quantity: Option<f64>,
+    pub quantity: Option<Decimal>,
     pub reduce_only: Option<bool>,
-    pub price: Option<f64>,
-    pub stop_price: Option<f64>,
+    pub price: Option<Decimal>,
+    pub stop_price: Option<Decimal>,
     pub close_position: Option<bool>,
-    pub activation_price: Option<f64>,
-    pub callback_rate: Option<f64>,
+    pub activation_price: Option<Decimal>,
+    pub callback_rate: Option<Decimal>,
     pub working_type: Option<WorkingType>,
     #[serde(serialize_with = "serialize_opt_as_uppercase")]
     pub price_protect: Option<bool>,
@@ -95,7 +94,9 @@ impl CoinFuturesAccount {
 
     /// Get currently open orders
     pub async fn get_open_orders(&self, symbol: impl Into<String>) -> Result<Vec<Order>> {
-        let payload = build_signed_request_p(PairQuery { symbol: symbol.into() }, self.recv_window)?;
+        let payload = self
+            .client
+            .build_signed_request_p(PairQuery { symbol: symbol.into() }, self.recv_window)?;
         self.client.get_signed("/dapi/v1/openOrders", &payload).await
     }
 
@@ -110,8 +111,8 @@ impl CoinFuturesAccount {
     pub async fn limit_buy(
         &self,
         symbol: impl Into<String>,
-        qty: impl Into<f64>,
-        price: f64,
+        qty: impl Into<Decimal>,
+        price: Decimal,
         time_in_force: TimeInForce,
     ) -> Result<Transaction> {
         let order = OrderRequest {
@@ -138,8 +139,8 @@ impl CoinFuturesAccount {
     pub async fn limit_sell(
         &self,
         symbol: impl Into<String>,
-        qty: impl Into<f64>,
-        price: f64,
+        qty: impl Into<Decimal>,
+        price: Decimal,
         time_in_force: TimeInForce,
     ) -> Result<Transaction> {
         let order = OrderRequest {
@@ -166,7 +167,7 @@ impl CoinFuturesAccount {
     pub async fn market_buy<S, F>(&self, symbol: S, qty: F) -> Result<Transaction>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Decimal>,
     {
         let order = OrderRequest {
             symbol: symbol.into(),
@@ -192,7 +193,7 @@ impl CoinFuturesAccount {
     pub async fn market_sell<S, F>(&self, symbol: S, qty: F) -> Result<Transaction>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Decimal>,
     {
         let order: OrderRequest = OrderRequest {
             symbol: symbol.into(),
@@ -239,14 +240,16 @@ impl CoinFuturesAccount {
 
     /// Return general [`AccountInformation`]
     pub async fn account_information(&self) -> Result<AccountInformation> {
-        let payload = build_signed_request(BTreeMap::<String, String>::new(), self.recv_window)?;
+        let payload = self
+            .client
+            .build_signed_request(BTreeMap::<String, String>::new(), self.recv_window)?;
         self.client.get_signed_d("/dapi/v1/account", &payload).await
     }
 
     /// Return account's [`AccountBalance`]
     pub async fn account_balance(&self) -> Result<Vec<AccountBalance>> {
         let parameters = BTreeMap::<String, String>::new();
-        let request = build_signed_request(parameters, self.recv_window)?;
+        let request = self.client.build_signed_request(parameters, self.recv_window)?;
         self.client.get_signed_d("/dapi/v1/balance", request.as_str()).await
     }
 
@@ -259,7 +262,7 @@ impl CoinFuturesAccount {
         parameters.insert("symbol".into(), symbol.into());
         parameters.insert("leverage".into(), leverage.to_string());
 
-        let request = build_signed_request(parameters, self.recv_window)?;
+        let request = self.client.build_signed_request(parameters, self.recv_window)?;
         self.client.post_signed_d("/dapi/v1/leverage", request.as_str()).await
     }
 
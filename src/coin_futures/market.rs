@@ -1,8 +1,7 @@
 use crate::client::*;
 use crate::coin_futures::rest_model::*;
 use crate::errors::*;
-use crate::rest_model::{BookTickers, KlineSummaries, KlineSummary, PairAndWindowQuery, PairQuery, SymbolPrice, Tickers};
-use crate::util::*;
+use crate::rest_model::{BookTickers, KlineSummaries, PairAndWindowQuery, PairQuery, SymbolPrice, Tickers};
 use serde_json::Value;
 
 #[derive(Clone)]
@@ -131,7 +130,8 @@ impl CoinFuturesMarket {
     ) -> Result<Vec<OpenInterestHistory>>
     where
         S1: Into<String>,
-        S2: Into<String>,
+        S2: TryInto<DataPeriod>,
+        Error: From<S2::Error>,
         S3: Into<Option<u64>>,
         S4: Into<Option<u64>>,
         S5: Into<u16>,
@@ -141,11 +141,10 @@ impl CoinFuturesMarket {
             end_time: end_time.into(),
             limit: limit.into(),
             symbol: symbol.into(),
-            period: Some(period.into()),
+            period: Some(period.try_into()?),
             from_id: None,
             interval: None,
         };
-        query.validate()?;
         self.client
             .get_signed_p("/futures/data/openInterestHist", Some(query), self.recv_window)
             .await
@@ -162,7 +161,8 @@ impl CoinFuturesMarket {
     ) -> Result<Vec<LongShortRatio>>
     where
         S1: Into<String>,
-        S2: Into<String>,
+        S2: TryInto<DataPeriod>,
+        Error: From<S2::Error>,
         S3: Into<Option<u64>>,
         S4: Into<Option<u64>>,
         S5: Into<u16>,
@@ -172,11 +172,10 @@ impl CoinFuturesMarket {
             end_time: end_time.into(),
             limit: limit.into(),
             symbol: symbol.into(),
-            period: Some(period.into()),
+            period: Some(period.try_into()?),
             from_id: None,
             interval: None,
         };
-        query.validate()?;
         self.client
             .get_signed_p("/futures/data/topLongShortAccountRatio", Some(query), self.recv_window)
             .await
@@ -193,7 +192,8 @@ impl CoinFuturesMarket {
     ) -> Result<Vec<LongShortRatio>>
     where
         S1: Into<String>,
-        S2: Into<String>,
+        S2: TryInto<DataPeriod>,
+        Error: From<S2::Error>,
         S3: Into<Option<u64>>,
         S4: Into<Option<u64>>,
         S5: Into<u16>,
@@ -203,11 +203,10 @@ impl CoinFuturesMarket {
             end_time: end_time.into(),
             limit: limit.into(),
             symbol: symbol.into(),
-            period: Some(period.into()),
+            period: Some(period.try_into()?),
             from_id: None,
             interval: None,
         };
-        query.validate()?;
         self.client
             .get_signed_p("/futures/data/topLongShortPositionRatio", Some(query), self.recv_window)
             .await
@@ -224,7 +223,8 @@ impl CoinFuturesMarket {
     ) -> Result<Vec<LongShortRatio>>
     where
         S1: Into<String>,
-        S2: Into<String>,
+        S2: TryInto<DataPeriod>,
+        Error: From<S2::Error>,
         S3: Into<Option<u64>>,
         S4: Into<Option<u64>>,
         S5: Into<u16>,
@@ -234,11 +234,10 @@ impl CoinFuturesMarket {
             end_time: end_time.into(),
             limit: limit.into(),
             symbol: symbol.into(),
-            period: Some(period.into()),
+            period: Some(period.try_into()?),
             from_id: None,
             interval: None,
         };
-        query.validate()?;
         self.client
             .get_signed_p(
                 "/futures/data/globalLongShortAccountRatio",
@@ -260,7 +259,8 @@ impl CoinFuturesMarket {
     ) -> Result<Vec<LongShortRatio>>
     where
         S1: Into<String>,
-        S2: Into<String>,
+        S2: TryInto<DataPeriod>,
+        Error: From<S2::Error>,
         S3: Into<Option<u64>>,
         S4: Into<Option<u64>>,
         S5: Into<u16>,
@@ -270,11 +270,10 @@ impl CoinFuturesMarket {
             end_time: end_time.into(),
             limit: limit.into(),
             symbol: symbol.into(),
-            period: Some(period.into()),
+            period: Some(period.try_into()?),
             from_id: None,
             interval: None,
         };
-        query.validate()?;
         self.client
             .get_signed_p("/futures/data/takerBuySellVol", Some(query), self.recv_window)
             .await
@@ -291,7 +290,8 @@ impl CoinFuturesMarket {
     ) -> Result<KlineSummaries>
     where
         S1: Into<String>,
-        S2: Into<String>,
+        S2: TryInto<KlineInterval>,
+        Error: From<S2::Error>,
         S3: Into<u16>,
         S4: Into<Option<u64>>,
         S5: Into<Option<u64>>,
@@ -301,33 +301,47 @@ impl CoinFuturesMarket {
             end_time: end_time.into(),
             limit: limit.into(),
             symbol: symbol.into(),
-            interval: Some(interval.into()),
+            interval: Some(interval.try_into()?),
             from_id: None,
             period: None,
         };
-        let data: Vec<Vec<Value>> = self.client.get_d("/dapi/v1/klines", Some(query)).await?;
-
-        let klines = KlineSummaries::AllKlineSummaries(
-            data.iter()
-                .map(|row| KlineSummary {
-                    open_time: to_i64(&row[0]),
-                    open: to_f64(&row[1]),
-                    high: to_f64(&row[2]),
-                    low: to_f64(&row[3]),
-                    close: to_f64(&row[4]),
-                    volume: to_f64(&row[5]),
-                    close_time: to_i64(&row[6]),
-                    quote_asset_volume: to_f64(&row[7]),
-                    number_of_trades: to_i64(&row[8]),
-                    taker_buy_base_asset_volume: to_f64(&row[9]),
-                    taker_buy_quote_asset_volume: to_f64(&row[10]),
-                })
-                .collect(),
-        );
-        Ok(klines)
+        let klines = self.client.get_d("/dapi/v1/klines", Some(query)).await?;
+
+        Ok(KlineSummaries::AllKlineSummaries(klines))
     }
 
     /// Returns up to 'limit' mark price klines for given symbol and interval ("1m", "5m", ...)
+    pub async fn get_mark_price_klines<S1, S2, S3, S4, S5>(
+        &self,
+        symbol: S1,
+        interval: S2,
+        limit: S3,
+        start_time: S4,
+        end_time: S5,
+    ) -> Result<KlineSummaries>
+    where
+        S1: Into<String>,
+        S2: TryInto<KlineInterval>,
+        Error: From<S2::Error>,
+        S3: Into<u16>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let query = HistoryQuery {
+            start_time: start_time.into(),
+            end_time: end_time.into(),
+            limit: limit.into(),
+            symbol: symbol.into(),
+            interval: Some(interval.try_into()?),
+            from_id: None,
+            period: None,
+        };
+        let klines = self.client.get_d("/dapi/v1/markPriceKlines", Some(query)).await?;
+
+        Ok(KlineSummaries::AllKlineSummaries(klines))
+    }
+
+    /// Raw rows of [`get_mark_price_klines`](Self::get_mark_price_klines)
     pub async fn get_mark_price_klines_v<S1, S2, S3, S4, S5>(
         &self,
         symbol: S1,
@@ -338,7 +352,8 @@ impl CoinFuturesMarket {
     ) -> Result<Vec<Vec<Value>>>
     where
         S1: Into<String>,
-        S2: Into<String>,
+        S2: TryInto<KlineInterval>,
+        Error: From<S2::Error>,
         S3: Into<u16>,
         S4: Into<Option<u64>>,
         S5: Into<Option<u64>>,
@@ -348,7 +363,7 @@ impl CoinFuturesMarket {
             end_time: end_time.into(),
             limit: limit.into(),
             symbol: symbol.into(),
-            interval: Some(interval.into()),
+            interval: Some(interval.try_into()?),
             from_id: None,
             period: None,
         };
@@ -358,6 +373,36 @@ impl CoinFuturesMarket {
     }
 
     /// Returns up to 'limit' index price klines for given pair and interval ("1m", "5m", ...)
+    pub async fn get_index_price_klines<S1, S2, S3, S4, S5>(
+        &self,
+        pair: S1,
+        interval: S2,
+        limit: S3,
+        start_time: S4,
+        end_time: S5,
+    ) -> Result<KlineSummaries>
+    where
+        S1: Into<String>,
+        S2: TryInto<KlineInterval>,
+        Error: From<S2::Error>,
+        S3: Into<u16>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let query = IndexQuery {
+            start_time: start_time.into(),
+            end_time: end_time.into(),
+            limit: limit.into(),
+            pair: pair.into(),
+            interval: Some(interval.try_into()?),
+        };
+
+        let klines = self.client.get_d("/dapi/v1/indexPriceKlines", Some(query)).await?;
+
+        Ok(KlineSummaries::AllKlineSummaries(klines))
+    }
+
+    /// Raw rows of [`get_index_price_klines`](Self::get_index_price_klines)
     pub async fn get_index_price_klines_v<S1, S2, S3, S4, S5>(
         &self,
         pair: S1,
@@ -368,7 +413,8 @@ impl CoinFuturesMarket {
     ) -> Result<Vec<Vec<Value>>>
     where
         S1: Into<String>,
-        S2: Into<String>,
+        S2: TryInto<KlineInterval>,
+        Error: From<S2::Error>,
         S3: Into<u16>,
         S4: Into<Option<u64>>,
         S5: Into<Option<u64>>,
@@ -378,7 +424,7 @@ impl CoinFuturesMarket {
             end_time: end_time.into(),
             limit: limit.into(),
             pair: pair.into(),
-            interval: Some(interval.into()),
+            interval: Some(interval.try_into()?),
         };
 
         let klines = self.client.get_d("/dapi/v1/indexPriceKlines", Some(query)).await?;
@@ -387,6 +433,35 @@ impl CoinFuturesMarket {
     }
 
     /// Returns up to 'limit' continuous contract klines for given pair and interval ("1m", "5m", ...)
+    pub async fn get_continuous_contract_klines<S1, S2, S3, S4, S5>(
+        &self,
+        pair: S1,
+        interval: S2,
+        limit: S3,
+        start_time: S4,
+        end_time: S5,
+    ) -> Result<KlineSummaries>
+    where
+        S1: Into<String>,
+        S2: TryInto<KlineInterval>,
+        Error: From<S2::Error>,
+        S3: Into<u16>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let query = IndexQuery {
+            start_time: start_time.into(),
+            end_time: end_time.into(),
+            limit: limit.into(),
+            pair: pair.into(),
+            interval: Some(interval.try_into()?),
+        };
+        let klines = self.client.get_d("/dapi/v1/continuousKlines", Some(query)).await?;
+
+        Ok(KlineSummaries::AllKlineSummaries(klines))
+    }
+
+    /// Raw rows of [`get_continuous_contract_klines`](Self::get_continuous_contract_klines)
     pub async fn get_continuous_contract_klines_v<S1, S2, S3, S4, S5>(
         &self,
         pair: S1,
@@ -397,7 +472,8 @@ impl CoinFuturesMarket {
     ) -> Result<Vec<Vec<Value>>>
     where
         S1: Into<String>,
-        S2: Into<String>,
+        S2: TryInto<KlineInterval>,
+        Error: From<S2::Error>,
         S3: Into<u16>,
         S4: Into<Option<u64>>,
         S5: Into<Option<u64>>,
@@ -407,7 +483,7 @@ impl CoinFuturesMarket {
             end_time: end_time.into(),
             limit: limit.into(),
             pair: pair.into(),
-            interval: Some(interval.into()),
+            interval: Some(interval.try_into()?),
         };
         let klines = self.client.get_d("/dapi/v1/continuousKlines", Some(query)).await?;
 
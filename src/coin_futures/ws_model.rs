@@ -1,5 +1,6 @@
 use crate::coin_futures::rest_model::{MarginType, OrderType, PositionSide, WorkingType};
-use crate::rest_model::{string_or_float, string_or_float_opt, ExecutionType, OrderSide, OrderStatus, TimeInForce};
+use crate::rest_model::{string_or_float, string_or_float_opt, Decimal, ExecutionType, OrderSide, OrderStatus,
+                        TimeInForce};
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE", tag = "e")]
@@ -55,11 +56,11 @@ pub struct Balance {
     #[serde(rename = "a")]
     pub asset: String,
     #[serde(rename = "wb", with = "string_or_float")]
-    pub wallet_balance: f64,
+    pub wallet_balance: Decimal,
     #[serde(rename = "cw", with = "string_or_float")]
-    pub cross_wallet_balance: f64,
+    pub cross_wallet_balance: Decimal,
     #[serde(rename = "bc", with = "string_or_float")]
-    pub balance_change: f64,
+    pub balance_change: Decimal,
 }
 
 #[derive(Debug, Deserialize)]
@@ -67,19 +68,19 @@ pub struct Position {
     #[serde(rename = "s")]
     pub symbol: String,
     #[serde(rename = "pa", with = "string_or_float")]
-    pub position_amount: f64,
+    pub position_amount: Decimal,
     #[serde(rename = "ep", with = "string_or_float")]
-    pub entry_price: f64,
+    pub entry_price: Decimal,
     #[serde(rename = "bep", with = "string_or_float")]
-    pub breakeven_price: f64,
+    pub breakeven_price: Decimal,
     #[serde(rename = "cr", with = "string_or_float")]
-    pub accumulated_realized: f64,
+    pub accumulated_realized: Decimal,
     #[serde(rename = "up", with = "string_or_float")]
-    pub unrealized_profit: f64,
+    pub unrealized_profit: Decimal,
     #[serde(rename = "mt")]
     pub margin_type: MarginType,
     #[serde(rename = "iw", with = "string_or_float")]
-    pub isolated_wallet: f64,
+    pub isolated_wallet: Decimal,
     #[serde(rename = "ps")]
     pub position_side: PositionSide,
 }
@@ -107,13 +108,13 @@ pub struct Order {
     #[serde(rename = "f")]
     pub time_in_force: TimeInForce,
     #[serde(rename = "q", with = "string_or_float")]
-    pub quantity: f64,
+    pub quantity: Decimal,
     #[serde(rename = "p", with = "string_or_float")]
-    pub price: f64,
+    pub price: Decimal,
     #[serde(rename = "ap", with = "string_or_float")]
-    pub average_price: f64,
+    pub average_price: Decimal,
     #[serde(rename = "sp", with = "string_or_float")]
-    pub stop_price: f64,
+    pub stop_price: Decimal,
     #[serde(rename = "x")]
     pub execution_type: ExecutionType,
     #[serde(rename = "X")]
@@ -121,13 +122,13 @@ pub struct Order {
     #[serde(rename = "i")]
     pub order_id: u64,
     #[serde(rename = "l", with = "string_or_float")]
-    pub order_last_filled_quantity: f64,
+    pub order_last_filled_quantity: Decimal,
     #[serde(rename = "z", with = "string_or_float")]
-    pub order_filled_accumulated_quantity: f64,
+    pub order_filled_accumulated_quantity: Decimal,
     #[serde(rename = "L", with = "string_or_float")]
-    pub last_filled_price: f64,
+    pub last_filled_price: Decimal,
     #[serde(default, rename = "n", with = "string_or_float_opt")]
-    pub commission: Option<f64>,
+    pub commission: Option<Decimal>,
     #[serde(rename = "N")]
     pub commission_asset: Option<String>,
     #[serde(rename = "T")]
@@ -147,11 +148,11 @@ pub struct Order {
     #[serde(rename = "cp")]
     pub close_position: bool,
     #[serde(default, rename = "AP", with = "string_or_float_opt")]
-    pub activation_price: Option<f64>,
+    pub activation_price: Option<Decimal>,
     #[serde(default, rename = "cr", with = "string_or_float_opt")]
-    pub callback_rate: Option<f64>,
+    pub callback_rate: Option<Decimal>,
     #[serde(rename = "pP")]
     pub price_protect: bool,
     #[serde(rename = "rp", with = "string_or_float")]
-    pub realized_profit: f64,
+    pub realized_profit: Decimal,
 }
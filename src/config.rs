@@ -1,10 +1,110 @@
-pub static DATA_REST_ENDPOINT: &str = "https://api.binance.com";
+use crate::credentials::SharedCredentials;
+use crate::errors::*;
+use crate::failover::Failover;
+use crate::proxy::Proxy;
+use crate::rate_limit::{RateLimiter, RetryPolicy};
+use crate::signer::{SharedSigner, Signer};
+use crate::time_sync::TimeSync;
+use crate::transport::{SharedTransport, Transport};
+use crate::websockets::ReconnectPolicy;
+
+/// Public spot market data only
+pub static DATA_REST_ENDPOINT: &str = "https://data-api.binance.vision";
+pub static DATA_WS_ENDPOINT: &str = "wss://data-stream.binance.vision";
+
+/// Binance deployments, see [`Config::for_environment`]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Environment {
+    #[default]
+    Production,
+    /// Production with the alternate spot hosts api1 to api4.binance.com, which may perform better
+    Api1,
+    Api2,
+    Api3,
+    Api4,
+    /// Spot, USD-M and COIN-M futures testnets
+    Testnet,
+    /// Binance.US, spot and SAPI endpoints
+    BinanceUs,
+    /// Binance TR, spot only
+    BinanceTr,
+    /// Public spot market data and streams, without credentials
+    DataOnly,
+}
+
+/// Product lines, not every environment provides all of them
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Product {
+    /// Spot REST endpoints (/api) and streams
+    Spot,
+    /// Spot websocket API
+    WsApi,
+    /// Wallet, margin and savings endpoints (/sapi)
+    Sapi,
+    /// USD-M futures (/fapi)
+    UsdMFutures,
+    /// COIN-M futures (/dapi)
+    CoinMFutures,
+    /// Options (/eapi)
+    Options,
+    /// Portfolio margin (/papi)
+    PortfolioMargin,
+}
+
+impl Environment {
+    /// Whether `product` is available in this environment
+    pub fn supports(self, product: Product) -> bool {
+        use Environment::*;
+        match self {
+            Production | Api1 | Api2 | Api3 | Api4 => true,
+            Testnet => matches!(
+                product,
+                Product::Spot | Product::WsApi | Product::UsdMFutures | Product::CoinMFutures
+            ),
+            BinanceUs => matches!(product, Product::Spot | Product::WsApi | Product::Sapi),
+            BinanceTr | DataOnly => product == Product::Spot,
+        }
+    }
+
+    /// Fails with [`Error::UnsupportedEndpoint`] if `product` is not available in this environment
+    pub fn check(self, product: Product) -> Result<()> {
+        match self.supports(product) {
+            true => Ok(()),
+            false => Err(Error::UnsupportedEndpoint {
+                environment: self,
+                endpoint: format!("{product:?}"),
+            }),
+        }
+    }
+
+    /// Fails if the REST `endpoint` is not available in this environment, `authenticated` for requests
+    /// sent with the API key
+    pub(crate) fn check_endpoint(self, endpoint: &str, authenticated: bool) -> Result<()> {
+        let product = match endpoint.split('/').nth(1) {
+            Some("sapi") => Product::Sapi,
+            Some("fapi") => Product::UsdMFutures,
+            Some("dapi") => Product::CoinMFutures,
+            Some("eapi") => Product::Options,
+            Some("papi") => Product::PortfolioMargin,
+            _ => Product::Spot,
+        };
+        if !self.supports(product) || (self == Environment::DataOnly && authenticated) {
+            return Err(Error::UnsupportedEndpoint {
+                environment: self,
+                endpoint: endpoint.to_string(),
+            });
+        }
+        Ok(())
+    }
+}
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Config {
     /// Spot
     pub rest_api_endpoint: String,
     pub ws_endpoint: String,
+    /// Spot websocket API
+    pub ws_api_endpoint: String,
 
     /// USD-M Futures
     pub futures_rest_api_endpoint: String,
@@ -26,7 +126,37 @@ pub struct Config {
 
     pub binance_us_api: bool,
 
+    /// Deployment of the endpoints, endpoints of products it does not provide are refused
+    pub environment: Environment,
+
     pub timeout: Option<u64>,
+
+    /// Websocket reconnection, disabled by default
+    pub ws_reconnect_policy: Option<ReconnectPolicy>,
+
+    /// Signs requests instead of the secret key
+    pub signer: Option<SharedSigner>,
+
+    /// Credentials of the clients instead of the keys given to their constructors
+    pub credentials: Option<SharedCredentials>,
+
+    /// Client side rate limiting, disabled by default
+    pub rate_limiter: Option<RateLimiter>,
+
+    /// Retries of rate limited GET requests, disabled by default
+    pub retry_policy: Option<RetryPolicy>,
+
+    /// Server clock of signed requests, the local clock by default
+    pub time_sync: Option<TimeSync>,
+
+    /// Sends the REST requests instead of reqwest
+    pub transport: Option<SharedTransport>,
+
+    /// Proxy of the REST and websocket connections
+    pub proxy: Option<Proxy>,
+
+    /// Failover across hosts of the REST clients, disabled by default
+    pub failover: Option<Failover>,
 }
 
 impl Config {
@@ -36,12 +166,54 @@ impl Config {
     /// use binance::config::Config;
     /// let config = Config::testnet();
     /// ```
-    pub fn testnet() -> Config {
-        Config::default()
-            .set_rest_api_endpoint("https://testnet.binance.vision")
-            .set_ws_endpoint("wss://testnet.binance.vision")
-            .set_futures_rest_api_endpoint("https://testnet.binancefuture.com")
-            .set_futures_ws_endpoint("wss://fstream.binancefuture.com")
+    pub fn testnet() -> Config { Config::for_environment(Environment::Testnet) }
+
+    /// Configure every endpoint for `environment`. Requests and streams of products the environment
+    /// does not provide fail with [`Error::UnsupportedEndpoint`].
+    ///
+    /// # Examples
+    /// ```
+    /// use binance::config::{Config, Environment, Product};
+    /// let config = Config::for_environment(Environment::BinanceUs);
+    /// assert_eq!(config.rest_api_endpoint, "https://api.binance.us");
+    /// assert!(config.environment.check(Product::UsdMFutures).is_err());
+    /// ```
+    pub fn for_environment(environment: Environment) -> Config {
+        let config = Config {
+            environment,
+            binance_us_api: environment == Environment::BinanceUs,
+            ..Config::default()
+        };
+        let alternate = |n: u8| {
+            config
+                .clone()
+                .set_rest_api_endpoint(format!("https://api{n}.binance.com"))
+        };
+        match environment {
+            Environment::Production => config,
+            Environment::Api1 => alternate(1),
+            Environment::Api2 => alternate(2),
+            Environment::Api3 => alternate(3),
+            Environment::Api4 => alternate(4),
+            Environment::Testnet => config
+                .set_rest_api_endpoint("https://testnet.binance.vision")
+                .set_ws_endpoint("wss://testnet.binance.vision")
+                .set_ws_api_endpoint("wss://ws-api.testnet.binance.vision/ws-api/v3")
+                .set_futures_rest_api_endpoint("https://testnet.binancefuture.com")
+                .set_futures_ws_endpoint("wss://fstream.binancefuture.com")
+                .set_coin_futures_rest_api_endpoint("https://testnet.binancefuture.com")
+                .set_coin_futures_ws_endpoint("wss://dstream.binancefuture.com"),
+            Environment::BinanceUs => config
+                .set_rest_api_endpoint("https://api.binance.us")
+                .set_ws_endpoint("wss://stream.binance.us:9443")
+                .set_ws_api_endpoint("wss://ws-api.binance.us:443/ws-api/v3"),
+            Environment::BinanceTr => config
+                .set_rest_api_endpoint("https://api.binance.me")
+                .set_ws_endpoint("wss://stream-cloud.binance.tr"),
+            Environment::DataOnly => config
+                .set_rest_api_endpoint(DATA_REST_ENDPOINT)
+                .set_ws_endpoint(DATA_WS_ENDPOINT),
+        }
     }
 
     /// Sets the rest api endpoint. Defaults to <https://api.binance.com>.
@@ -84,6 +256,26 @@ impl Config {
         self
     }
 
+    /// Sets the websocket API endpoint. Defaults to "wss://ws-api.binance.com:443/ws-api/v3".
+    ///
+    /// # Arguments
+    ///
+    /// * `ws_api_endpoint`:
+    ///
+    /// returns: Config
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use binance::config::Config;
+    /// let config = Config::default();
+    /// config.set_ws_api_endpoint("ws://myendpoint:8080");
+    /// ```
+    pub fn set_ws_api_endpoint<T: Into<String>>(mut self, ws_api_endpoint: T) -> Self {
+        self.ws_api_endpoint = ws_api_endpoint.into();
+        self
+    }
+
     /// Sets the futures rest api endpoint. Defaults to <https://fapi.binance.com>.
     ///
     /// # Arguments
@@ -194,6 +386,209 @@ impl Config {
         self.timeout = Some(timeout);
         self
     }
+
+    /// Sets the websocket reconnection policy.
+    /// When set, websockets transparently reconnect to the same streams when the server closes the
+    /// connection, which Binance does at least every 24 hours.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy`: The reconnection policy
+    ///
+    /// returns: Config
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use binance::config::Config;
+    /// use binance::websockets::ReconnectPolicy;
+    /// let config = Config::default();
+    /// config.set_ws_reconnect_policy(ReconnectPolicy::default());
+    /// ```
+    pub fn set_ws_reconnect_policy(mut self, policy: ReconnectPolicy) -> Self {
+        self.ws_reconnect_policy = Some(policy);
+        self
+    }
+
+    /// Sets the signer of signed requests.
+    /// By default requests are signed with the secret key, using HMAC SHA256 unless it is a PEM encoded
+    /// Ed25519 or RSA private key.
+    ///
+    /// # Arguments
+    ///
+    /// * `signer`: The signer, shared by all clients created with this configuration
+    ///
+    /// returns: Config
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use binance::config::Config;
+    /// use binance::signer::HmacSigner;
+    /// let config = Config::default();
+    /// config.set_signer(HmacSigner::new("secret_key"));
+    /// ```
+    pub fn set_signer<S: Signer + 'static>(mut self, signer: S) -> Self {
+        self.signer = Some(SharedSigner(std::sync::Arc::new(signer)));
+        self
+    }
+
+    /// Sets the credentials of the clients, loaded from a provider and shared so that they can be
+    /// rotated without creating the clients again. They take precedence over the keys given to the
+    /// constructors, and the signer of the configuration over their secret key.
+    ///
+    /// # Arguments
+    ///
+    /// * `credentials`: The shared credentials
+    ///
+    /// returns: Config
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use binance::config::Config;
+    /// use binance::credentials::{EnvCredentials, SharedCredentials};
+    /// let config = Config::default();
+    /// config.set_credentials(SharedCredentials::new(EnvCredentials::default()));
+    /// ```
+    pub fn set_credentials(mut self, credentials: SharedCredentials) -> Self {
+        self.credentials = Some(credentials);
+        self
+    }
+
+    /// Sets the client side rate limiter.
+    /// Clients created with this configuration share the limiter, which tracks the usage of each host.
+    ///
+    /// # Arguments
+    ///
+    /// * `rate_limiter`: The rate limiter
+    ///
+    /// returns: Config
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use binance::config::Config;
+    /// use binance::rate_limit::{OnLimit, RateLimiter};
+    /// let config = Config::default();
+    /// config.set_rate_limiter(RateLimiter::new(OnLimit::Delay));
+    /// ```
+    pub fn set_rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// Sets the retry policy of GET requests rejected with HTTP 429 or 418.
+    /// Requests are retried after the delay given by the server, signed GET requests with a fresh
+    /// timestamp. POST, PUT and DELETE requests are never retried.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy`: The retry policy
+    ///
+    /// returns: Config
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use binance::config::Config;
+    /// use binance::rate_limit::RetryPolicy;
+    /// let config = Config::default();
+    /// config.set_retry_policy(RetryPolicy::default());
+    /// ```
+    pub fn set_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(policy);
+        self
+    }
+
+    /// Sets the synchronization of the server clock used to stamp signed requests.
+    /// Clients created with this configuration share the estimated clock offset.
+    ///
+    /// # Arguments
+    ///
+    /// * `time_sync`: The server clock synchronization
+    ///
+    /// returns: Config
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use binance::config::Config;
+    /// use binance::time_sync::TimeSync;
+    /// let config = Config::default();
+    /// config.set_time_sync(TimeSync::default());
+    /// ```
+    pub fn set_time_sync(mut self, time_sync: TimeSync) -> Self {
+        self.time_sync = Some(time_sync);
+        self
+    }
+
+    /// Sets the HTTP transport of the REST clients, reqwest by default.
+    /// The timeout of the configuration only applies to the default transport.
+    ///
+    /// # Arguments
+    ///
+    /// * `transport`: The transport sending the requests
+    ///
+    /// returns: Config
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use binance::config::Config;
+    /// use binance::transport::ReqwestTransport;
+    /// let config = Config::default();
+    /// let transport = ReqwestTransport::new(&config).unwrap();
+    /// config.set_transport(transport);
+    /// ```
+    pub fn set_transport<T: Transport + 'static>(mut self, transport: T) -> Self {
+        self.transport = Some(SharedTransport(std::sync::Arc::new(transport)));
+        self
+    }
+
+    /// Sets the proxy of the REST clients and websockets, HTTP (tunneled with CONNECT) or SOCKS5.
+    /// The proxy only applies to the default REST transport.
+    ///
+    /// # Arguments
+    ///
+    /// * `proxy`: The proxy, optionally with a username and a password
+    ///
+    /// returns: Config
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use binance::config::Config;
+    /// use binance::proxy::Proxy;
+    /// let config = Config::default();
+    /// config.set_proxy(Proxy::http("10.0.0.1", 3128).with_auth("user", "password"));
+    /// ```
+    pub fn set_proxy(mut self, proxy: Proxy) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Sets the failover of the REST clients across hosts.
+    /// It applies to the clients whose endpoint is one of its hosts.
+    ///
+    /// # Arguments
+    ///
+    /// * `failover`: The hosts to fail over across, and their health
+    ///
+    /// returns: Config
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use binance::config::Config;
+    /// use binance::failover::Failover;
+    /// let config = Config::default();
+    /// config.set_failover(Failover::spot());
+    /// ```
+    pub fn set_failover(mut self, failover: Failover) -> Self {
+        self.failover = Some(failover);
+        self
+    }
 }
 
 impl Default for Config {
@@ -207,6 +602,7 @@ impl Default for Config {
         Config {
             rest_api_endpoint: "https://api.binance.com".into(),
             ws_endpoint: "wss://stream.binance.com:9443".into(),
+            ws_api_endpoint: "wss://ws-api.binance.com:443/ws-api/v3".into(),
 
             futures_rest_api_endpoint: "https://fapi.binance.com".into(),
             futures_ws_endpoint: "wss://fstream.binance.com".into(),
@@ -223,7 +619,27 @@ impl Default for Config {
             recv_window: 5000,
             binance_us_api: false,
 
+            environment: Environment::Production,
+
             timeout: None,
+
+            ws_reconnect_policy: None,
+
+            signer: None,
+
+            credentials: None,
+
+            rate_limiter: None,
+
+            retry_policy: None,
+
+            time_sync: None,
+
+            transport: None,
+
+            proxy: None,
+
+            failover: None,
         }
     }
 }
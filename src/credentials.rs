@@ -0,0 +1,270 @@
+//! API credentials
+//!
+//! Credentials are passed to the constructors of the API handles, or loaded by a [`CredentialProvider`]
+//! from the environment, a file or any other source with
+//! [`Config::set_credentials`](crate::config::Config::set_credentials). Handles created with the
+//! configuration share the credentials, and use the new ones as soon as they are refreshed, e.g. after
+//! rotating the API key.
+//!
+//! Keys are held in [`Secret`], which is zeroed when dropped and not printed by `Debug`.
+//!
+//! # Examples
+//! ```rust,no_run
+//! use binance::{account::*, api::*, config::*, credentials::*};
+//! # tokio_test::block_on(async {
+//! let credentials = SharedCredentials::new(FileCredentials::new("/run/secrets/binance.json"));
+//! let conf = Config::default().set_credentials(credentials.clone());
+//! let account: Account = Binance::new_with_config(None, None, &conf);
+//! let info = account.get_account().await;
+//! // After the file was updated with a new key
+//! credentials.refresh().unwrap();
+//! let info = account.get_account().await;
+//! # });
+//! ```
+
+use std::fmt;
+use std::path::PathBuf;
+use std::sync::{Arc, RwLock};
+
+use zeroize::Zeroize;
+
+use crate::config::Config;
+use crate::errors::*;
+use crate::signer::SharedSigner;
+
+/// A string zeroed when dropped, `Debug` does not print it
+#[derive(Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(transparent)]
+pub struct Secret(String);
+
+impl Secret {
+    pub fn new<T: Into<String>>(secret: T) -> Self { Secret(secret.into()) }
+
+    /// The secret itself
+    pub fn expose(&self) -> &str { &self.0 }
+}
+
+impl From<String> for Secret {
+    fn from(secret: String) -> Self { Secret(secret) }
+}
+
+impl From<&str> for Secret {
+    fn from(secret: &str) -> Self { Secret(secret.to_string()) }
+}
+
+impl Drop for Secret {
+    fn drop(&mut self) { self.0.zeroize(); }
+}
+
+impl fmt::Debug for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.write_str("Secret(***)") }
+}
+
+/// An API key and its secret key, or the PEM encoded private key of Ed25519 and RSA API keys
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+pub struct Credentials {
+    pub api_key: Secret,
+    pub secret_key: Secret,
+}
+
+impl Credentials {
+    pub fn new<K: Into<Secret>, S: Into<Secret>>(api_key: K, secret_key: S) -> Self {
+        Credentials {
+            api_key: api_key.into(),
+            secret_key: secret_key.into(),
+        }
+    }
+}
+
+/// Source of the credentials, read again on every [`SharedCredentials::refresh`]
+pub trait CredentialProvider: Send + Sync {
+    fn credentials(&self) -> Result<Credentials>;
+}
+
+/// Credentials from environment variables, `BINANCE_API_KEY` and `BINANCE_API_SECRET_KEY` by default
+#[derive(Clone, Debug)]
+pub struct EnvCredentials {
+    api_key_var: String,
+    secret_key_var: String,
+}
+
+impl EnvCredentials {
+    pub fn new<K: Into<String>, S: Into<String>>(api_key_var: K, secret_key_var: S) -> Self {
+        EnvCredentials {
+            api_key_var: api_key_var.into(),
+            secret_key_var: secret_key_var.into(),
+        }
+    }
+}
+
+impl Default for EnvCredentials {
+    fn default() -> Self { Self::new("BINANCE_API_KEY", "BINANCE_API_SECRET_KEY") }
+}
+
+impl CredentialProvider for EnvCredentials {
+    fn credentials(&self) -> Result<Credentials> {
+        let var = |name: &str| {
+            std::env::var(name)
+                .map(Secret::from)
+                .map_err(|e| Error::CredentialsUnavailable(format!("{name}: {e}")))
+        };
+        Ok(Credentials {
+            api_key: var(&self.api_key_var)?,
+            secret_key: var(&self.secret_key_var)?,
+        })
+    }
+}
+
+/// Credentials from a JSON file, `{"api_key": "...", "secret_key": "..."}`
+#[derive(Clone, Debug)]
+pub struct FileCredentials {
+    path: PathBuf,
+}
+
+impl FileCredentials {
+    pub fn new<P: Into<PathBuf>>(path: P) -> Self { FileCredentials { path: path.into() } }
+}
+
+impl CredentialProvider for FileCredentials {
+    fn credentials(&self) -> Result<Credentials> {
+        let unavailable = |e: &dyn fmt::Display| Error::CredentialsUnavailable(format!("{}: {e}", self.path.display()));
+        let content = Secret::from(std::fs::read_to_string(&self.path).map_err(|e| unavailable(&e))?);
+        serde_json::from_str(content.expose()).map_err(|e| unavailable(&e))
+    }
+}
+
+/// Credentials returned by a function, such as a client of a secrets manager
+pub struct CallbackCredentials<F>(F);
+
+impl<F: Fn() -> Result<Credentials> + Send + Sync> CallbackCredentials<F> {
+    pub fn new(callback: F) -> Self { CallbackCredentials(callback) }
+}
+
+impl<F: Fn() -> Result<Credentials> + Send + Sync> CredentialProvider for CallbackCredentials<F> {
+    fn credentials(&self) -> Result<Credentials> { (self.0)() }
+}
+
+/// Credentials shared by the clients of a configuration, loaded from the provider on first use
+#[derive(Clone)]
+pub struct SharedCredentials {
+    provider: Arc<dyn CredentialProvider>,
+    current: Arc<RwLock<Option<Loaded>>>,
+}
+
+#[derive(Clone)]
+struct Loaded {
+    api_key: Secret,
+    signer: SharedSigner,
+}
+
+impl SharedCredentials {
+    pub fn new<P: CredentialProvider + 'static>(provider: P) -> Self {
+        SharedCredentials {
+            provider: Arc::new(provider),
+            current: Arc::new(RwLock::new(None)),
+        }
+    }
+
+    /// Load the credentials from the provider again, clients use them from their next request
+    pub fn refresh(&self) -> Result<()> {
+        self.load()?;
+        Ok(())
+    }
+
+    /// Replace the credentials until the next refresh
+    pub fn rotate(&self, credentials: Credentials) { *self.current.write().unwrap() = Some(Loaded::new(credentials)); }
+
+    fn load(&self) -> Result<Loaded> {
+        let loaded = Loaded::new(self.provider.credentials()?);
+        *self.current.write().unwrap() = Some(loaded.clone());
+        Ok(loaded)
+    }
+
+    fn loaded(&self) -> Result<Loaded> {
+        let current = self.current.read().unwrap().clone();
+        match current {
+            Some(loaded) => Ok(loaded),
+            None => self.load(),
+        }
+    }
+}
+
+impl Loaded {
+    fn new(credentials: Credentials) -> Self {
+        Loaded {
+            signer: SharedSigner::for_secret_key(credentials.secret_key.expose()),
+            api_key: credentials.api_key,
+        }
+    }
+}
+
+impl fmt::Debug for SharedCredentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.write_str("SharedCredentials") }
+}
+
+impl PartialEq for SharedCredentials {
+    fn eq(&self, other: &Self) -> bool { Arc::ptr_eq(&self.current, &other.current) }
+}
+
+impl Eq for SharedCredentials {}
+
+/// Keys of a client, the credentials of the configuration take precedence over the keys of the
+/// constructor, and the signer of the configuration over the secret keys
+#[derive(Clone)]
+pub(crate) struct ApiKeys {
+    api_key: Secret,
+    signer: SharedSigner,
+    credentials: Option<SharedCredentials>,
+    custom_signer: bool,
+}
+
+impl ApiKeys {
+    pub(crate) fn new(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Self {
+        let secret_key = Secret::from(secret_key.unwrap_or_default());
+        ApiKeys {
+            api_key: Secret::from(api_key.unwrap_or_default()),
+            signer: config
+                .signer
+                .clone()
+                .unwrap_or_else(|| SharedSigner::for_secret_key(secret_key.expose())),
+            credentials: config.credentials.clone(),
+            custom_signer: config.signer.is_some(),
+        }
+    }
+
+    pub(crate) fn api_key(&self) -> Result<Secret> {
+        match &self.credentials {
+            Some(credentials) => Ok(credentials.loaded()?.api_key),
+            None => Ok(self.api_key.clone()),
+        }
+    }
+
+    pub(crate) fn signer(&self) -> Result<SharedSigner> {
+        match &self.credentials {
+            Some(credentials) if !self.custom_signer => Ok(credentials.loaded()?.signer),
+            _ => Ok(self.signer.clone()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn rotated_credentials() {
+        let credentials = SharedCredentials::new(CallbackCredentials::new(|| Ok(Credentials::new("key", "secret"))));
+        let keys = ApiKeys::new(None, None, &Config::default().set_credentials(credentials.clone()));
+        assert_eq!(keys.api_key().unwrap().expose(), "key");
+        let signature = keys.signer().unwrap().sign("payload").unwrap();
+        credentials.rotate(Credentials::new("new_key", "new_secret"));
+        assert_eq!(keys.api_key().unwrap().expose(), "new_key");
+        assert_ne!(keys.signer().unwrap().sign("payload").unwrap(), signature);
+        credentials.refresh().unwrap();
+        assert_eq!(keys.api_key().unwrap().expose(), "key");
+        assert_eq!(
+            format!("{:?}", Credentials::new("key", "secret")),
+            "Credentials { api_key: Secret(***), secret_key: Secret(***) }"
+        );
+    }
+}
@@ -2,6 +2,8 @@ use serde_json::Value;
 use std::collections::HashMap;
 use thiserror::Error;
 
+use crate::filters::FilterViolation;
+
 #[derive(Debug, Deserialize, Error)]
 #[error("code: {code}, msg: {msg}")]
 pub struct BinanceContentError {
@@ -12,6 +14,11 @@ pub struct BinanceContentError {
     extra: HashMap<String, Value>,
 }
 
+impl BinanceContentError {
+    /// The documented error code, None for unknown codes
+    pub fn error_code(&self) -> Option<BinanceErrorCode> { BinanceErrorCode::from_code(self.code) }
+}
+
 /// First errors are technical errors
 /// All unhandled binance content errors are BinanceError
 /// The rest are binance content errors that are properly handled
@@ -32,8 +39,9 @@ pub enum Error {
     Json(#[from] serde_json::Error),
     #[error(transparent)]
     Qs(#[from] serde_qs::Error),
-    #[error(transparent)]
-    Tungstenite(#[from] tokio_tungstenite::tungstenite::Error),
+    /// Failure of the websocket implementation, which is its source
+    #[error("websocket error: {0}")]
+    WebSocket(#[source] Box<dyn std::error::Error + Send + Sync>),
     #[error(transparent)]
     TimestampError(#[from] std::time::SystemTimeError),
     #[error(transparent)]
@@ -49,23 +57,606 @@ pub enum Error {
     UnknownSymbol(String),
     #[error("{msg}")]
     InvalidOrderError { msg: String },
+    #[error("order rejected by the symbol filters: {}", display_violations(.0))]
+    FilterViolations(Vec<FilterViolation>),
     #[error("invalid price")]
     InvalidPrice,
+    #[error("invalid private key: {0}")]
+    InvalidKey(String),
+    #[error("credentials unavailable: {0}")]
+    CredentialsUnavailable(String),
     #[error("invalid period {0}")]
     InvalidPeriod(String),
+    #[error("invalid kline interval {0}")]
+    InvalidInterval(String),
     #[error("internal server error")]
     InternalServerError,
     #[error("service unavailable")]
     ServiceUnavailable,
     #[error("Unauthorized")]
     Unauthorized,
+    /// HTTP 429, requests are rejected until the limit's interval is over
+    #[error("rate limited, retry after {retry_after:?}")]
+    RateLimited {
+        retry_after: Option<std::time::Duration>,
+        response: Option<BinanceContentError>,
+    },
+    /// HTTP 418, the IP is banned for repeatedly exceeding rate limits
+    #[error("IP banned until {until:?}")]
+    IpBanned {
+        /// End of the ban in milliseconds since the epoch
+        until: Option<u64>,
+        response: Option<BinanceContentError>,
+    },
+    #[error("{endpoint} is not available on {environment:?}")]
+    UnsupportedEndpoint {
+        environment: crate::config::Environment,
+        endpoint: String,
+    },
+    #[error("rate limit budget exceeded, retry after {retry_after:?}")]
+    RateLimitBudgetExceeded { retry_after: std::time::Duration },
     #[error("{0}")]
     Msg(String),
 }
 
+impl Error {
+    /// The error code returned by Binance, if any
+    pub fn error_code(&self) -> Option<BinanceErrorCode> {
+        match self {
+            Error::BinanceError { response }
+            | Error::RateLimited {
+                response: Some(response),
+                ..
+            }
+            | Error::IpBanned {
+                response: Some(response),
+                ..
+            } => response.error_code(),
+            Error::InvalidPrice => Some(BinanceErrorCode::FilterFailure),
+            Error::InvalidListenKey(_) => Some(BinanceErrorCode::InvalidListenKey),
+            _ => None,
+        }
+    }
+}
+
+/// Conversions that cannot fail, e.g. passing a [`KlineInterval`](crate::rest_model::KlineInterval) where
+/// its string is also accepted
+impl From<std::convert::Infallible> for Error {
+    fn from(e: std::convert::Infallible) -> Self { match e {} }
+}
+
+fn display_violations(violations: &[FilterViolation]) -> String {
+    violations
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Documented error codes of the spot and futures APIs
+///
+/// Some codes are specific to the futures APIs, and a code can come with different messages depending on
+/// the cause, see [`BinanceContentError::msg`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(i32)]
+pub enum BinanceErrorCode {
+    /// An unknown error occurred while processing the request
+    Unknown = -1000,
+    /// Internal error, unable to process the request
+    Disconnected = -1001,
+    /// Not authorized to execute this request
+    Unauthorized = -1002,
+    /// Too many requests, the rate limit was exceeded
+    TooManyRequests = -1003,
+    /// Unexpected response from the message bus, the execution status is unknown
+    UnexpectedResponse = -1006,
+    /// Timeout waiting for the backend, the execution status is unknown
+    Timeout = -1007,
+    /// The server is overloaded, only reduce only and close position orders are accepted
+    ServerBusy = -1008,
+    /// The request was rejected by a filter of the symbol, such as the price or the lot size
+    FilterFailure = -1013,
+    /// Unsupported order combination
+    UnknownOrderComposition = -1014,
+    /// Too many new orders, the order rate limit was exceeded
+    TooManyOrders = -1015,
+    /// The service is no longer available
+    ServiceShuttingDown = -1016,
+    /// This operation is not supported
+    UnsupportedOperation = -1020,
+    /// The timestamp is outside of the receive window or ahead of the server time
+    InvalidTimestamp = -1021,
+    /// The signature of the request is not valid
+    InvalidSignature = -1022,
+    /// Not found, unauthenticated, or unauthorized
+    NotFoundAuthenticatedOrAuthorized = -1099,
+    /// Illegal characters found in a parameter
+    IllegalChars = -1100,
+    /// Too many parameters sent, or duplicated values
+    TooManyParameters = -1101,
+    /// A mandatory parameter was not sent, was empty or malformed
+    MandatoryParamEmptyOrMalformed = -1102,
+    /// An unknown parameter was sent
+    UnknownParam = -1103,
+    /// Not all sent parameters were read
+    UnreadParameters = -1104,
+    /// A parameter was empty
+    ParamEmpty = -1105,
+    /// A parameter was sent when not required
+    ParamNotRequired = -1106,
+    /// A parameter overflowed
+    ParamOverflow = -1108,
+    /// The precision is over the maximum defined for the asset
+    BadPrecision = -1111,
+    /// No orders on the book for the symbol
+    NoDepth = -1112,
+    /// The time in force parameter was sent when not required
+    TifNotRequired = -1114,
+    /// Invalid time in force
+    InvalidTif = -1115,
+    /// Invalid order type
+    InvalidOrderType = -1116,
+    /// Invalid side
+    InvalidSide = -1117,
+    /// The new client order id was empty
+    EmptyNewClientOrderId = -1118,
+    /// The original client order id was empty
+    EmptyOrigClientOrderId = -1119,
+    /// Invalid interval
+    BadInterval = -1120,
+    /// Invalid symbol
+    BadSymbol = -1121,
+    /// Invalid symbol status
+    InvalidSymbolStatus = -1122,
+    /// The listen key does not exist
+    InvalidListenKey = -1125,
+    /// The lookup interval is too big
+    MoreThanXxHours = -1127,
+    /// Invalid combination of optional parameters
+    OptionalParamsBadCombo = -1128,
+    /// Invalid data sent for a parameter
+    InvalidParameter = -1130,
+    /// The receive window must be less than 60000
+    BadRecvWindow = -1131,
+    /// The strategy type was less than 1000000
+    BadStrategyType = -1134,
+    /// Invalid JSON request
+    InvalidJson = -1135,
+    /// Invalid ticker type
+    InvalidTickerType = -1139,
+    /// Invalid cancel restrictions
+    InvalidCancelRestrictions = -1145,
+    /// A symbol is present multiple times in the list
+    DuplicateSymbols = -1151,
+    /// The new order was rejected, most often because of an insufficient balance
+    NewOrderRejected = -2010,
+    /// The cancellation was rejected, most often because the order is unknown
+    CancelRejected = -2011,
+    /// The order does not exist
+    NoSuchOrder = -2013,
+    /// The API key format is invalid
+    BadApiKeyFormat = -2014,
+    /// Invalid API key, IP, or permissions for the action
+    RejectedApiKey = -2015,
+    /// No trading window could be found for the symbol
+    NoTradingWindow = -2016,
+    /// The balance is insufficient
+    BalanceNotSufficient = -2018,
+    /// The margin is insufficient
+    MarginNotSufficient = -2019,
+    /// Unable to fill the order
+    UnableToFill = -2020,
+    /// The order would immediately trigger
+    OrderWouldImmediatelyTrigger = -2021,
+    /// The reduce only order was rejected
+    ReduceOnlyReject = -2022,
+    /// The user is being liquidated
+    UserInLiquidation = -2023,
+    /// The position is insufficient
+    PositionNotSufficient = -2024,
+    /// The maximum number of open orders is reached
+    MaxOpenOrderExceeded = -2025,
+    /// The order was canceled or expired with no executed quantity over 90 days ago and has been archived
+    OrderArchived = -2026,
+    /// The maximum position at the current leverage is exceeded
+    MaxLeverageRatio = -2027,
+    /// The leverage is smaller than permitted, insufficient margin balance
+    MinLeverageRatio = -2028,
+    /// Invalid order status
+    InvalidOrderStatus = -4000,
+    /// The price is less than 0
+    PriceLessThanZero = -4001,
+    /// The price is greater than the maximum price
+    PriceGreaterThanMaxPrice = -4002,
+    /// The quantity is less than 0
+    QtyLessThanZero = -4003,
+    /// The quantity is less than the minimum quantity
+    QtyLessThanMinQty = -4004,
+    /// The quantity is greater than the maximum quantity
+    QtyGreaterThanMaxQty = -4005,
+    /// The stop price is less than 0
+    StopPriceLessThanZero = -4006,
+    /// The stop price is greater than the maximum price
+    StopPriceGreaterThanMaxPrice = -4007,
+    /// The tick size is less than 0
+    TickSizeLessThanZero = -4008,
+    /// The maximum price is less than the minimum price
+    MaxPriceLessThanMinPrice = -4009,
+    /// The maximum quantity is less than the minimum quantity
+    MaxQtyLessThanMinQty = -4010,
+    /// The step size is less than 0
+    StepSizeLessThanZero = -4011,
+    /// The maximum number of orders is less than 0
+    MaxNumOrdersLessThanZero = -4012,
+    /// The price is less than the minimum price
+    PriceLessThanMinPrice = -4013,
+    /// The price is not a multiple of the tick size
+    PriceNotIncreasedByTickSize = -4014,
+    /// The client order id is too long
+    InvalidClientOrderIdLen = -4015,
+    /// The price is higher than the mark price multiplier cap
+    PriceHigherThanMultiplierUp = -4016,
+    /// The quantity is not a multiple of the step size
+    QtyNotIncreasedByStepSize = -4023,
+    /// The price is lower than the mark price multiplier floor
+    PriceLowerThanMultiplierDown = -4024,
+    /// Invalid leverage
+    InvalidLeverage = -4028,
+    /// Invalid tick size precision
+    InvalidTickSizePrecision = -4029,
+    /// Invalid step size precision
+    InvalidStepSizePrecision = -4030,
+    /// Invalid working type
+    InvalidWorkingType = -4031,
+    /// The maximum number of orders to cancel at once is exceeded
+    ExceedMaxCancelOrderSize = -4032,
+    /// Invalid balance type
+    InvalidBalanceType = -4044,
+    /// The maximum number of stop orders is reached
+    MaxStopOrderExceeded = -4045,
+    /// The margin type is already the requested one
+    NoNeedToChangeMarginType = -4046,
+    /// The margin type cannot be changed while there are open orders
+    ThereExistsOpenOrders = -4047,
+    /// The margin type cannot be changed while there is a position
+    ThereExistsQuantity = -4048,
+    /// Isolated margin cannot be added to a cross position
+    AddIsolatedMarginReject = -4049,
+    /// The cross balance is insufficient
+    CrossBalanceInsufficient = -4050,
+    /// The isolated balance is insufficient
+    IsolatedBalanceInsufficient = -4051,
+    /// Auto add margin is already in the requested state
+    NoNeedToChangeAutoAddMargin = -4052,
+    /// Auto add margin only applies to isolated positions
+    AutoAddCrossedMarginReject = -4053,
+    /// Isolated margin cannot be added to an empty position
+    AddIsolatedMarginNoPositionReject = -4054,
+    /// The amount must be positive
+    AmountMustBePositive = -4055,
+    /// Invalid API key type
+    InvalidApiKeyType = -4056,
+    /// Invalid RSA public key
+    InvalidRsaPublicKey = -4057,
+    /// The maximum price is too large
+    MaxPriceTooLarge = -4058,
+    /// The position mode is already the requested one
+    NoNeedToChangePositionSide = -4059,
+    /// Invalid position side
+    InvalidPositionSide = -4060,
+    /// The position side does not match the position mode
+    PositionSideNotMatch = -4061,
+    /// Invalid or improper reduce only value
+    ReduceOnlyConflict = -4062,
+    /// The position mode cannot be changed while there are open orders
+    PositionSideChangeExistsOpenOrders = -4067,
+    /// The position mode cannot be changed while there is a position
+    PositionSideChangeExistsQuantity = -4068,
+    /// Invalid number of orders in the batch
+    InvalidBatchPlaceOrderSize = -4082,
+    /// Failed to place the batch of orders
+    PlaceBatchOrdersFail = -4083,
+    /// The method is not allowed currently
+    UpcomingMethod = -4084,
+    /// Invalid price spread threshold
+    InvalidPriceSpreadThreshold = -4086,
+    /// The user can only place reduce only orders
+    ReduceOnlyOrderPermission = -4087,
+    /// The user cannot place orders currently
+    NoPlaceOrderPermission = -4088,
+    /// Invalid contract type
+    InvalidContractType = -4104,
+    /// The client transaction id is too long
+    InvalidClientTranIdLen = -4114,
+    /// The client transaction id is duplicated
+    DuplicatedClientTranId = -4115,
+    /// The reduce only order failed the margin check
+    ReduceOnlyMarginCheckFailed = -4118,
+    /// The counterparty's best price does not meet the percent price filter
+    MarketOrderReject = -4131,
+    /// Invalid activation price
+    InvalidActivationPrice = -4135,
+    /// A quantity cannot be sent with close position
+    QuantityExistsWithClosePosition = -4137,
+    /// Reduce only must be true with close position
+    ReduceOnlyMustBeTrue = -4138,
+    /// The order type cannot be market with close position
+    OrderTypeCannotBeMarket = -4139,
+    /// The trigger price of the strategy is invalid
+    StrategyInvalidTriggerPrice = -4142,
+    /// The notional of the order is below the minimum
+    MinNotional = -4164,
+    /// Invalid time interval
+    InvalidTimeInterval = -4165,
+    /// The price is higher than the stop price multiplier cap
+    PriceHigherThanStopMultiplierUp = -4183,
+    /// The price is lower than the stop price multiplier floor
+    PriceLowerThanStopMultiplierDown = -4184,
+    /// The fill or kill order could not be filled immediately
+    FokOrderReject = -5021,
+    /// The post only order would immediately match
+    GtxOrderReject = -5022,
+    /// The symbol is not in trading status, the order cannot be modified
+    MoveOrderNotAllowed = -5024,
+    /// Only limit orders can be modified
+    LimitOrderOnly = -5025,
+    /// The maximum number of modifications of the order is reached
+    ExceedMaximumModifyOrderLimit = -5026,
+    /// The modification does not change the order
+    SameOrder = -5027,
+    /// The request arrived at the matching engine outside of the receive window
+    MeRecvWindowReject = -5028,
+}
+
+impl BinanceErrorCode {
+    /// The documented code, None for unknown codes
+    pub fn from_code(code: i32) -> Option<Self> {
+        use BinanceErrorCode::*;
+        Some(match code {
+            -1000 => Unknown,
+            -1001 => Disconnected,
+            -1002 => Unauthorized,
+            -1003 => TooManyRequests,
+            -1006 => UnexpectedResponse,
+            -1007 => Timeout,
+            -1008 => ServerBusy,
+            -1013 => FilterFailure,
+            -1014 => UnknownOrderComposition,
+            -1015 => TooManyOrders,
+            -1016 => ServiceShuttingDown,
+            -1020 => UnsupportedOperation,
+            -1021 => InvalidTimestamp,
+            -1022 => InvalidSignature,
+            -1099 => NotFoundAuthenticatedOrAuthorized,
+            -1100 => IllegalChars,
+            -1101 => TooManyParameters,
+            -1102 => MandatoryParamEmptyOrMalformed,
+            -1103 => UnknownParam,
+            -1104 => UnreadParameters,
+            -1105 => ParamEmpty,
+            -1106 => ParamNotRequired,
+            -1108 => ParamOverflow,
+            -1111 => BadPrecision,
+            -1112 => NoDepth,
+            -1114 => TifNotRequired,
+            -1115 => InvalidTif,
+            -1116 => InvalidOrderType,
+            -1117 => InvalidSide,
+            -1118 => EmptyNewClientOrderId,
+            -1119 => EmptyOrigClientOrderId,
+            -1120 => BadInterval,
+            -1121 => BadSymbol,
+            -1122 => InvalidSymbolStatus,
+            -1125 => InvalidListenKey,
+            -1127 => MoreThanXxHours,
+            -1128 => OptionalParamsBadCombo,
+            -1130 => InvalidParameter,
+            -1131 => BadRecvWindow,
+            -1134 => BadStrategyType,
+            -1135 => InvalidJson,
+            -1139 => InvalidTickerType,
+            -1145 => InvalidCancelRestrictions,
+            -1151 => DuplicateSymbols,
+            -2010 => NewOrderRejected,
+            -2011 => CancelRejected,
+            -2013 => NoSuchOrder,
+            -2014 => BadApiKeyFormat,
+            -2015 => RejectedApiKey,
+            -2016 => NoTradingWindow,
+            -2018 => BalanceNotSufficient,
+            -2019 => MarginNotSufficient,
+            -2020 => UnableToFill,
+            -2021 => OrderWouldImmediatelyTrigger,
+            -2022 => ReduceOnlyReject,
+            -2023 => UserInLiquidation,
+            -2024 => PositionNotSufficient,
+            -2025 => MaxOpenOrderExceeded,
+            -2026 => OrderArchived,
+            -2027 => MaxLeverageRatio,
+            -2028 => MinLeverageRatio,
+            -4000 => InvalidOrderStatus,
+            -4001 => PriceLessThanZero,
+            -4002 => PriceGreaterThanMaxPrice,
+            -4003 => QtyLessThanZero,
+            -4004 => QtyLessThanMinQty,
+            -4005 => QtyGreaterThanMaxQty,
+            -4006 => StopPriceLessThanZero,
+            -4007 => StopPriceGreaterThanMaxPrice,
+            -4008 => TickSizeLessThanZero,
+            -4009 => MaxPriceLessThanMinPrice,
+            -4010 => MaxQtyLessThanMinQty,
+            -4011 => StepSizeLessThanZero,
+            -4012 => MaxNumOrdersLessThanZero,
+            -4013 => PriceLessThanMinPrice,
+            -4014 => PriceNotIncreasedByTickSize,
+            -4015 => InvalidClientOrderIdLen,
+            -4016 => PriceHigherThanMultiplierUp,
+            -4023 => QtyNotIncreasedByStepSize,
+            -4024 => PriceLowerThanMultiplierDown,
+            -4028 => InvalidLeverage,
+            -4029 => InvalidTickSizePrecision,
+            -4030 => InvalidStepSizePrecision,
+            -4031 => InvalidWorkingType,
+            -4032 => ExceedMaxCancelOrderSize,
+            -4044 => InvalidBalanceType,
+            -4045 => MaxStopOrderExceeded,
+            -4046 => NoNeedToChangeMarginType,
+            -4047 => ThereExistsOpenOrders,
+            -4048 => ThereExistsQuantity,
+            -4049 => AddIsolatedMarginReject,
+            -4050 => CrossBalanceInsufficient,
+            -4051 => IsolatedBalanceInsufficient,
+            -4052 => NoNeedToChangeAutoAddMargin,
+            -4053 => AutoAddCrossedMarginReject,
+            -4054 => AddIsolatedMarginNoPositionReject,
+            -4055 => AmountMustBePositive,
+            -4056 => InvalidApiKeyType,
+            -4057 => InvalidRsaPublicKey,
+            -4058 => MaxPriceTooLarge,
+            -4059 => NoNeedToChangePositionSide,
+            -4060 => InvalidPositionSide,
+            -4061 => PositionSideNotMatch,
+            -4062 => ReduceOnlyConflict,
+            -4067 => PositionSideChangeExistsOpenOrders,
+            -4068 => PositionSideChangeExistsQuantity,
+            -4082 => InvalidBatchPlaceOrderSize,
+            -4083 => PlaceBatchOrdersFail,
+            -4084 => UpcomingMethod,
+            -4086 => InvalidPriceSpreadThreshold,
+            -4087 => ReduceOnlyOrderPermission,
+            -4088 => NoPlaceOrderPermission,
+            -4104 => InvalidContractType,
+            -4114 => InvalidClientTranIdLen,
+            -4115 => DuplicatedClientTranId,
+            -4118 => ReduceOnlyMarginCheckFailed,
+            -4131 => MarketOrderReject,
+            -4135 => InvalidActivationPrice,
+            -4137 => QuantityExistsWithClosePosition,
+            -4138 => ReduceOnlyMustBeTrue,
+            -4139 => OrderTypeCannotBeMarket,
+            -4142 => StrategyInvalidTriggerPrice,
+            -4164 => MinNotional,
+            -4165 => InvalidTimeInterval,
+            -4183 => PriceHigherThanStopMultiplierUp,
+            -4184 => PriceLowerThanStopMultiplierDown,
+            -5021 => FokOrderReject,
+            -5022 => GtxOrderReject,
+            -5024 => MoveOrderNotAllowed,
+            -5025 => LimitOrderOnly,
+            -5026 => ExceedMaximumModifyOrderLimit,
+            -5027 => SameOrder,
+            -5028 => MeRecvWindowReject,
+            _ => return None,
+        })
+    }
+
+    /// Numeric value of the code
+    pub fn code(self) -> i32 { self as i32 }
+
+    /// Whether the request may succeed if sent again later, without changing it.
+    /// The status of orders is unknown after `UnexpectedResponse` and `Timeout`,
+    /// query it before placing the order again.
+    pub fn is_retryable(self) -> bool {
+        use BinanceErrorCode::*;
+        matches!(
+            self,
+            Disconnected
+                | TooManyRequests
+                | UnexpectedResponse
+                | Timeout
+                | ServerBusy
+                | TooManyOrders
+                | ServiceShuttingDown
+                | InvalidTimestamp
+                | MeRecvWindowReject
+        )
+    }
+
+    /// Whether a new order was rejected by the exchange because of its parameters or the account state
+    pub fn is_order_rejection(self) -> bool {
+        use BinanceErrorCode::*;
+        matches!(
+            self,
+            FilterFailure
+                | UnknownOrderComposition
+                | BadPrecision
+                | InvalidTif
+                | InvalidOrderType
+                | InvalidSide
+                | NewOrderRejected
+                | BalanceNotSufficient
+                | MarginNotSufficient
+                | UnableToFill
+                | OrderWouldImmediatelyTrigger
+                | ReduceOnlyReject
+                | UserInLiquidation
+                | PositionNotSufficient
+                | MaxOpenOrderExceeded
+                | MaxLeverageRatio
+                | MinLeverageRatio
+                | PriceLessThanZero
+                | PriceGreaterThanMaxPrice
+                | QtyLessThanZero
+                | QtyLessThanMinQty
+                | QtyGreaterThanMaxQty
+                | StopPriceLessThanZero
+                | StopPriceGreaterThanMaxPrice
+                | PriceLessThanMinPrice
+                | PriceNotIncreasedByTickSize
+                | PriceHigherThanMultiplierUp
+                | QtyNotIncreasedByStepSize
+                | PriceLowerThanMultiplierDown
+                | MaxStopOrderExceeded
+                | PositionSideNotMatch
+                | ReduceOnlyConflict
+                | ReduceOnlyOrderPermission
+                | NoPlaceOrderPermission
+                | ReduceOnlyMarginCheckFailed
+                | MarketOrderReject
+                | InvalidActivationPrice
+                | QuantityExistsWithClosePosition
+                | ReduceOnlyMustBeTrue
+                | OrderTypeCannotBeMarket
+                | MinNotional
+                | PriceHigherThanStopMultiplierUp
+                | PriceLowerThanStopMultiplierDown
+                | FokOrderReject
+                | GtxOrderReject
+        )
+    }
+}
+
 /// Custom error messages
 pub mod error_messages {
     pub const INVALID_PRICE: &str = "Invalid price.";
 }
 
 pub type Result<T> = core::result::Result<T, Error>;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn error_codes() {
+        let error: BinanceContentError =
+            serde_json::from_str(r#"{"code":-4164,"msg":"Order's notional must be no smaller than 5.0"}"#).unwrap();
+        let code = error.error_code().unwrap();
+        assert_eq!(code, BinanceErrorCode::MinNotional);
+        assert_eq!(BinanceErrorCode::from_code(code.code()), Some(code));
+        assert!(code.is_order_rejection() && !code.is_retryable());
+        assert_eq!(
+            Error::BinanceError { response: error }.error_code(),
+            Some(BinanceErrorCode::MinNotional)
+        );
+        assert_eq!(BinanceErrorCode::from_code(-1), None);
+    }
+
+    #[test]
+    fn websocket_error_source() {
+        let error = Error::WebSocket(Box::new(std::io::Error::other("connection reset")));
+        assert_eq!(error.to_string(), "websocket error: connection reset");
+        let source = std::error::Error::source(&error).unwrap();
+        assert!(source.downcast_ref::<std::io::Error>().is_some());
+    }
+}
@@ -0,0 +1,162 @@
+//! Failover across API hosts
+//!
+//! Binance serves the spot API from several clusters, `api.binance.com`, `api-gcp.binance.com` and
+//! `api1` to `api4.binance.com`, and during incidents one of them can fail while the others work.
+//! Clients created with a configuration holding a [`Failover`] send each request to the first healthy
+//! host of its list, when their own host is one of them. A host that cannot be reached or answers with
+//! HTTP 500 or 503 is set aside for the cool-down, and the request is sent to the next host.
+//!
+//! Orders are never sent twice: POST requests only move to the next host when the connection could not
+//! be established, since an order that reached a failing host may still have been executed.
+//!
+//! # Examples
+//! ```rust,no_run
+//! use binance::{api::*, config::*, failover::Failover, market::*};
+//! # tokio_test::block_on(async {
+//! let conf = Config::default().set_failover(Failover::spot());
+//! let market: Market = Binance::new_with_config(None, None, &conf);
+//! let price = market.get_price("BTCUSDT").await;
+//! # });
+//! ```
+
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use reqwest::{Method, StatusCode};
+
+use crate::errors::*;
+use crate::transport::HttpResponse;
+
+/// Hosts of the spot API, in order of preference
+pub static SPOT_HOSTS: &[&str] = &[
+    "https://api.binance.com",
+    "https://api-gcp.binance.com",
+    "https://api1.binance.com",
+    "https://api2.binance.com",
+    "https://api3.binance.com",
+    "https://api4.binance.com",
+];
+
+/// Hosts of an API and their health, shared by the clients of a configuration
+#[derive(Clone)]
+pub struct Failover {
+    hosts: Arc<Mutex<Vec<Host>>>,
+    cool_down: Duration,
+}
+
+struct Host {
+    url: String,
+    /// Set aside until then after a failure
+    down_until: Option<Instant>,
+}
+
+impl Failover {
+    /// Fail over across `hosts` in order, setting a failing host aside for `cool_down`
+    pub fn new<T: Into<String>>(hosts: impl IntoIterator<Item = T>, cool_down: Duration) -> Self {
+        let hosts = hosts
+            .into_iter()
+            .map(|url| Host {
+                url: url.into(),
+                down_until: None,
+            })
+            .collect();
+        Failover {
+            hosts: Arc::new(Mutex::new(hosts)),
+            cool_down,
+        }
+    }
+
+    /// Fail over across the spot API clusters, with a cool-down of 30 seconds
+    pub fn spot() -> Self { Self::new(SPOT_HOSTS.iter().copied(), Duration::from_secs(30)) }
+
+    /// Whether `host` is one of the hosts
+    pub fn contains(&self, host: &str) -> bool { self.hosts.lock().unwrap().iter().any(|h| h.url == host) }
+
+    /// Whether `host` is not set aside
+    pub fn is_healthy(&self, host: &str) -> bool {
+        let now = Instant::now();
+        self.hosts
+            .lock()
+            .unwrap()
+            .iter()
+            .any(|h| h.url == host && h.down_until.is_none_or(|until| until <= now))
+    }
+
+    /// Hosts to try in order, the healthy ones first then the others by the end of their cool-down
+    pub fn candidates(&self) -> Vec<String> {
+        let now = Instant::now();
+        let hosts = self.hosts.lock().unwrap();
+        let (mut healthy, mut down): (Vec<&Host>, Vec<&Host>) = hosts
+            .iter()
+            .partition(|h| h.down_until.is_none_or(|until| until <= now));
+        down.sort_by_key(|h| h.down_until);
+        healthy.append(&mut down);
+        healthy.into_iter().map(|h| h.url.clone()).collect()
+    }
+
+    /// Set `host` aside for the cool-down
+    pub fn mark_down(&self, host: &str) {
+        let until = Instant::now() + self.cool_down;
+        if let Some(h) = self.hosts.lock().unwrap().iter_mut().find(|h| h.url == host) {
+            h.down_until = Some(until);
+        }
+    }
+
+    /// `host` answered, it is healthy again
+    pub fn mark_up(&self, host: &str) {
+        if let Some(h) = self.hosts.lock().unwrap().iter_mut().find(|h| h.url == host) {
+            h.down_until = None;
+        }
+    }
+}
+
+impl fmt::Debug for Failover {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.write_str("Failover") }
+}
+
+impl PartialEq for Failover {
+    fn eq(&self, other: &Self) -> bool { Arc::ptr_eq(&self.hosts, &other.hosts) }
+}
+
+impl Eq for Failover {}
+
+/// Whether the host failed, rather than rejecting the request
+pub(crate) fn is_host_failure(result: &Result<HttpResponse>) -> bool {
+    match result {
+        Ok(response) => matches!(
+            response.status,
+            StatusCode::INTERNAL_SERVER_ERROR | StatusCode::SERVICE_UNAVAILABLE
+        ),
+        Err(Error::ReqError(e)) => e.is_connect() || e.is_timeout() || e.is_request(),
+        Err(Error::IoError(_)) => true,
+        Err(_) => false,
+    }
+}
+
+/// Whether the request can be sent to another host after `result`. POST requests may have been
+/// executed by a host that failed afterwards, they are only sent again if the connection failed.
+pub(crate) fn can_fail_over(method: &Method, result: &Result<HttpResponse>) -> bool {
+    match (method, result) {
+        (&Method::POST, Err(Error::ReqError(e))) => e.is_connect(),
+        (&Method::POST, _) => false,
+        _ => is_host_failure(result),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn unhealthy_hosts_last() {
+        let failover = Failover::new(["a", "b", "c"], Duration::from_secs(30));
+        failover.mark_down("a");
+        assert!(!failover.is_healthy("a"));
+        assert_eq!(failover.candidates(), ["b", "c", "a"]);
+        failover.mark_down("b");
+        assert_eq!(failover.candidates(), ["c", "a", "b"]);
+        failover.mark_up("a");
+        assert_eq!(failover.candidates(), ["a", "c", "b"]);
+    }
+}
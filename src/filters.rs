@@ -0,0 +1,885 @@
+//! Order validation against the filters of a symbol
+//!
+//! Orders that do not pass the filters published in the exchange information are rejected by the
+//! exchange with error -1013. [`SymbolFilters`] checks an order before it is sent, reporting every
+//! violated filter at once, and rounds prices and quantities to the tick and step sizes.
+//!
+//! # Examples
+//! ```rust,no_run
+//! use binance::{account::OrderRequest, api::*, filters::SymbolFilters, general::General, rest_model::*};
+//! # tokio_test::block_on(async {
+//! let general: General = Binance::new(None, None);
+//! let info = general.exchange_info().await.unwrap();
+//! let symbol = info.symbols.iter().find(|s| s.symbol == "BTCUSDT").unwrap();
+//! let filters = SymbolFilters::from(symbol);
+//! let order = OrderRequest {
+//!     symbol: "BTCUSDT".to_string(),
+//!     quantity: Some(filters.round_quantity("0.0012345".parse().unwrap())),
+//!     price: Some(filters.round_price("20000.123".parse().unwrap())),
+//!     order_type: OrderType::Limit,
+//!     side: OrderSide::Buy,
+//!     time_in_force: Some(TimeInForce::GTC),
+//!     ..OrderRequest::default()
+//! };
+//! filters.check(&order, None).unwrap();
+//! # });
+//! ```
+
+use std::fmt;
+
+use crate::account;
+use crate::errors::*;
+use crate::rest_model::{self, Decimal, OrderType};
+
+/// PRICE_FILTER, zero values are disabled
+#[derive(Debug, Clone, PartialEq)]
+pub struct PriceFilter {
+    pub min_price: Decimal,
+    pub max_price: Decimal,
+    pub tick_size: Decimal,
+}
+
+/// LOT_SIZE and MARKET_LOT_SIZE, zero values are disabled
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuantityFilter {
+    pub min_qty: Decimal,
+    pub max_qty: Decimal,
+    pub step_size: Decimal,
+}
+
+/// MIN_NOTIONAL and NOTIONAL
+#[derive(Debug, Clone, PartialEq)]
+pub struct NotionalFilter {
+    pub min_notional: Decimal,
+    pub max_notional: Option<Decimal>,
+    pub apply_min_to_market: bool,
+    pub apply_max_to_market: bool,
+}
+
+/// PERCENT_PRICE, bounds of the price relative to the average or mark price
+#[derive(Debug, Clone, PartialEq)]
+pub struct PercentPriceFilter {
+    pub multiplier_up: Decimal,
+    pub multiplier_down: Decimal,
+}
+
+/// The filters of a symbol checked before placing an order
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SymbolFilters {
+    pub symbol: String,
+    pub price: Option<PriceFilter>,
+    pub lot_size: Option<QuantityFilter>,
+    pub market_lot_size: Option<QuantityFilter>,
+    pub notional: Option<NotionalFilter>,
+    pub percent_price: Option<PercentPriceFilter>,
+    pub iceberg_parts: Option<u16>,
+}
+
+/// The parts of an order checked by the filters
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct OrderParams {
+    pub price: Option<Decimal>,
+    pub stop_price: Option<Decimal>,
+    pub quantity: Option<Decimal>,
+    pub quote_order_qty: Option<Decimal>,
+    pub iceberg_qty: Option<Decimal>,
+    pub is_market: bool,
+}
+
+/// A filter rejecting an order
+#[derive(Debug, Clone, PartialEq)]
+pub enum FilterViolation {
+    /// The price or the stop price is below the minimum price
+    PriceBelowMin {
+        price: Decimal,
+        min_price: Decimal,
+    },
+    /// The price or the stop price is above the maximum price
+    PriceAboveMax {
+        price: Decimal,
+        max_price: Decimal,
+    },
+    /// The price or the stop price is not a multiple of the tick size
+    PriceNotMultipleOfTick {
+        price: Decimal,
+        tick_size: Decimal,
+    },
+    /// The price is too far from the reference price
+    PriceOutOfBounds {
+        price: Decimal,
+        low: Decimal,
+        high: Decimal,
+    },
+    QuantityBelowMin {
+        quantity: Decimal,
+        min_qty: Decimal,
+    },
+    QuantityAboveMax {
+        quantity: Decimal,
+        max_qty: Decimal,
+    },
+    QuantityNotMultipleOfStep {
+        quantity: Decimal,
+        step_size: Decimal,
+    },
+    NotionalBelowMin {
+        notional: Decimal,
+        min_notional: Decimal,
+    },
+    NotionalAboveMax {
+        notional: Decimal,
+        max_notional: Decimal,
+    },
+    TooManyIcebergParts {
+        parts: Decimal,
+        limit: u16,
+    },
+}
+
+impl fmt::Display for FilterViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PriceBelowMin { price, min_price } => write!(f, "price {price} is below the minimum {min_price}"),
+            Self::PriceAboveMax { price, max_price } => write!(f, "price {price} is above the maximum {max_price}"),
+            Self::PriceNotMultipleOfTick { price, tick_size } => {
+                write!(f, "price {price} is not a multiple of the tick size {tick_size}")
+            }
+            Self::PriceOutOfBounds { price, low, high } => write!(f, "price {price} is outside of [{low}, {high}]"),
+            Self::QuantityBelowMin { quantity, min_qty } => {
+                write!(f, "quantity {quantity} is below the minimum {min_qty}")
+            }
+            Self::QuantityAboveMax { quantity, max_qty } => {
+                write!(f, "quantity {quantity} is above the maximum {max_qty}")
+            }
+            Self::QuantityNotMultipleOfStep { quantity, step_size } => {
+                write!(f, "quantity {quantity} is not a multiple of the step size {step_size}")
+            }
+            Self::NotionalBelowMin { notional, min_notional } => {
+                write!(f, "notional {notional} is below the minimum {min_notional}")
+            }
+            Self::NotionalAboveMax { notional, max_notional } => {
+                write!(f, "notional {notional} is above the maximum {max_notional}")
+            }
+            Self::TooManyIcebergParts { parts, limit } => write!(f, "{parts} iceberg parts exceed the limit {limit}"),
+        }
+    }
+}
+
+impl SymbolFilters {
+    /// Every filter violated by `order`
+    ///
+    /// `reference_price` is the average price for spot or the mark price for futures, it is used
+    /// for the PERCENT_PRICE filter and as the price of market orders for the notional filters,
+    /// which are skipped without it.
+    pub fn validate<'a, O>(&self, order: &'a O, reference_price: Option<Decimal>) -> Vec<FilterViolation>
+    where
+        OrderParams: From<&'a O>,
+    {
+        let order = OrderParams::from(order);
+        let mut violations = vec![];
+        if let Some(filter) = &self.price {
+            let prices = if order.is_market { None } else { order.price };
+            for price in prices.into_iter().chain(order.stop_price) {
+                filter.check(price, &mut violations);
+            }
+        }
+        if let (Some(filter), Some(reference), Some(price)) = (&self.percent_price, reference_price, order.price) {
+            let (low, high) = (reference * filter.multiplier_down, reference * filter.multiplier_up);
+            if !order.is_market && (price < low || price > high) {
+                violations.push(FilterViolation::PriceOutOfBounds { price, low, high });
+            }
+        }
+        if let Some(quantity) = order.quantity {
+            if let Some(filter) = &self.lot_size {
+                filter.check(quantity, &mut violations);
+            }
+            if let Some(filter) = self.market_lot_size.as_ref().filter(|_| order.is_market) {
+                filter.check(quantity, &mut violations);
+            }
+            if let (Some(limit), Some(iceberg_qty)) = (self.iceberg_parts, order.iceberg_qty) {
+                if iceberg_qty > Decimal::default() {
+                    let parts = (quantity / iceberg_qty).ceil();
+                    if parts > Decimal::from(limit) {
+                        violations.push(FilterViolation::TooManyIcebergParts { parts, limit });
+                    }
+                }
+            }
+        }
+        if let Some(filter) = &self.notional {
+            let price = if order.is_market { reference_price } else { order.price };
+            let notional = order.quote_order_qty.or_else(|| Some(order.quantity? * price?));
+            if let Some(notional) = notional {
+                filter.check(notional, order.is_market, &mut violations);
+            }
+        }
+        violations
+    }
+
+    /// Fails with [`Error::FilterViolations`] if `order` violates any filter, see [`Self::validate`]
+    pub fn check<'a, O>(&self, order: &'a O, reference_price: Option<Decimal>) -> Result<()>
+    where
+        OrderParams: From<&'a O>,
+    {
+        let violations = self.validate(order, reference_price);
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::FilterViolations(violations))
+        }
+    }
+
+    /// Round `price` to the nearest multiple of the tick size
+    pub fn round_price(&self, price: Decimal) -> Decimal {
+        match &self.price {
+            Some(filter) => round_to_step(price, filter.tick_size),
+            None => price,
+        }
+    }
+
+    /// Round `quantity` down to a multiple of the lot step size
+    pub fn round_quantity(&self, quantity: Decimal) -> Decimal {
+        match &self.lot_size {
+            Some(filter) => floor_to_step(quantity, filter.step_size),
+            None => quantity,
+        }
+    }
+
+    /// Round `quantity` down to a multiple of the market lot step size, or the lot step size
+    pub fn round_market_quantity(&self, quantity: Decimal) -> Decimal {
+        match self
+            .market_lot_size
+            .as_ref()
+            .filter(|f| f.step_size > Decimal::default())
+        {
+            Some(filter) => floor_to_step(self.round_quantity(quantity), filter.step_size),
+            None => self.round_quantity(quantity),
+        }
+    }
+}
+
+impl PriceFilter {
+    pub fn new(min_price: Decimal, max_price: Decimal, tick_size: Decimal) -> Self {
+        PriceFilter {
+            min_price,
+            max_price,
+            tick_size,
+        }
+    }
+
+    fn check(&self, price: Decimal, violations: &mut Vec<FilterViolation>) {
+        let zero = Decimal::default();
+        if self.min_price > zero && price < self.min_price {
+            violations.push(FilterViolation::PriceBelowMin {
+                price,
+                min_price: self.min_price,
+            });
+        }
+        if self.max_price > zero && price > self.max_price {
+            violations.push(FilterViolation::PriceAboveMax {
+                price,
+                max_price: self.max_price,
+            });
+        }
+        if !is_multiple_of(price, self.tick_size) {
+            violations.push(FilterViolation::PriceNotMultipleOfTick {
+                price,
+                tick_size: self.tick_size,
+            });
+        }
+    }
+}
+
+impl QuantityFilter {
+    pub fn new(min_qty: Decimal, max_qty: Decimal, step_size: Decimal) -> Self {
+        QuantityFilter {
+            min_qty,
+            max_qty,
+            step_size,
+        }
+    }
+
+    fn check(&self, quantity: Decimal, violations: &mut Vec<FilterViolation>) {
+        let zero = Decimal::default();
+        if self.min_qty > zero && quantity < self.min_qty {
+            violations.push(FilterViolation::QuantityBelowMin {
+                quantity,
+                min_qty: self.min_qty,
+            });
+        }
+        if self.max_qty > zero && quantity > self.max_qty {
+            violations.push(FilterViolation::QuantityAboveMax {
+                quantity,
+                max_qty: self.max_qty,
+            });
+        }
+        if !is_multiple_of(quantity, self.step_size) {
+            violations.push(FilterViolation::QuantityNotMultipleOfStep {
+                quantity,
+                step_size: self.step_size,
+            });
+        }
+    }
+}
+
+impl PercentPriceFilter {
+    pub fn new(multiplier_up: Decimal, multiplier_down: Decimal) -> Self {
+        PercentPriceFilter {
+            multiplier_up,
+            multiplier_down,
+        }
+    }
+}
+
+impl NotionalFilter {
+    fn check(&self, notional: Decimal, is_market: bool, violations: &mut Vec<FilterViolation>) {
+        if (!is_market || self.apply_min_to_market) && notional < self.min_notional {
+            violations.push(FilterViolation::NotionalBelowMin {
+                notional,
+                min_notional: self.min_notional,
+            });
+        }
+        if let Some(max_notional) = self.max_notional.filter(|_| !is_market || self.apply_max_to_market) {
+            if max_notional > Decimal::default() && notional > max_notional {
+                violations.push(FilterViolation::NotionalAboveMax { notional, max_notional });
+            }
+        }
+    }
+}
+
+impl From<&rest_model::Symbol> for SymbolFilters {
+    fn from(symbol: &rest_model::Symbol) -> Self {
+        use rest_model::Filters;
+        let mut filters = SymbolFilters {
+            symbol: symbol.symbol.clone(),
+            ..SymbolFilters::default()
+        };
+        for filter in &symbol.filters {
+            match filter {
+                Filters::PriceFilter {
+                    min_price,
+                    max_price,
+                    tick_size,
+                } => filters.price = Some(PriceFilter::new(*min_price, *max_price, *tick_size)),
+                Filters::PercentPrice {
+                    multiplier_up,
+                    multiplier_down,
+                    ..
+                } => filters.percent_price = Some(PercentPriceFilter::new(*multiplier_up, *multiplier_down)),
+                Filters::LotSize {
+                    min_qty,
+                    max_qty,
+                    step_size,
+                } => filters.lot_size = Some(QuantityFilter::new(*min_qty, *max_qty, *step_size)),
+                Filters::MarketLotSize {
+                    min_qty,
+                    max_qty,
+                    step_size,
+                } => filters.market_lot_size = Some(QuantityFilter::new(*min_qty, *max_qty, *step_size)),
+                Filters::MinNotional {
+                    min_notional,
+                    apply_to_market,
+                    ..
+                } => {
+                    filters.notional = Some(NotionalFilter {
+                        min_notional: *min_notional,
+                        max_notional: None,
+                        apply_min_to_market: *apply_to_market,
+                        apply_max_to_market: false,
+                    })
+                }
+                Filters::Notional {
+                    min_notional,
+                    apply_min_to_market,
+                    max_notional,
+                    apply_max_to_market,
+                    ..
+                } => {
+                    filters.notional = Some(NotionalFilter {
+                        min_notional: *min_notional,
+                        max_notional: Some(*max_notional),
+                        apply_min_to_market: *apply_min_to_market,
+                        apply_max_to_market: *apply_max_to_market,
+                    })
+                }
+                Filters::IcebergParts { limit } => filters.iceberg_parts = Some(*limit),
+                _ => {}
+            }
+        }
+        filters
+    }
+}
+
+impl From<&account::OrderRequest> for OrderParams {
+    fn from(order: &account::OrderRequest) -> Self {
+        OrderParams {
+            price: order.price,
+            stop_price: order.stop_price,
+            quantity: order.quantity,
+            quote_order_qty: order.quote_order_qty,
+            iceberg_qty: order.iceberg_qty,
+            is_market: order.order_type == OrderType::Market,
+        }
+    }
+}
+
+impl From<&OrderParams> for OrderParams {
+    fn from(order: &OrderParams) -> Self { order.clone() }
+}
+
+#[cfg(feature = "futures_api")]
+impl From<&crate::futures::rest_model::Symbol> for SymbolFilters {
+    fn from(symbol: &crate::futures::rest_model::Symbol) -> Self {
+        use crate::futures::rest_model::Filters;
+        let mut filters = SymbolFilters {
+            symbol: symbol.symbol.clone(),
+            ..SymbolFilters::default()
+        };
+        for filter in &symbol.filters {
+            match filter {
+                Filters::PriceFilter {
+                    min_price,
+                    max_price,
+                    tick_size,
+                } => filters.price = Some(PriceFilter::new(*min_price, *max_price, *tick_size)),
+                Filters::LotSize {
+                    min_qty,
+                    max_qty,
+                    step_size,
+                } => filters.lot_size = Some(QuantityFilter::new(*min_qty, *max_qty, *step_size)),
+                Filters::MarketLotSize {
+                    min_qty,
+                    max_qty,
+                    step_size,
+                } => filters.market_lot_size = Some(QuantityFilter::new(*min_qty, *max_qty, *step_size)),
+                Filters::MinNotional { notional } => {
+                    filters.notional = Some(NotionalFilter {
+                        min_notional: *notional,
+                        max_notional: None,
+                        apply_min_to_market: true,
+                        apply_max_to_market: false,
+                    })
+                }
+                Filters::PercentPrice {
+                    multiplier_up,
+                    multiplier_down,
+                    ..
+                } => filters.percent_price = Some(PercentPriceFilter::new(*multiplier_up, *multiplier_down)),
+                _ => {}
+            }
+        }
+        filters
+    }
+}
+
+#[cfg(feature = "futures_api")]
+impl From<&crate::futures::account::OrderRequest> for OrderParams {
+    fn from(order: &crate::futures::account::OrderRequest) -> Self {
+        use crate::futures::rest_model::OrderType;
+        OrderParams {
+            price: order.price,
+            stop_price: order.stop_price,
+            quantity: order.quantity,
+            is_market: matches!(
+                order.order_type,
+                OrderType::Market | OrderType::StopMarket | OrderType::TakeProfitMarket | OrderType::TrailingStopMarket
+            ),
+            ..OrderParams::default()
+        }
+    }
+}
+
+#[cfg(feature = "coin_futures_api")]
+impl From<&crate::coin_futures::rest_model::Symbol> for SymbolFilters {
+    /// Quantities are in contracts, so MIN_NOTIONAL is not checked
+    fn from(symbol: &crate::coin_futures::rest_model::Symbol) -> Self {
+        use crate::coin_futures::rest_model::Filters;
+        let mut filters = SymbolFilters {
+            symbol: symbol.symbol.clone(),
+            ..SymbolFilters::default()
+        };
+        for filter in &symbol.filters {
+            match filter {
+                Filters::PriceFilter {
+                    min_price,
+                    max_price,
+                    tick_size,
+                } => filters.price = Some(PriceFilter::new(*min_price, *max_price, *tick_size)),
+                Filters::LotSize {
+                    min_qty,
+                    max_qty,
+                    step_size,
+                } => filters.lot_size = Some(QuantityFilter::new(*min_qty, *max_qty, *step_size)),
+                Filters::MarketLotSize {
+                    min_qty,
+                    max_qty,
+                    step_size,
+                } => filters.market_lot_size = Some(QuantityFilter::new(*min_qty, *max_qty, *step_size)),
+                Filters::PercentPrice {
+                    multiplier_up,
+                    multiplier_down,
+                    ..
+                } => filters.percent_price = Some(PercentPriceFilter::new(*multiplier_up, *multiplier_down)),
+                _ => {}
+            }
+        }
+        filters
+    }
+}
+
+#[cfg(feature = "coin_futures_api")]
+impl From<&crate::coin_futures::account::OrderRequest> for OrderParams {
+    fn from(order: &crate::coin_futures::account::OrderRequest) -> Self {
+        use crate::coin_futures::rest_model::OrderType;
+        OrderParams {
+            price: order.price,
+            stop_price: order.stop_price,
+            quantity: order.quantity,
+            is_market: matches!(
+                order.order_type,
+                OrderType::Market | OrderType::StopMarket | OrderType::TakeProfitMarket | OrderType::TrailingStopMarket
+            ),
+            ..OrderParams::default()
+        }
+    }
+}
+
+/// Round `value` to the nearest multiple of `step`, a zero step leaves it unchanged
+pub fn round_to_step(value: Decimal, step: Decimal) -> Decimal { snap(value, step, false) }
+
+/// Round `value` down to a multiple of `step`, a zero step leaves it unchanged
+pub fn floor_to_step(value: Decimal, step: Decimal) -> Decimal { snap(value, step, true) }
+
+#[cfg(not(feature = "decimal"))]
+fn snap(value: Decimal, step: Decimal, floor: bool) -> Decimal {
+    if step <= 0.0 {
+        return value;
+    }
+    // Absorb the representation error of the division before rounding down
+    let steps = ((value / step) * 1e9).round() / 1e9;
+    let steps = if floor { steps.floor() } else { steps.round() };
+    // Keep as many decimals as the step has, `0.1 * 3` is `0.30000000000000004`
+    let decimals = step.to_string().split('.').nth(1).map_or(0, str::len) as i32;
+    let scale = 10f64.powi(decimals);
+    (steps * step * scale).round() / scale
+}
+
+#[cfg(feature = "decimal")]
+fn snap(value: Decimal, step: Decimal, floor: bool) -> Decimal {
+    use rust_decimal::RoundingStrategy;
+    if step <= Decimal::ZERO {
+        return value;
+    }
+    let steps = value / step;
+    let steps = if floor {
+        steps.floor()
+    } else {
+        steps.round_dp_with_strategy(0, RoundingStrategy::MidpointAwayFromZero)
+    };
+    steps * step
+}
+
+#[cfg(not(feature = "decimal"))]
+fn is_multiple_of(value: Decimal, step: Decimal) -> bool {
+    step <= 0.0 || (round_to_step(value, step) - value).abs() <= step * 1e-9
+}
+
+#[cfg(feature = "decimal")]
+fn is_multiple_of(value: Decimal, step: Decimal) -> bool { step <= Decimal::ZERO || (value % step).is_zero() }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn d(s: &str) -> Decimal { s.parse().unwrap() }
+
+    fn filters() -> SymbolFilters {
+        SymbolFilters {
+            symbol: "BTCUSDT".to_string(),
+            price: Some(PriceFilter::new(d("0.01"), d("1000000"), d("0.01"))),
+            lot_size: Some(QuantityFilter::new(d("0.00001"), d("9000"), d("0.00001"))),
+            notional: Some(NotionalFilter {
+                min_notional: d("5"),
+                max_notional: None,
+                apply_min_to_market: true,
+                apply_max_to_market: false,
+            }),
+            ..SymbolFilters::default()
+        }
+    }
+
+    #[test]
+    fn rounding() {
+        let filters = filters();
+        assert_eq!(filters.round_price(d("20000.126")), d("20000.13"));
+        assert_eq!(filters.round_quantity(d("0.123456789")), d("0.12345"));
+        assert_eq!(floor_to_step(d("0.3"), d("0.1")), d("0.3"));
+        assert_eq!(round_to_step(d("7"), d("0")), d("7"));
+    }
+
+    #[test]
+    fn reports_every_violation() {
+        let filters = filters();
+        let order = OrderParams {
+            price: Some(d("20000.005")),
+            quantity: Some(d("0.000015")),
+            ..OrderParams::default()
+        };
+        let violations = filters.validate(&order, None);
+        assert_eq!(violations.len(), 3, "{violations:?}");
+        assert!(matches!(violations[0], FilterViolation::PriceNotMultipleOfTick { .. }));
+        assert!(matches!(
+            violations[1],
+            FilterViolation::QuantityNotMultipleOfStep { .. }
+        ));
+        assert!(matches!(violations[2], FilterViolation::NotionalBelowMin { .. }));
+
+        let order = OrderParams {
+            price: Some(d("20000.01")),
+            quantity: Some(d("0.001")),
+            ..OrderParams::default()
+        };
+        assert!(filters.check(&order, None).is_ok());
+    }
+
+    #[test]
+    fn market_orders_priced_from_reference_price() {
+        let filters = filters();
+        let order = OrderParams {
+            price: Some(d("20000.005")),
+            quantity: Some(d("0.0001")),
+            is_market: true,
+            ..OrderParams::default()
+        };
+        // The price of market orders is not checked, the notional is skipped without a reference price
+        assert!(filters.validate(&order, None).is_empty());
+        let violations = filters.validate(&order, Some(d("20000")));
+        assert!(
+            matches!(violations[..], [FilterViolation::NotionalBelowMin { .. }]),
+            "{violations:?}"
+        );
+        assert!(filters.validate(&order, Some(d("100000"))).is_empty());
+    }
+
+    #[test]
+    fn percent_price() {
+        let filters = SymbolFilters {
+            percent_price: Some(PercentPriceFilter::new(d("1.05"), d("0.95"))),
+            ..SymbolFilters::default()
+        };
+        let order = |price: &str| OrderParams {
+            price: Some(d(price)),
+            ..OrderParams::default()
+        };
+        assert!(filters.validate(&order("20500"), Some(d("20000"))).is_empty());
+        assert!(filters.validate(&order("22000"), None).is_empty());
+        for price in ["22000", "18000"] {
+            let violations = filters.validate(&order(price), Some(d("20000")));
+            assert!(
+                matches!(violations[..], [FilterViolation::PriceOutOfBounds { .. }]),
+                "{violations:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn market_lot_size() {
+        let filters = SymbolFilters {
+            market_lot_size: Some(QuantityFilter::new(d("0.001"), d("10"), d("0.001"))),
+            ..SymbolFilters::default()
+        };
+        let order = OrderParams {
+            quantity: Some(d("20")),
+            ..OrderParams::default()
+        };
+        assert!(filters.validate(&order, None).is_empty());
+        let market = OrderParams {
+            is_market: true,
+            ..order
+        };
+        let violations = filters.validate(&market, None);
+        assert!(
+            matches!(violations[..], [FilterViolation::QuantityAboveMax { .. }]),
+            "{violations:?}"
+        );
+        assert_eq!(filters.round_market_quantity(d("1.23456")), d("1.234"));
+    }
+
+    #[test]
+    fn iceberg_parts() {
+        let filters = SymbolFilters {
+            iceberg_parts: Some(10),
+            ..SymbolFilters::default()
+        };
+        let order = |iceberg_qty: &str| OrderParams {
+            price: Some(d("20000")),
+            quantity: Some(d("1")),
+            iceberg_qty: Some(d(iceberg_qty)),
+            ..OrderParams::default()
+        };
+        assert!(filters.validate(&order("0.1"), None).is_empty());
+        assert_eq!(filters.validate(&order("0.05"), None), vec![
+            FilterViolation::TooManyIcebergParts {
+                parts: d("20"),
+                limit: 10
+            }
+        ]);
+    }
+
+    #[test]
+    fn max_notional() {
+        let filters = SymbolFilters {
+            notional: Some(NotionalFilter {
+                min_notional: d("5"),
+                max_notional: Some(d("1000")),
+                apply_min_to_market: true,
+                apply_max_to_market: false,
+            }),
+            ..SymbolFilters::default()
+        };
+        let order = OrderParams {
+            price: Some(d("20000")),
+            quantity: Some(d("1")),
+            ..OrderParams::default()
+        };
+        let violations = filters.validate(&order, None);
+        assert!(
+            matches!(violations[..], [FilterViolation::NotionalAboveMax { .. }]),
+            "{violations:?}"
+        );
+        let market = OrderParams {
+            is_market: true,
+            ..order
+        };
+        assert!(filters.validate(&market, Some(d("20000"))).is_empty());
+    }
+
+    #[cfg(not(feature = "decimal"))]
+    #[test]
+    fn float_steps() {
+        let filters = SymbolFilters {
+            price: Some(PriceFilter::new(0.0, 0.0, 0.1)),
+            ..SymbolFilters::default()
+        };
+        assert_eq!(filters.round_price(0.3), 0.3);
+        assert_eq!(filters.round_price(0.1 + 0.2), 0.3);
+        assert!(is_multiple_of(0.3, 0.1));
+        assert!(is_multiple_of(0.1 + 0.2, 0.1));
+        assert!(!is_multiple_of(0.35, 0.1));
+    }
+
+    #[cfg(feature = "futures_api")]
+    #[test]
+    fn futures_symbol_filters() {
+        let symbol: crate::futures::rest_model::Symbol = serde_json::from_value(serde_json::json!({
+            "symbol": "BTCUSDT",
+            "pair": "BTCUSDT",
+            "contractType": "PERPETUAL",
+            "deliveryDate": 4133404800000u64,
+            "onboardDate": 1569398400000u64,
+            "status": "TRADING",
+            "maintMarginPercent": "2.5000",
+            "requiredMarginPercent": "5.0000",
+            "baseAsset": "BTC",
+            "quoteAsset": "USDT",
+            "pricePrecision": 2,
+            "quantityPrecision": 3,
+            "baseAssetPrecision": 8,
+            "quotePrecision": 8,
+            "underlyingType": "COIN",
+            "underlyingSubType": [],
+            "settlePlan": 0,
+            "triggerProtect": "0.0500",
+            "filters": [
+                { "filterType": "PRICE_FILTER", "minPrice": "556.80", "maxPrice": "4529764", "tickSize": "0.10" },
+                { "filterType": "LOT_SIZE", "minQty": "0.001", "maxQty": "1000", "stepSize": "0.001" },
+                { "filterType": "MARKET_LOT_SIZE", "minQty": "0.001", "maxQty": "120", "stepSize": "0.001" },
+                { "filterType": "MAX_NUM_ORDERS", "limit": 200 },
+                { "filterType": "MIN_NOTIONAL", "notional": "100" },
+                { "filterType": "PERCENT_PRICE", "multiplierUp": "1.0500", "multiplierDown": "0.9500", "multiplierDecimal": "4" },
+            ],
+            "orderTypes": ["LIMIT", "MARKET"],
+            "timeInForce": ["GTC"],
+        }))
+        .unwrap();
+        let filters = SymbolFilters::from(&symbol);
+        assert_eq!(filters.symbol, "BTCUSDT");
+        assert_eq!(
+            filters.price,
+            Some(PriceFilter::new(d("556.80"), d("4529764"), d("0.10")))
+        );
+        assert_eq!(
+            filters.lot_size,
+            Some(QuantityFilter::new(d("0.001"), d("1000"), d("0.001")))
+        );
+        assert_eq!(
+            filters.market_lot_size,
+            Some(QuantityFilter::new(d("0.001"), d("120"), d("0.001")))
+        );
+        assert_eq!(
+            filters.notional,
+            Some(NotionalFilter {
+                min_notional: d("100"),
+                max_notional: None,
+                apply_min_to_market: true,
+                apply_max_to_market: false,
+            })
+        );
+        assert_eq!(
+            filters.percent_price,
+            Some(PercentPriceFilter::new(d("1.05"), d("0.95")))
+        );
+    }
+
+    #[cfg(feature = "coin_futures_api")]
+    #[test]
+    fn coin_futures_symbol_filters() {
+        let symbol: crate::coin_futures::rest_model::Symbol = serde_json::from_value(serde_json::json!({
+            "symbol": "BTCUSD_PERP",
+            "pair": "BTCUSD",
+            "contractType": "PERPETUAL",
+            "deliveryDate": 4133404800000u64,
+            "onboardDate": 1597042800000u64,
+            "contractStatus": "TRADING",
+            "contractSize": 100,
+            "marginAsset": "BTC",
+            "maintMarginPercent": "2.5000",
+            "requiredMarginPercent": "5.0000",
+            "baseAsset": "BTC",
+            "quoteAsset": "USD",
+            "pricePrecision": 1,
+            "quantityPrecision": 0,
+            "baseAssetPrecision": 8,
+            "quotePrecision": 8,
+            "equalQtyPrecision": 4,
+            "underlyingType": "COIN",
+            "underlyingSubType": [],
+            "triggerProtect": "0.0500",
+            "filters": [
+                { "filterType": "PRICE_FILTER", "minPrice": "1000", "maxPrice": "4520958", "tickSize": "0.1" },
+                { "filterType": "LOT_SIZE", "minQty": "1", "maxQty": "1000000", "stepSize": "1" },
+                { "filterType": "MARKET_LOT_SIZE", "minQty": "1", "maxQty": "60000", "stepSize": "1" },
+                { "filterType": "PERCENT_PRICE", "multiplierUp": "1.0500", "multiplierDown": "0.9500", "multiplierDecimal": "4" },
+            ],
+            "orderTypes": ["LIMIT", "MARKET"],
+            "timeInForce": ["GTC"],
+        }))
+        .unwrap();
+        let filters = SymbolFilters::from(&symbol);
+        assert_eq!(filters.symbol, "BTCUSD_PERP");
+        assert_eq!(filters.price, Some(PriceFilter::new(d("1000"), d("4520958"), d("0.1"))));
+        assert_eq!(
+            filters.lot_size,
+            Some(QuantityFilter::new(d("1"), d("1000000"), d("1")))
+        );
+        assert_eq!(
+            filters.market_lot_size,
+            Some(QuantityFilter::new(d("1"), d("60000"), d("1")))
+        );
+        // Quantities are in contracts
+        assert_eq!(filters.notional, None);
+        assert_eq!(
+            filters.percent_price,
+            Some(PercentPriceFilter::new(d("1.05"), d("0.95")))
+        );
+    }
+}
@@ -21,7 +21,9 @@ impl FuturesGeneral {
     /// Obtain exchange information
     /// - Current exchange trading rules and symbol information
     pub async fn exchange_info(&self) -> Result<ExchangeInformation> {
-        self.client.get_p("/fapi/v1/exchangeInfo", None).await
+        let info: ExchangeInformation = self.client.get_p("/fapi/v1/exchangeInfo", None).await?;
+        self.client.set_rate_limits(&info.rate_limits);
+        Ok(info)
     }
 
     /// Get Symbol information
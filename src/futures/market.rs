@@ -1,8 +1,7 @@
 use crate::client::*;
 use crate::errors::*;
 use crate::futures::rest_model::*;
-use crate::rest_model::{BookTickers, KlineSummaries, KlineSummary, PairAndWindowQuery, PairQuery, SymbolPrice, Tickers};
-use crate::util::*;
+use crate::rest_model::{BookTickers, KlineSummaries, PairAndWindowQuery, PairQuery, SymbolPrice, Tickers};
 use serde_json::Value;
 
 //TODO : Validate intervals and start/end times in history queries
@@ -135,7 +134,8 @@ impl FuturesMarket {
     ) -> Result<Vec<OpenInterestHistory>>
     where
         S1: Into<String>,
-        S2: Into<String>,
+        S2: TryInto<DataPeriod>,
+        Error: From<S2::Error>,
         S3: Into<Option<u64>>,
         S4: Into<Option<u64>>,
         S5: Into<u16>,
@@ -145,11 +145,10 @@ impl FuturesMarket {
             end_time: end_time.into(),
             limit: limit.into(),
             symbol: symbol.into(),
-            period: Some(period.into()),
+            period: Some(period.try_into()?),
             from_id: None,
             interval: None,
         };
-        query.validate()?;
         self.client
             .get_signed_p("/futures/data/openInterestHist", Some(query), self.recv_window)
             .await
@@ -166,7 +165,8 @@ impl FuturesMarket {
     ) -> Result<Vec<LongShortRatio>>
     where
         S1: Into<String>,
-        S2: Into<String>,
+        S2: TryInto<DataPeriod>,
+        Error: From<S2::Error>,
         S3: Into<Option<u64>>,
         S4: Into<Option<u64>>,
         S5: Into<u16>,
@@ -176,11 +176,10 @@ impl FuturesMarket {
             end_time: end_time.into(),
             limit: limit.into(),
             symbol: symbol.into(),
-            period: Some(period.into()),
+            period: Some(period.try_into()?),
             from_id: None,
             interval: None,
         };
-        query.validate()?;
         self.client
             .get_signed_p("/futures/data/topLongShortAccountRatio", Some(query), self.recv_window)
             .await
@@ -197,7 +196,8 @@ impl FuturesMarket {
     ) -> Result<Vec<LongShortRatio>>
     where
         S1: Into<String>,
-        S2: Into<String>,
+        S2: TryInto<DataPeriod>,
+        Error: From<S2::Error>,
         S3: Into<Option<u64>>,
         S4: Into<Option<u64>>,
         S5: Into<u16>,
@@ -207,11 +207,10 @@ impl FuturesMarket {
             end_time: end_time.into(),
             limit: limit.into(),
             symbol: symbol.into(),
-            period: Some(period.into()),
+            period: Some(period.try_into()?),
             from_id: None,
             interval: None,
         };
-        query.validate()?;
         self.client
             .get_signed_p("/futures/data/topLongShortPositionRatio", Some(query), self.recv_window)
             .await
@@ -228,7 +227,8 @@ impl FuturesMarket {
     ) -> Result<Vec<LongShortRatio>>
     where
         S1: Into<String>,
-        S2: Into<String>,
+        S2: TryInto<DataPeriod>,
+        Error: From<S2::Error>,
         S3: Into<Option<u64>>,
         S4: Into<Option<u64>>,
         S5: Into<u16>,
@@ -238,11 +238,10 @@ impl FuturesMarket {
             end_time: end_time.into(),
             limit: limit.into(),
             symbol: symbol.into(),
-            period: Some(period.into()),
+            period: Some(period.try_into()?),
             from_id: None,
             interval: None,
         };
-        query.validate()?;
         self.client
             .get_signed_p(
                 "/futures/data/globalLongShortAccountRatio",
@@ -263,7 +262,8 @@ impl FuturesMarket {
     ) -> Result<Vec<LongShortRatio>>
     where
         S1: Into<String>,
-        S2: Into<String>,
+        S2: TryInto<DataPeriod>,
+        Error: From<S2::Error>,
         S3: Into<Option<u64>>,
         S4: Into<Option<u64>>,
         S5: Into<u16>,
@@ -273,11 +273,10 @@ impl FuturesMarket {
             end_time: end_time.into(),
             limit: limit.into(),
             symbol: symbol.into(),
-            period: Some(period.into()),
+            period: Some(period.try_into()?),
             from_id: None,
             interval: None,
         };
-        query.validate()?;
         self.client
             .get_signed_p("/futures/data/takerlongshortRatio", Some(query), self.recv_window)
             .await
@@ -295,7 +294,8 @@ impl FuturesMarket {
     ) -> Result<KlineSummaries>
     where
         S1: Into<String>,
-        S2: Into<String>,
+        S2: TryInto<KlineInterval>,
+        Error: From<S2::Error>,
         S3: Into<u16>,
         S4: Into<Option<u64>>,
         S5: Into<Option<u64>>,
@@ -305,37 +305,49 @@ impl FuturesMarket {
             end_time: end_time.into(),
             limit: limit.into(),
             symbol: symbol.into(),
-            interval: Some(interval.into()),
+            interval: Some(interval.try_into()?),
             from_id: None,
             period: None,
         };
-        let data: Vec<Vec<Value>> = self.client.get_d("/fapi/v1/klines", Some(query)).await?;
-
-        let klines = KlineSummaries::AllKlineSummaries(
-            data.iter()
-                .map(|row| KlineSummary {
-                    open_time: to_i64(&row[0]),
-                    open: to_f64(&row[1]),
-                    high: to_f64(&row[2]),
-                    low: to_f64(&row[3]),
-                    close: to_f64(&row[4]),
-                    volume: to_f64(&row[5]),
-                    close_time: to_i64(&row[6]),
-                    quote_asset_volume: to_f64(&row[7]),
-                    number_of_trades: to_i64(&row[8]),
-                    taker_buy_base_asset_volume: to_f64(&row[9]),
-                    taker_buy_quote_asset_volume: to_f64(&row[10]),
-                })
-                .collect(),
-        );
-        Ok(klines)
+        let klines = self.client.get_d("/fapi/v1/klines", Some(query)).await?;
+
+        Ok(KlineSummaries::AllKlineSummaries(klines))
     }
 
     /// Returns up to 'limit' blvt klines for given symbol and interval ("1m", "5m", ...)
     /// Note that the symbol is not the traditional pair but rather {symbol}{UP|DOWN}
     /// https://binance-docs.github.io/apidocs/futures/en/#blvt-nav-kline-candlestick-streams
-    /// As the vector fields are undocumented on binance futures you are un your own, follow
-    /// KlineSummary for an example
+    pub async fn get_blvt_klines<S1, S2, S3, S4, S5>(
+        &self,
+        symbol: S1,
+        interval: S2,
+        limit: S3,
+        start_time: S4,
+        end_time: S5,
+    ) -> Result<KlineSummaries>
+    where
+        S1: Into<String>,
+        S2: TryInto<KlineInterval>,
+        Error: From<S2::Error>,
+        S3: Into<u16>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let query = HistoryQuery {
+            start_time: start_time.into(),
+            end_time: end_time.into(),
+            limit: limit.into(),
+            symbol: symbol.into(),
+            interval: Some(interval.try_into()?),
+            from_id: None,
+            period: None,
+        };
+        let klines = self.client.get_d("/fapi/v1/lvtKlines", Some(query)).await?;
+
+        Ok(KlineSummaries::AllKlineSummaries(klines))
+    }
+
+    /// Raw rows of [`get_blvt_klines`](Self::get_blvt_klines)
     pub async fn get_blvt_klines_v<S1, S2, S3, S4, S5>(
         &self,
         symbol: S1,
@@ -346,7 +358,8 @@ impl FuturesMarket {
     ) -> Result<Vec<Vec<Value>>>
     where
         S1: Into<String>,
-        S2: Into<String>,
+        S2: TryInto<KlineInterval>,
+        Error: From<S2::Error>,
         S3: Into<u16>,
         S4: Into<Option<u64>>,
         S5: Into<Option<u64>>,
@@ -356,7 +369,7 @@ impl FuturesMarket {
             end_time: end_time.into(),
             limit: limit.into(),
             symbol: symbol.into(),
-            interval: Some(interval.into()),
+            interval: Some(interval.try_into()?),
             from_id: None,
             period: None,
         };
@@ -367,8 +380,37 @@ impl FuturesMarket {
 
     /// Returns up to 'limit' mark price klines for given symbol and interval ("1m", "5m", ...)
     /// https://binance-docs.github.io/apidocs/futures/en/#mark-price-kline-candlestick-data
-    /// As the vector fields are undocumented on binance futures you are un your own, follow
-    /// KlineSummary for an example
+    pub async fn get_mark_price_klines<S1, S2, S3, S4, S5>(
+        &self,
+        symbol: S1,
+        interval: S2,
+        limit: S3,
+        start_time: S4,
+        end_time: S5,
+    ) -> Result<KlineSummaries>
+    where
+        S1: Into<String>,
+        S2: TryInto<KlineInterval>,
+        Error: From<S2::Error>,
+        S3: Into<u16>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let query = HistoryQuery {
+            start_time: start_time.into(),
+            end_time: end_time.into(),
+            limit: limit.into(),
+            symbol: symbol.into(),
+            interval: Some(interval.try_into()?),
+            from_id: None,
+            period: None,
+        };
+        let klines = self.client.get_d("/fapi/v1/markPriceKlines", Some(query)).await?;
+
+        Ok(KlineSummaries::AllKlineSummaries(klines))
+    }
+
+    /// Raw rows of [`get_mark_price_klines`](Self::get_mark_price_klines)
     pub async fn get_mark_price_klines_v<S1, S2, S3, S4, S5>(
         &self,
         symbol: S1,
@@ -379,7 +421,8 @@ impl FuturesMarket {
     ) -> Result<Vec<Vec<Value>>>
     where
         S1: Into<String>,
-        S2: Into<String>,
+        S2: TryInto<KlineInterval>,
+        Error: From<S2::Error>,
         S3: Into<u16>,
         S4: Into<Option<u64>>,
         S5: Into<Option<u64>>,
@@ -389,7 +432,7 @@ impl FuturesMarket {
             end_time: end_time.into(),
             limit: limit.into(),
             symbol: symbol.into(),
-            interval: Some(interval.into()),
+            interval: Some(interval.try_into()?),
             from_id: None,
             period: None,
         };
@@ -400,8 +443,36 @@ impl FuturesMarket {
 
     /// Returns up to 'limit' index price klines for given symbol and interval ("1m", "5m", ...)
     /// https://binance-docs.github.io/apidocs/futures/en/#index-price-kline-candlestick-data
-    /// As the vector fields are undocumented on binance futures you are un your own, follow
-    /// KlineSummary for an example
+    pub async fn get_index_price_klines<S1, S2, S3, S4, S5>(
+        &self,
+        symbol: S1,
+        interval: S2,
+        limit: S3,
+        start_time: S4,
+        end_time: S5,
+    ) -> Result<KlineSummaries>
+    where
+        S1: Into<String>,
+        S2: TryInto<KlineInterval>,
+        Error: From<S2::Error>,
+        S3: Into<u16>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let query = IndexQuery {
+            start_time: start_time.into(),
+            end_time: end_time.into(),
+            limit: limit.into(),
+            pair: symbol.into(),
+            interval: Some(interval.try_into()?),
+        };
+
+        let klines = self.client.get_d("/fapi/v1/indexPriceKlines", Some(query)).await?;
+
+        Ok(KlineSummaries::AllKlineSummaries(klines))
+    }
+
+    /// Raw rows of [`get_index_price_klines`](Self::get_index_price_klines)
     pub async fn get_index_price_klines_v<S1, S2, S3, S4, S5>(
         &self,
         symbol: S1,
@@ -412,7 +483,8 @@ impl FuturesMarket {
     ) -> Result<Vec<Vec<Value>>>
     where
         S1: Into<String>,
-        S2: Into<String>,
+        S2: TryInto<KlineInterval>,
+        Error: From<S2::Error>,
         S3: Into<u16>,
         S4: Into<Option<u64>>,
         S5: Into<Option<u64>>,
@@ -422,7 +494,7 @@ impl FuturesMarket {
             end_time: end_time.into(),
             limit: limit.into(),
             pair: symbol.into(),
-            interval: Some(interval.into()),
+            interval: Some(interval.try_into()?),
         };
 
         let klines = self.client.get_d("/fapi/v1/indexPriceKlines", Some(query)).await?;
@@ -432,8 +504,37 @@ impl FuturesMarket {
 
     /// Returns up to 'limit' continuous contract klines for given symbol and interval ("1m", "5m", ...)
     /// https://binance-docs.github.io/apidocs/futures/en/#continuous-contract-kline-candlestick-data
-    /// As the vector fields are undocumented on binance futures you are un your own, follow
-    /// KlineSummary for an example
+    pub async fn get_continuous_contract_klines<S1, S2, S3, S4, S5>(
+        &self,
+        symbol: S1,
+        interval: S2,
+        limit: S3,
+        start_time: S4,
+        end_time: S5,
+    ) -> Result<KlineSummaries>
+    where
+        S1: Into<String>,
+        S2: TryInto<KlineInterval>,
+        Error: From<S2::Error>,
+        S3: Into<u16>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let query = HistoryQuery {
+            start_time: start_time.into(),
+            end_time: end_time.into(),
+            limit: limit.into(),
+            symbol: symbol.into(),
+            interval: Some(interval.try_into()?),
+            from_id: None,
+            period: None,
+        };
+        let klines = self.client.get_d("/fapi/v1/continuousKlines", Some(query)).await?;
+
+        Ok(KlineSummaries::AllKlineSummaries(klines))
+    }
+
+    /// Raw rows of [`get_continuous_contract_klines`](Self::get_continuous_contract_klines)
     pub async fn get_continuous_contract_klines_v<S1, S2, S3, S4, S5>(
         &self,
         symbol: S1,
@@ -444,7 +545,8 @@ impl FuturesMarket {
     ) -> Result<Vec<Vec<Value>>>
     where
         S1: Into<String>,
-        S2: Into<String>,
+        S2: TryInto<KlineInterval>,
+        Error: From<S2::Error>,
         S3: Into<u16>,
         S4: Into<Option<u64>>,
         S5: Into<Option<u64>>,
@@ -454,7 +556,7 @@ impl FuturesMarket {
             end_time: end_time.into(),
             limit: limit.into(),
             symbol: symbol.into(),
-            interval: Some(interval.into()),
+            interval: Some(interval.try_into()?),
             from_id: None,
             period: None,
         };
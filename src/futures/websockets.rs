@@ -1,16 +1,11 @@
-use std::sync::atomic::{AtomicBool, Ordering};
-
-use futures::StreamExt;
-use serde_json::from_str;
-use tokio::net::TcpStream;
-use tokio_tungstenite::tungstenite::handshake::client::Response;
-use tokio_tungstenite::tungstenite::Message;
-use tokio_tungstenite::WebSocketStream;
-use tokio_tungstenite::{connect_async, MaybeTlsStream};
-use url::Url;
-
-use crate::config::Config;
+use std::sync::atomic::AtomicBool;
+
+use crate::config::{Config, Product};
 use crate::errors::*;
+use crate::rest_model::KlineInterval;
+use crate::websockets::{combined_stream_url, stream_url, SubscriptionHandle};
+use crate::ws_model::ReconnectedEvent;
+use crate::ws_transport::WsConnection;
 
 pub static STREAM_ENDPOINT: &str = "stream";
 pub static WS_ENDPOINT: &str = "ws";
@@ -31,7 +26,14 @@ pub fn agg_trade_stream(symbol: &str) -> String { format!("{symbol}@aggTrade") }
 
 pub fn trade_stream(symbol: &str) -> String { format!("{symbol}@trade") }
 
-pub fn kline_stream(symbol: &str, interval: &str) -> String { format!("{symbol}@kline_{interval}") }
+/// Kline stream of `symbol`, the interval is a [`KlineInterval`] or its string such as `"1m"`
+pub fn kline_stream<I>(symbol: &str, interval: I) -> Result<String>
+where
+    I: TryInto<KlineInterval>,
+    Error: From<I::Error>,
+{
+    Ok(format!("{symbol}@kline_{}", interval.try_into()?))
+}
 
 pub fn book_ticker_stream(symbol: &str) -> String { format!("{symbol}@bookTicker") }
 
@@ -56,13 +58,9 @@ pub fn partial_book_depth_stream(symbol: &str, levels: u16, update_speed: u16) -
 /// * `update_speed`: 1000 or 100
 pub fn diff_book_depth_stream(symbol: &str, update_speed: u16) -> String { format!("{symbol}@depth@{update_speed}ms") }
 
-fn combined_stream(streams: Vec<String>) -> String { streams.join("/") }
-
-pub struct WebSockets<'a, WE> {
-    pub socket: Option<(WebSocketStream<MaybeTlsStream<TcpStream>>, Response)>,
-    handler: Box<dyn FnMut(WE) -> Result<()> + 'a + Send>,
-    conf: Config,
-}
+/// Websocket holder for USD-M futures streams, shares the event loop and subscription management of
+/// [`crate::websockets::WebSockets`]
+pub struct WebSockets<'a, WE>(crate::websockets::WebSockets<'a, WE>);
 
 impl<'a, WE: serde::de::DeserializeOwned> WebSockets<'a, WE> {
     /// New websocket holder with default configuration
@@ -82,76 +80,46 @@ impl<'a, WE: serde::de::DeserializeOwned> WebSockets<'a, WE> {
     where
         Callback: FnMut(WE) -> Result<()> + 'a + Send,
     {
-        WebSockets {
-            socket: None,
-            handler: Box::new(handler),
-            conf,
-        }
+        WebSockets(crate::websockets::WebSockets::new_with_options(handler, conf))
     }
 
     /// Connect to multiple websocket endpoints
     /// N.B: WE has to be CombinedStreamEvent
     pub async fn connect_multiple(&mut self, endpoints: Vec<String>) -> Result<()> {
-        let mut url = Url::parse(&self.conf.futures_ws_endpoint)?;
-        url.path_segments_mut()
-            .map_err(|_| Error::UrlParserError(url::ParseError::RelativeUrlWithoutBase))?
-            .push(STREAM_ENDPOINT);
-        url.set_query(Some(&format!("streams={}", combined_stream(endpoints))));
+        self.0.conf().environment.check(Product::UsdMFutures)?;
+        let url = combined_stream_url(&self.0.conf().futures_ws_endpoint, endpoints.clone())?;
 
-        self.handle_connect(url).await
+        self.0.handle_connect(url, endpoints).await
     }
 
     /// Connect to a websocket endpoint
     pub async fn connect(&mut self, endpoint: &str) -> Result<()> {
-        let wss: String = format!("{}/{}/{}", self.conf.futures_ws_endpoint, WS_ENDPOINT, endpoint);
-        let url = Url::parse(&wss)?;
+        self.0.conf().environment.check(Product::UsdMFutures)?;
+        let url = stream_url(&self.0.conf().futures_ws_endpoint, endpoint)?;
 
-        self.handle_connect(url).await
+        self.0.handle_connect(url, vec![endpoint.to_string()]).await
     }
 
-    async fn handle_connect(&mut self, url: Url) -> Result<()> {
-        match connect_async(url.as_str()).await {
-            Ok(answer) => {
-                self.socket = Some(answer);
-                Ok(())
-            }
-            Err(e) => Err(Error::Msg(format!("Error during handshake {e}"))),
-        }
+    /// See [`crate::websockets::WebSockets::on_reconnect`]
+    pub fn on_reconnect<Callback>(self, handler: Callback) -> Self
+    where
+        Callback: FnMut(ReconnectedEvent) -> Result<()> + 'a + Send,
+    {
+        WebSockets(self.0.on_reconnect(handler))
     }
 
+    /// See [`crate::websockets::WebSockets::subscriptions`]
+    pub fn subscriptions(&self) -> SubscriptionHandle { self.0.subscriptions() }
+
+    /// Streams currently subscribed to
+    pub fn streams(&self) -> &[String] { self.0.streams() }
+
+    pub fn conf(&self) -> &Config { self.0.conf() }
+
     /// Disconnect from the endpoint
-    pub async fn disconnect(&mut self) -> Result<()> {
-        if let Some(ref mut socket) = self.socket {
-            socket.0.close(None).await?;
-            Ok(())
-        } else {
-            Err(Error::Msg("Not able to close the connection".to_string()))
-        }
-    }
+    pub async fn disconnect(&mut self) -> Result<()> { self.0.disconnect().await }
 
-    pub fn socket(&self) -> &Option<(WebSocketStream<MaybeTlsStream<TcpStream>>, Response)> { &self.socket }
-
-    pub async fn event_loop(&mut self, running: &AtomicBool) -> Result<()> {
-        while running.load(Ordering::Relaxed) {
-            if let Some((ref mut socket, _)) = self.socket {
-                // TODO: return error instead of panic?
-                let message = socket.next().await.unwrap()?;
-
-                match message {
-                    Message::Text(msg) => {
-                        if msg.is_empty() {
-                            return Ok(());
-                        }
-                        let event: WE = from_str(msg.as_str())?;
-                        (self.handler)(event)?;
-                    }
-                    Message::Ping(_) | Message::Pong(_) | Message::Binary(_) | Message::Frame(_) => {}
-                    Message::Close(e) => {
-                        return Err(Error::Msg(format!("Disconnected {e:?}")));
-                    }
-                }
-            }
-        }
-        Ok(())
-    }
+    pub fn socket(&self) -> &Option<WsConnection> { self.0.socket() }
+
+    pub async fn event_loop(&mut self, running: &AtomicBool) -> Result<()> { self.0.event_loop(running).await }
 }
@@ -75,7 +75,10 @@ pub mod util;
 
 pub mod account;
 pub mod api;
+#[cfg(feature = "blocking")]
+pub mod blocking;
 pub mod config;
+pub mod credentials;
 #[cfg(feature = "futures_api")]
 pub mod futures;
 #[cfg(feature = "coin_futures_api")]
@@ -84,15 +87,28 @@ pub mod coin_futures;
 pub mod portfolio_margin;
 #[cfg(feature = "options_api")]
 pub mod options;
+pub mod failover;
+pub mod filters;
 pub mod general;
 #[cfg(feature = "margin_api")]
 pub mod margin;
 pub mod market;
+pub mod orderbook;
+pub mod proxy;
+pub mod rate_limit;
 pub mod rest_model;
+mod runtime;
 #[cfg(feature = "savings_api")]
 pub mod savings;
+pub mod signer;
+#[cfg(feature = "testing")]
+pub mod testing;
+pub mod time_sync;
+pub mod transport;
 pub mod userstream;
 #[cfg(feature = "wallet_api")]
 pub mod wallet;
 pub mod websockets;
+pub mod ws_api;
 pub mod ws_model;
+pub mod ws_transport;
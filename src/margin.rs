@@ -48,13 +48,13 @@ impl Margin {
     /// ```rust,no_run
     /// use binance::{api::*, margin::*, config::*, rest_model::*};
     /// let margin: Margin = Binance::new_with_env(&Config::testnet());
-    /// let transaction_id = tokio_test::block_on(margin.transfer("BTCUSDT", 0.001, MarginTransferType::FromMainToMargin));
+    /// let transaction_id = tokio_test::block_on(margin.transfer("BTCUSDT", "0.001".parse::<Decimal>().unwrap(), MarginTransferType::FromMainToMargin));
     /// assert!(transaction_id.is_ok(), "{:?}", transaction_id);
     /// ```
     pub async fn transfer<S, F>(&self, symbol: S, qty: F, transfer_type: MarginTransferType) -> Result<TransactionId>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Decimal>,
     {
         let transfer: Transfer = Transfer {
             asset: symbol.into(),
@@ -71,7 +71,7 @@ impl Margin {
     /// ```rust,no_run
     /// use binance::{api::*, margin::*, config::*, rest_model::*};
     /// let margin: Margin = Binance::new_with_env(&Config::testnet());
-    /// let transaction_id = tokio_test::block_on(margin.isolated_transfer("BTC", "BTC", 0.001, IsolatedMarginTransferType::Spot, IsolatedMarginTransferType::IsolatedMargin));
+    /// let transaction_id = tokio_test::block_on(margin.isolated_transfer("BTC", "BTC", "0.001".parse::<Decimal>().unwrap(), IsolatedMarginTransferType::Spot, IsolatedMarginTransferType::IsolatedMargin));
     /// assert!(transaction_id.is_ok(), "{:?}", transaction_id);
     /// ```
     pub async fn isolated_transfer<S, F>(
@@ -84,7 +84,7 @@ impl Margin {
     ) -> Result<TransactionId>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Decimal>,
     {
         let transfer = IsolatedTransfer {
             asset: asset_symbol.into(),
@@ -103,13 +103,13 @@ impl Margin {
     /// ```rust,no_run
     /// use binance::{api::*, margin::*, config::*, rest_model::*};
     /// let margin: Margin = Binance::new_with_env(&Config::testnet());
-    /// let transaction_id = tokio_test::block_on(margin.loan("BTCUSDT", 0.001));
+    /// let transaction_id = tokio_test::block_on(margin.loan("BTCUSDT", "0.001".parse::<Decimal>().unwrap()));
     /// assert!(transaction_id.is_ok(), "{:?}", transaction_id);
     /// ```
     pub async fn loan<S, F>(&self, symbol: S, qty: F) -> Result<TransactionId>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Decimal>,
     {
         self.loan_with_isolation(symbol, qty, None, None).await
     }
@@ -119,7 +119,7 @@ impl Margin {
     /// ```rust,no_run
     /// use binance::{api::*, margin::*, config::*, rest_model::*};
     /// let margin: Margin = Binance::new_with_env(&Config::testnet());
-    /// let transaction_id = tokio_test::block_on(margin.loan_with_isolation("BTCUSDT", 0.001, Some(true), Some("BNB".to_string())));
+    /// let transaction_id = tokio_test::block_on(margin.loan_with_isolation("BTCUSDT", "0.001".parse::<Decimal>().unwrap(), Some(true), Some("BNB".to_string())));
     /// assert!(transaction_id.is_ok(), "{:?}", transaction_id);
     /// ```
     pub async fn loan_with_isolation<S, F>(
@@ -131,7 +131,7 @@ impl Margin {
     ) -> Result<TransactionId>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Decimal>,
     {
         let loan: Loan = Loan {
             asset: symbol.into(),
@@ -148,13 +148,13 @@ impl Margin {
     /// ```rust,no_run
     /// use binance::{api::*, margin::*, config::*, rest_model::*};
     /// let margin: Margin = Binance::new_with_env(&Config::testnet());
-    /// let transaction_id = tokio_test::block_on(margin.repay("BTCUSDT", 0.001));
+    /// let transaction_id = tokio_test::block_on(margin.repay("BTCUSDT", "0.001".parse::<Decimal>().unwrap()));
     /// assert!(transaction_id.is_ok(), "{:?}", transaction_id);
     /// ```
     pub async fn repay<S, F>(&self, symbol: S, qty: F) -> Result<TransactionId>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Decimal>,
     {
         self.repay_with_isolation(symbol, qty, None, None).await
     }
@@ -164,7 +164,7 @@ impl Margin {
     /// ```rust,no_run
     /// use binance::{api::*, margin::*, config::*, rest_model::*};
     /// let margin: Margin = Binance::new_with_env(&Config::testnet());
-    /// let transaction_id = tokio_test::block_on(margin.repay_with_isolation("BTCUSDT", 0.001, Some(true), Some("BNB".to_string())));
+    /// let transaction_id = tokio_test::block_on(margin.repay_with_isolation("BTCUSDT", "0.001".parse::<Decimal>().unwrap(), Some(true), Some("BNB".to_string())));
     /// assert!(transaction_id.is_ok(), "{:?}", transaction_id);
     /// ```
     pub async fn repay_with_isolation<S, F>(
@@ -176,7 +176,7 @@ impl Margin {
     ) -> Result<TransactionId>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Decimal>,
     {
         let loan: Loan = Loan {
             asset: symbol.into(),
@@ -198,12 +198,12 @@ impl Margin {
     ///     symbol: "BTCUSDT".to_string(),
     ///     side: OrderSide::Sell,
     ///     order_type: OrderType::Limit,
-    ///     quantity: Some(0.001),
+    ///     quantity: Some("0.001".parse().unwrap()),
     ///     quote_order_qty: None,
-    ///     price: Some(10.0),
-    ///     stop_price: Some(10.0),
+    ///     price: Some("10.0".parse().unwrap()),
+    ///     stop_price: Some("10.0".parse().unwrap()),
     ///     new_client_order_id: Some("my_id".to_string()),
-    ///     iceberg_qty: Some(10.0),
+    ///     iceberg_qty: Some("10.0".parse().unwrap()),
     ///     new_order_resp_type: OrderResponse::Ack,
     ///     time_in_force: Some(TimeInForce::FOK),
     ///     side_effect_type: SideEffectType::NoSideEffect,
@@ -227,12 +227,12 @@ impl Margin {
     ///     symbol: "BTCUSDT".to_string(),
     ///     side: OrderSide::Sell,
     ///     order_type: OrderType::Limit,
-    ///     quantity: Some(0.001),
+    ///     quantity: Some("0.001".parse().unwrap()),
     ///     quote_order_qty: None,
-    ///     price: Some(10.0),
-    ///     stop_price: Some(10.0),
+    ///     price: Some("10.0".parse().unwrap()),
+    ///     stop_price: Some("10.0".parse().unwrap()),
     ///     new_client_order_id: Some("my_id".to_string()),
-    ///     iceberg_qty: Some(10.0),
+    ///     iceberg_qty: Some("10.0".parse().unwrap()),
     ///     new_order_resp_type: OrderResponse::Ack,
     ///     time_in_force: Some(TimeInForce::FOK),
     ///     side_effect_type: SideEffectType::NoSideEffect,
@@ -253,9 +253,9 @@ impl Margin {
     /// let margin_order = MarginOCOOrder {
     ///     symbol: "BTCUSDT".to_string(),
     ///     side: OrderSide::Sell,
-    ///     quantity: 10.0,
-    ///     price: 10.0,
-    ///     stop_price: 1.0,
+    ///     quantity: "10.0".parse().unwrap(),
+    ///     price: "10.0".parse().unwrap(),
+    ///     stop_price: "1.0".parse().unwrap(),
     ///     ..MarginOCOOrder::default()
     /// };
     /// let transaction_id = tokio_test::block_on(margin.new_oco_order(margin_order));
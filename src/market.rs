@@ -2,7 +2,6 @@ use crate::client::*;
 use crate::errors::*;
 use crate::rest_model::*;
 use crate::util::*;
-use serde_json::Value;
 // use std::collections::BTreeMap;
 
 static API_V3_DEPTH: &str = "/api/v3/depth";
@@ -197,7 +196,7 @@ impl Market {
         self.client.get_p(API_V3_AGG_TRADES, Some(&request)).await
     }
 
-    /// Returns up to 'limit' klines for given symbol and interval ("1m", "5m", ...)
+    /// Returns up to 'limit' klines for given symbol and interval, a [`KlineInterval`] or its string ("1m", "5m", ...)
     /// <https://github.com/binance-exchange/binance-official-api-docs/blob/master/rest-api.md#klinecandlestick-data>
     /// # Examples
     /// ```rust
@@ -217,14 +216,16 @@ impl Market {
     ) -> Result<KlineSummaries>
     where
         S1: Into<String>,
-        S2: Into<String>,
+        S2: TryInto<KlineInterval>,
+        Error: From<S2::Error>,
         S3: Into<Option<u16>>,
         S4: Into<Option<u64>>,
         S5: Into<Option<u64>>,
     {
+        let interval: KlineInterval = interval.try_into()?;
         let parameters = IntoIterator::into_iter([
             Some(("symbol", symbol.into())),
-            Some(("interval", interval.into())),
+            Some(("interval", interval.to_string())),
             limit.into().map(|l| ("limit", l.to_string())),
             start_time.into().map(|s| ("startTime", s.to_string())),
             end_time.into().map(|e| ("endTime", e.to_string())),
@@ -233,26 +234,8 @@ impl Market {
 
         let request = build_request(parameters);
 
-        let parsed_data: Vec<Vec<Value>> = self.client.get(API_V3_KLINES, Some(&request)).await?;
+        let klines = self.client.get(API_V3_KLINES, Some(&request)).await?;
 
-        let klines = KlineSummaries::AllKlineSummaries(
-            parsed_data
-                .iter()
-                .map(|row| KlineSummary {
-                    open_time: to_i64(&row[0]),
-                    open: to_f64(&row[1]),
-                    high: to_f64(&row[2]),
-                    low: to_f64(&row[3]),
-                    close: to_f64(&row[4]),
-                    volume: to_f64(&row[5]),
-                    close_time: to_i64(&row[6]),
-                    quote_asset_volume: to_f64(&row[7]),
-                    number_of_trades: to_i64(&row[8]),
-                    taker_buy_base_asset_volume: to_f64(&row[9]),
-                    taker_buy_quote_asset_volume: to_f64(&row[10]),
-                })
-                .collect(),
-        );
-        Ok(klines)
+        Ok(KlineSummaries::AllKlineSummaries(klines))
     }
 }
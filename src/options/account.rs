@@ -4,7 +4,6 @@ use crate::client::Client;
 use crate::errors::*;
 use crate::options::rest_model::*;
 use crate::rest_model::PairQuery;
-use crate::util::{build_signed_request, build_signed_request_p};
 
 #[derive(Clone)]
 pub struct OptionsAccount {
@@ -31,7 +30,9 @@ struct ExerciseRecordQuery {
 impl OptionsAccount {
     /// Get account asset information
     pub async fn account_information(&self) -> Result<Vec<AccountInformation>> {
-        let request = build_signed_request(BTreeMap::<String, String>::new(), self.recv_window)?;
+        let request = self
+            .client
+            .build_signed_request(BTreeMap::<String, String>::new(), self.recv_window)?;
         self.client.get_signed_d("/eapi/v1/account", &request).await
     }
 
@@ -70,7 +71,9 @@ impl OptionsAccount {
 
     /// Get all open options orders for a symbol
     pub async fn get_open_orders(&self, symbol: impl Into<String>) -> Result<Vec<OptionOrder>> {
-        let payload = build_signed_request_p(PairQuery { symbol: symbol.into() }, self.recv_window)?;
+        let payload = self
+            .client
+            .build_signed_request_p(PairQuery { symbol: symbol.into() }, self.recv_window)?;
         self.client.get_signed("/eapi/v1/openOrders", &payload).await
     }
 
@@ -88,7 +91,9 @@ impl OptionsAccount {
 
     /// Get options position information for a symbol
     pub async fn get_position(&self, symbol: impl Into<String>) -> Result<Vec<OptionPosition>> {
-        let payload = build_signed_request_p(PairQuery { symbol: symbol.into() }, self.recv_window)?;
+        let payload = self
+            .client
+            .build_signed_request_p(PairQuery { symbol: symbol.into() }, self.recv_window)?;
         self.client.get_signed("/eapi/v1/position", &payload).await
     }
 
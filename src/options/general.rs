@@ -17,6 +17,8 @@ impl OptionsGeneral {
     pub async fn get_server_time(&self) -> Result<ServerTime> { self.client.get_p("/eapi/v1/time", None).await }
 
     pub async fn exchange_info(&self) -> Result<ExchangeInformation> {
-        self.client.get_p("/eapi/v1/exchangeInfo", None).await
+        let info: ExchangeInformation = self.client.get_p("/eapi/v1/exchangeInfo", None).await?;
+        self.client.set_rate_limits(&info.rate_limits);
+        Ok(info)
     }
 }
@@ -1,7 +1,7 @@
 use crate::client::*;
 use crate::errors::*;
 use crate::options::rest_model::*;
-use crate::rest_model::PairQuery;
+use crate::rest_model::{KlineSummaries, PairQuery};
 
 #[derive(Clone)]
 pub struct OptionsMarket {
@@ -38,23 +38,29 @@ impl OptionsMarket {
             .await
     }
 
-    /// Get kline/candlestick data for a symbol
-    pub async fn get_klines(
+    /// Get kline/candlestick data for a symbol, the interval is a [`KlineInterval`] or its string
+    pub async fn get_klines<I>(
         &self,
         symbol: impl Into<String>,
-        interval: impl Into<String>,
+        interval: I,
         start_time: Option<u64>,
         end_time: Option<u64>,
         limit: Option<u16>,
-    ) -> Result<Vec<Vec<serde_json::Value>>> {
+    ) -> Result<KlineSummaries>
+    where
+        I: TryInto<KlineInterval>,
+        Error: From<I::Error>,
+    {
         let query = OptionKlineQuery {
             symbol: symbol.into(),
-            interval: interval.into(),
+            interval: interval.try_into()?,
             start_time,
             end_time,
             limit,
         };
-        self.client.get_d("/eapi/v1/klines", Some(query)).await
+        let klines = self.client.get_d("/eapi/v1/klines", Some(query)).await?;
+
+        Ok(KlineSummaries::AllKlineSummaries(klines))
     }
 
     /// Get open interest for an underlying asset
@@ -1,5 +1,6 @@
-pub use crate::rest_model::{Asks, Bids, OrderSide, OrderStatus, RateLimit, ServerTime, TimeInForce};
 use crate::rest_model::string_or_float;
+pub use crate::rest_model::{Asks, Bids, Decimal, KlineInterval, OrderSide, OrderStatus, RateLimit, ServerTime,
+                            TimeInForce};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -40,21 +41,21 @@ pub struct OptionSymbol {
     pub symbol: String,
     pub side: OptionSide,
     #[serde(with = "string_or_float")]
-    pub strike_price: f64,
+    pub strike_price: Decimal,
     pub underlying: String,
     pub unit: u64,
     #[serde(with = "string_or_float")]
-    pub min_qty: f64,
+    pub min_qty: Decimal,
     #[serde(with = "string_or_float")]
-    pub max_qty: f64,
+    pub max_qty: Decimal,
     #[serde(with = "string_or_float")]
-    pub initial_margin: f64,
+    pub initial_margin: Decimal,
     #[serde(with = "string_or_float")]
-    pub maintenance_margin: f64,
+    pub maintenance_margin: Decimal,
     #[serde(with = "string_or_float")]
-    pub min_initial_margin: f64,
+    pub min_initial_margin: Decimal,
     #[serde(with = "string_or_float")]
-    pub min_maintenance_margin: f64,
+    pub min_maintenance_margin: Decimal,
     pub price_scale: u16,
     pub quantity_scale: u16,
     pub quote_asset: String,
@@ -74,21 +75,21 @@ pub enum Filters {
     #[serde(rename_all = "camelCase")]
     PriceFilter {
         #[serde(with = "string_or_float")]
-        min_price: f64,
+        min_price: Decimal,
         #[serde(with = "string_or_float")]
-        max_price: f64,
+        max_price: Decimal,
         #[serde(with = "string_or_float")]
-        tick_size: f64,
+        tick_size: Decimal,
     },
     #[serde(rename = "LOT_SIZE")]
     #[serde(rename_all = "camelCase")]
     LotSize {
         #[serde(with = "string_or_float")]
-        min_qty: f64,
+        min_qty: Decimal,
         #[serde(with = "string_or_float")]
-        max_qty: f64,
+        max_qty: Decimal,
         #[serde(with = "string_or_float")]
-        step_size: f64,
+        step_size: Decimal,
     },
     #[serde(other)]
     Others,
@@ -114,35 +115,35 @@ pub struct OrderBook {
 pub struct OptionTicker {
     pub symbol: String,
     #[serde(with = "string_or_float")]
-    pub price_change: f64,
+    pub price_change: Decimal,
     #[serde(with = "string_or_float")]
-    pub price_change_percent: f64,
+    pub price_change_percent: Decimal,
     #[serde(with = "string_or_float")]
-    pub last_price: f64,
+    pub last_price: Decimal,
     #[serde(with = "string_or_float")]
-    pub last_qty: f64,
+    pub last_qty: Decimal,
     #[serde(with = "string_or_float")]
-    pub open: f64,
+    pub open: Decimal,
     #[serde(with = "string_or_float")]
-    pub high: f64,
+    pub high: Decimal,
     #[serde(with = "string_or_float")]
-    pub low: f64,
+    pub low: Decimal,
     #[serde(with = "string_or_float")]
-    pub volume: f64,
+    pub volume: Decimal,
     #[serde(with = "string_or_float")]
-    pub amount: f64,
+    pub amount: Decimal,
     #[serde(with = "string_or_float")]
-    pub bid_price: f64,
+    pub bid_price: Decimal,
     #[serde(with = "string_or_float")]
-    pub ask_price: f64,
+    pub ask_price: Decimal,
     pub open_time: u64,
     pub close_time: u64,
     pub first_trade_id: i64,
     pub trade_count: u64,
     #[serde(with = "string_or_float")]
-    pub strike_price: f64,
+    pub strike_price: Decimal,
     #[serde(with = "string_or_float")]
-    pub exercise_price: f64,
+    pub exercise_price: Decimal,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -150,25 +151,25 @@ pub struct OptionTicker {
 pub struct OptionMarkPrice {
     pub symbol: String,
     #[serde(with = "string_or_float")]
-    pub mark_price: f64,
+    pub mark_price: Decimal,
     #[serde(with = "string_or_float")]
-    pub bid_iv: f64,
+    pub bid_iv: Decimal,
     #[serde(with = "string_or_float")]
-    pub ask_iv: f64,
+    pub ask_iv: Decimal,
     #[serde(with = "string_or_float")]
-    pub mark_iv: f64,
+    pub mark_iv: Decimal,
     #[serde(with = "string_or_float")]
-    pub delta: f64,
+    pub delta: Decimal,
     #[serde(with = "string_or_float")]
-    pub theta: f64,
+    pub theta: Decimal,
     #[serde(with = "string_or_float")]
-    pub gamma: f64,
+    pub gamma: Decimal,
     #[serde(with = "string_or_float")]
-    pub vega: f64,
+    pub vega: Decimal,
     #[serde(with = "string_or_float")]
-    pub high_price_limit: f64,
+    pub high_price_limit: Decimal,
     #[serde(with = "string_or_float")]
-    pub low_price_limit: f64,
+    pub low_price_limit: Decimal,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -177,11 +178,11 @@ pub struct OptionTrade {
     pub id: u64,
     pub symbol: String,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Decimal,
     #[serde(with = "string_or_float")]
-    pub qty: f64,
+    pub qty: Decimal,
     #[serde(with = "string_or_float")]
-    pub quote_qty: f64,
+    pub quote_qty: Decimal,
     pub side: i32,
     pub time: u64,
 }
@@ -191,9 +192,9 @@ pub struct OptionTrade {
 pub struct OptionOpenInterest {
     pub symbol: String,
     #[serde(with = "string_or_float")]
-    pub sum_open_interest: f64,
+    pub sum_open_interest: Decimal,
     #[serde(with = "string_or_float")]
-    pub sum_open_interest_usd: f64,
+    pub sum_open_interest_usd: Decimal,
     pub timestamp: String,
 }
 
@@ -202,19 +203,19 @@ pub struct OptionOpenInterest {
 pub struct AccountInformation {
     pub asset: String,
     #[serde(with = "string_or_float")]
-    pub margin_balance: f64,
+    pub margin_balance: Decimal,
     #[serde(with = "string_or_float")]
-    pub equity: f64,
+    pub equity: Decimal,
     #[serde(with = "string_or_float")]
-    pub available_balance: f64,
+    pub available_balance: Decimal,
     #[serde(with = "string_or_float")]
-    pub max_withdraw_amount: f64,
+    pub max_withdraw_amount: Decimal,
     #[serde(with = "string_or_float")]
-    pub unrealized_pnl: f64,
+    pub unrealized_pnl: Decimal,
     #[serde(with = "string_or_float")]
-    pub maint_margin: f64,
+    pub maint_margin: Decimal,
     #[serde(with = "string_or_float")]
-    pub initial_margin: f64,
+    pub initial_margin: Decimal,
     pub update_time: u64,
 }
 
@@ -224,19 +225,19 @@ pub struct OptionPosition {
     pub symbol: String,
     pub side: String,
     #[serde(with = "string_or_float")]
-    pub quantity: f64,
+    pub quantity: Decimal,
     #[serde(with = "string_or_float")]
-    pub reduced_quantity: f64,
+    pub reduced_quantity: Decimal,
     #[serde(with = "string_or_float")]
-    pub entry_price: f64,
+    pub entry_price: Decimal,
     #[serde(with = "string_or_float")]
-    pub mark_price: f64,
+    pub mark_price: Decimal,
     #[serde(with = "string_or_float")]
-    pub unrealized_pnl: f64,
+    pub unrealized_pnl: Decimal,
     pub ror: String,
     pub expiry_date: u64,
     #[serde(with = "string_or_float")]
-    pub strike_price: f64,
+    pub strike_price: Decimal,
     pub update_time: u64,
 }
 
@@ -246,13 +247,13 @@ pub struct OptionOrder {
     pub order_id: u64,
     pub symbol: String,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Decimal,
     #[serde(with = "string_or_float")]
-    pub quantity: f64,
+    pub quantity: Decimal,
     #[serde(with = "string_or_float")]
-    pub executed_qty: f64,
+    pub executed_qty: Decimal,
     #[serde(with = "string_or_float")]
-    pub fee: f64,
+    pub fee: Decimal,
     pub side: OrderSide,
     pub order_type: OrderType,
     pub time_in_force: TimeInForce,
@@ -262,13 +263,13 @@ pub struct OptionOrder {
     pub update_time: u64,
     pub status: OrderStatus,
     #[serde(with = "string_or_float")]
-    pub avg_price: f64,
+    pub avg_price: Decimal,
     pub source: String,
     pub client_order_id: String,
     #[serde(with = "string_or_float")]
-    pub price_scale: f64,
+    pub price_scale: Decimal,
     #[serde(with = "string_or_float")]
-    pub quantity_scale: f64,
+    pub quantity_scale: Decimal,
     pub option_side: OptionSide,
     pub quote_asset: String,
     pub mmp: bool,
@@ -280,19 +281,19 @@ pub struct ExerciseRecord {
     pub id: String,
     pub symbol: String,
     #[serde(with = "string_or_float")]
-    pub strike_price: f64,
+    pub strike_price: Decimal,
     #[serde(with = "string_or_float")]
-    pub real_strike_price: f64,
+    pub real_strike_price: Decimal,
     pub exercise_date: u64,
     #[serde(with = "string_or_float")]
-    pub quantity: f64,
+    pub quantity: Decimal,
     #[serde(with = "string_or_float")]
-    pub fee: f64,
+    pub fee: Decimal,
     pub create_date: u64,
     #[serde(with = "string_or_float")]
-    pub price_scale: f64,
+    pub price_scale: Decimal,
     #[serde(with = "string_or_float")]
-    pub quantity_scale: f64,
+    pub quantity_scale: Decimal,
     pub option_side: OptionSide,
     pub expiry_date: u64,
 }
@@ -304,8 +305,8 @@ pub struct OptionOrderRequest {
     pub side: OrderSide,
     #[serde(rename = "type")]
     pub order_type: OrderType,
-    pub quantity: f64,
-    pub price: Option<f64>,
+    pub quantity: Decimal,
+    pub price: Option<Decimal>,
     pub time_in_force: Option<TimeInForce>,
     pub reduce_only: Option<bool>,
     pub post_only: Option<bool>,
@@ -323,7 +324,7 @@ pub(crate) struct UnderlyingQuery {
 #[serde(rename_all = "camelCase")]
 pub(crate) struct OptionKlineQuery {
     pub symbol: String,
-    pub interval: String,
+    pub interval: KlineInterval,
     pub start_time: Option<u64>,
     pub end_time: Option<u64>,
     pub limit: Option<u16>,
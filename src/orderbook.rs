@@ -0,0 +1,336 @@
+//! Local order book kept in sync with the diff depth stream
+//!
+//! Implements the procedure documented by Binance to manage a local order book:
+//! 1. Open a diff depth stream (see [`crate::websockets::diff_book_depth_stream`]) and feed every
+//!    [`DepthOrderBookEvent`] to [`LocalOrderBook::update`], events are buffered until a snapshot is applied
+//! 2. Fetch a depth snapshot and apply it with [`LocalOrderBook::apply_snapshot`] or one of the `sync` methods
+//! 3. Buffered events older than the snapshot are dropped, the others are applied in order
+//! 4. Whenever an update returns [`BookUpdate::OutOfSync`], fetch a new snapshot
+//!
+//! # Examples
+//! ```rust,no_run
+//! use binance::{api::*, market::*, config::*, orderbook::*, ws_model::DepthOrderBookEvent};
+//! let market: Market = Binance::new_with_env(&Config::default());
+//! let mut book = LocalOrderBook::new("BTCUSDT", BookKind::Spot);
+//! # let events: Vec<DepthOrderBookEvent> = vec![];
+//! for event in events {
+//!     if book.update(event) == BookUpdate::OutOfSync {
+//!         tokio_test::block_on(book.sync(&market, 1000)).unwrap();
+//!     }
+//! }
+//! println!("{:?} {:?}", book.best_bid(), book.best_ask());
+//! ```
+
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+
+use crate::errors::*;
+use crate::market::Market;
+use crate::rest_model::{Asks, Bids, Decimal, OrderBook};
+use crate::ws_model::DepthOrderBookEvent;
+
+/// Market of the order book, update ids are not sequenced the same way for spot and futures
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BookKind {
+    Spot,
+    UsdMFutures,
+    CoinMFutures,
+}
+
+/// Outcome of feeding an event or a snapshot to the book
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BookUpdate {
+    /// The book waits for a snapshot, the event was buffered
+    Buffered,
+    /// The event is older than the book and was dropped
+    Stale,
+    /// The book is up to date
+    Applied,
+    /// A gap in update ids was detected, a new snapshot is required
+    OutOfSync,
+}
+
+/// Price used as a map key
+#[derive(Debug, Clone, Copy)]
+struct PriceKey(Decimal);
+
+impl PartialEq for PriceKey {
+    fn eq(&self, other: &Self) -> bool { self.cmp(other) == Ordering::Equal }
+}
+
+impl Eq for PriceKey {}
+
+impl PartialOrd for PriceKey {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(self.cmp(other)) }
+}
+
+impl Ord for PriceKey {
+    fn cmp(&self, other: &Self) -> Ordering { self.0.partial_cmp(&other.0).unwrap_or(Ordering::Equal) }
+}
+
+#[derive(Debug, Clone)]
+pub struct LocalOrderBook {
+    symbol: String,
+    kind: BookKind,
+    bids: BTreeMap<PriceKey, Decimal>,
+    asks: BTreeMap<PriceKey, Decimal>,
+    /// None until a snapshot is applied
+    last_update_id: Option<u64>,
+    /// Whether the next applied event is the first one after the snapshot
+    first_event: bool,
+    buffer: Vec<DepthOrderBookEvent>,
+}
+
+impl LocalOrderBook {
+    pub fn new<S: Into<String>>(symbol: S, kind: BookKind) -> Self {
+        Self {
+            symbol: symbol.into(),
+            kind,
+            bids: BTreeMap::new(),
+            asks: BTreeMap::new(),
+            last_update_id: None,
+            first_event: true,
+            buffer: vec![],
+        }
+    }
+
+    pub fn symbol(&self) -> &str { &self.symbol }
+
+    pub fn kind(&self) -> BookKind { self.kind }
+
+    /// Update id of the last snapshot or event applied, None while waiting for a snapshot
+    pub fn last_update_id(&self) -> Option<u64> { self.last_update_id }
+
+    pub fn is_synced(&self) -> bool { self.last_update_id.is_some() }
+
+    /// Clear the book and buffer events until the next snapshot
+    pub fn reset(&mut self) {
+        self.bids.clear();
+        self.asks.clear();
+        self.last_update_id = None;
+        self.first_event = true;
+        self.buffer.clear();
+    }
+
+    /// Feed a diff depth event
+    pub fn update(&mut self, event: DepthOrderBookEvent) -> BookUpdate {
+        if self.last_update_id.is_none() {
+            self.buffer.push(event);
+            return BookUpdate::Buffered;
+        }
+        self.apply_event(event)
+    }
+
+    /// Replace the book with a REST snapshot and replay buffered events on top of it
+    /// Returns [`BookUpdate::OutOfSync`] if the snapshot does not connect with the buffered events,
+    /// in which case another snapshot should be fetched.
+    pub fn apply_snapshot<B: Into<OrderBook>>(&mut self, snapshot: B) -> BookUpdate {
+        let snapshot = snapshot.into();
+        self.bids = snapshot
+            .bids
+            .into_iter()
+            .filter(|b| b.qty != Decimal::default())
+            .map(|b| (PriceKey(b.price), b.qty))
+            .collect();
+        self.asks = snapshot
+            .asks
+            .into_iter()
+            .filter(|a| a.qty != Decimal::default())
+            .map(|a| (PriceKey(a.price), a.qty))
+            .collect();
+        self.last_update_id = Some(snapshot.last_update_id);
+        self.first_event = true;
+
+        let mut buffered = std::mem::take(&mut self.buffer).into_iter();
+        while let Some(event) = buffered.next() {
+            if self.apply_event(event) == BookUpdate::OutOfSync {
+                self.buffer.extend(buffered);
+                return BookUpdate::OutOfSync;
+            }
+        }
+        BookUpdate::Applied
+    }
+
+    fn apply_event(&mut self, event: DepthOrderBookEvent) -> BookUpdate {
+        let Some(last) = self.last_update_id else {
+            return BookUpdate::OutOfSync;
+        };
+        let stale = match self.kind {
+            BookKind::Spot => event.final_update_id <= last,
+            BookKind::UsdMFutures | BookKind::CoinMFutures => event.final_update_id < last,
+        };
+        if stale {
+            return BookUpdate::Stale;
+        }
+        let in_sequence = match (self.kind, self.first_event) {
+            (BookKind::Spot, true) => event.first_update_id <= last + 1 && event.final_update_id > last,
+            (BookKind::Spot, false) => event.first_update_id == last + 1,
+            (_, true) => event.first_update_id <= last && event.final_update_id >= last,
+            (_, false) => event
+                .previous_final_update_id
+                .map_or(event.first_update_id == last + 1, |pu| pu == last),
+        };
+        if !in_sequence {
+            // Keep the event, it may still be needed once the next snapshot is applied
+            self.reset();
+            self.buffer.push(event);
+            return BookUpdate::OutOfSync;
+        }
+
+        for bid in event.bids {
+            Self::apply_level(&mut self.bids, bid.price, bid.qty);
+        }
+        for ask in event.asks {
+            Self::apply_level(&mut self.asks, ask.price, ask.qty);
+        }
+        self.last_update_id = Some(event.final_update_id);
+        self.first_event = false;
+        BookUpdate::Applied
+    }
+
+    fn apply_level(levels: &mut BTreeMap<PriceKey, Decimal>, price: Decimal, qty: Decimal) {
+        if qty == Decimal::default() {
+            levels.remove(&PriceKey(price));
+        } else {
+            levels.insert(PriceKey(price), qty);
+        }
+    }
+
+    pub fn best_bid(&self) -> Option<Bids> { self.bids().next() }
+
+    pub fn best_ask(&self) -> Option<Asks> { self.asks().next() }
+
+    /// Bids from the best (highest) price
+    pub fn bids(&self) -> impl Iterator<Item = Bids> + '_ {
+        self.bids.iter().rev().map(|(price, qty)| Bids {
+            price: price.0,
+            qty: *qty,
+        })
+    }
+
+    /// Asks from the best (lowest) price
+    pub fn asks(&self) -> impl Iterator<Item = Asks> + '_ {
+        self.asks.iter().map(|(price, qty)| Asks {
+            price: price.0,
+            qty: *qty,
+        })
+    }
+
+    /// Best `levels` bids and asks
+    pub fn depth(&self, levels: usize) -> (Vec<Bids>, Vec<Asks>) {
+        (self.bids().take(levels).collect(), self.asks().take(levels).collect())
+    }
+
+    /// Copy of the whole book, None while waiting for a snapshot
+    pub fn snapshot(&self) -> Option<OrderBook> {
+        self.last_update_id.map(|last_update_id| OrderBook {
+            last_update_id,
+            bids: self.bids().collect(),
+            asks: self.asks().collect(),
+        })
+    }
+
+    /// Fetch a spot snapshot of `limit` levels and apply it
+    pub async fn sync(&mut self, market: &Market, limit: u16) -> Result<BookUpdate> {
+        let snapshot = market.get_custom_depth(&self.symbol, limit).await?;
+        Ok(self.apply_snapshot(snapshot))
+    }
+
+    /// Fetch a USD-M futures snapshot and apply it
+    #[cfg(feature = "futures_api")]
+    pub async fn sync_futures(&mut self, market: &crate::futures::market::FuturesMarket) -> Result<BookUpdate> {
+        let snapshot = market.get_depth(self.symbol.clone()).await?;
+        Ok(self.apply_snapshot(snapshot))
+    }
+
+    /// Fetch a COIN-M futures snapshot and apply it
+    #[cfg(feature = "coin_futures_api")]
+    pub async fn sync_coin_futures(
+        &mut self,
+        market: &crate::coin_futures::market::CoinFuturesMarket,
+    ) -> Result<BookUpdate> {
+        let snapshot = market.get_depth(self.symbol.clone()).await?;
+        Ok(self.apply_snapshot(snapshot))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn d(value: f64) -> Decimal { value.to_string().parse().unwrap() }
+
+    fn level(price: f64, qty: f64) -> Bids {
+        Bids {
+            price: d(price),
+            qty: d(qty),
+        }
+    }
+
+    fn event(first: u64, last: u64, previous: Option<u64>, bids: Vec<Bids>) -> DepthOrderBookEvent {
+        DepthOrderBookEvent {
+            event_time: 0,
+            symbol: "BTCUSDT".to_string(),
+            first_update_id: first,
+            final_update_id: last,
+            previous_final_update_id: previous,
+            bids,
+            asks: vec![],
+        }
+    }
+
+    fn snapshot(last_update_id: u64) -> OrderBook {
+        OrderBook {
+            last_update_id,
+            bids: vec![level(10.0, 1.0), level(9.0, 1.0)],
+            asks: vec![Asks {
+                price: d(11.0),
+                qty: d(1.0),
+            }],
+        }
+    }
+
+    #[test]
+    fn spot_buffered_events_are_replayed() {
+        let mut book = LocalOrderBook::new("BTCUSDT", BookKind::Spot);
+        assert_eq!(
+            book.update(event(90, 100, None, vec![level(8.0, 1.0)])),
+            BookUpdate::Buffered
+        );
+        assert_eq!(
+            book.update(event(101, 105, None, vec![level(10.0, 0.0)])),
+            BookUpdate::Buffered
+        );
+        assert_eq!(
+            book.update(event(106, 110, None, vec![level(9.5, 2.0)])),
+            BookUpdate::Buffered
+        );
+        assert_eq!(book.apply_snapshot(snapshot(103)), BookUpdate::Applied);
+        assert_eq!(book.last_update_id(), Some(110));
+        let (bids, _) = book.depth(2);
+        assert_eq!(bids.iter().map(|b| b.price).collect::<Vec<_>>(), vec![d(9.5), d(9.0)]);
+        assert_eq!(book.best_ask().map(|a| a.price), Some(d(11.0)));
+    }
+
+    #[test]
+    fn spot_gap_requires_resync() {
+        let mut book = LocalOrderBook::new("BTCUSDT", BookKind::Spot);
+        assert_eq!(book.apply_snapshot(snapshot(100)), BookUpdate::Applied);
+        assert_eq!(book.update(event(101, 102, None, vec![])), BookUpdate::Applied);
+        assert_eq!(book.update(event(104, 105, None, vec![])), BookUpdate::OutOfSync);
+        assert!(!book.is_synced());
+        assert_eq!(book.apply_snapshot(snapshot(104)), BookUpdate::Applied);
+        assert_eq!(book.last_update_id(), Some(105));
+    }
+
+    #[test]
+    fn futures_sequence_uses_previous_update_id() {
+        let mut book = LocalOrderBook::new("BTCUSDT", BookKind::UsdMFutures);
+        book.update(event(95, 100, Some(94), vec![]));
+        book.update(event(101, 108, Some(100), vec![level(10.0, 3.0)]));
+        assert_eq!(book.apply_snapshot(snapshot(105)), BookUpdate::Applied);
+        assert_eq!(book.best_bid().map(|b| b.qty), Some(d(3.0)));
+        assert_eq!(book.update(event(110, 112, Some(108), vec![])), BookUpdate::Applied);
+        assert_eq!(book.update(event(115, 118, Some(113), vec![])), BookUpdate::OutOfSync);
+    }
+}
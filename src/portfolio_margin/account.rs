@@ -4,8 +4,7 @@ use crate::client::Client;
 use crate::errors::*;
 use crate::portfolio_margin::rest_model::{CmOrder, CmOrderRequest, CmPosition, MarginOrder, MarginOrderRequest,
                                           RepayResult, UmOrder, UmOrderRequest, UmPosition};
-use crate::rest_model::PairQuery;
-use crate::util::{build_signed_request, build_signed_request_p};
+use crate::rest_model::{Decimal, PairQuery};
 
 #[derive(Clone)]
 pub struct PortfolioMarginAccount {
@@ -24,7 +23,9 @@ struct OrderIdQuery {
 impl PortfolioMarginAccount {
     /// Place a UM futures order
     pub async fn um_place_order(&self, order: UmOrderRequest) -> Result<UmOrder> {
-        self.client.post_signed_p("/papi/v1/um/order", order, self.recv_window).await
+        self.client
+            .post_signed_p("/papi/v1/um/order", order, self.recv_window)
+            .await
     }
 
     /// Get a UM futures order by symbol and order id
@@ -43,7 +44,9 @@ impl PortfolioMarginAccount {
 
     /// Get all open UM futures orders for a symbol
     pub async fn um_get_open_orders(&self, symbol: impl Into<String>) -> Result<Vec<UmOrder>> {
-        let payload = build_signed_request_p(PairQuery { symbol: symbol.into() }, self.recv_window)?;
+        let payload = self
+            .client
+            .build_signed_request_p(PairQuery { symbol: symbol.into() }, self.recv_window)?;
         self.client.get_signed("/papi/v1/um/openOrders", &payload).await
     }
 
@@ -75,13 +78,17 @@ impl PortfolioMarginAccount {
 
     /// Get UM futures position information for a symbol
     pub async fn um_position_information(&self, symbol: impl Into<String>) -> Result<Vec<UmPosition>> {
-        let payload = build_signed_request_p(PairQuery { symbol: symbol.into() }, self.recv_window)?;
+        let payload = self
+            .client
+            .build_signed_request_p(PairQuery { symbol: symbol.into() }, self.recv_window)?;
         self.client.get_signed("/papi/v1/um/positionRisk", &payload).await
     }
 
     /// Place a CM futures order
     pub async fn cm_place_order(&self, order: CmOrderRequest) -> Result<CmOrder> {
-        self.client.post_signed_p("/papi/v1/cm/order", order, self.recv_window).await
+        self.client
+            .post_signed_p("/papi/v1/cm/order", order, self.recv_window)
+            .await
     }
 
     /// Get a CM futures order by symbol and order id
@@ -100,7 +107,9 @@ impl PortfolioMarginAccount {
 
     /// Get all open CM futures orders for a symbol
     pub async fn cm_get_open_orders(&self, symbol: impl Into<String>) -> Result<Vec<CmOrder>> {
-        let payload = build_signed_request_p(PairQuery { symbol: symbol.into() }, self.recv_window)?;
+        let payload = self
+            .client
+            .build_signed_request_p(PairQuery { symbol: symbol.into() }, self.recv_window)?;
         self.client.get_signed("/papi/v1/cm/openOrders", &payload).await
     }
 
@@ -132,18 +141,24 @@ impl PortfolioMarginAccount {
 
     /// Get CM futures position information for a symbol
     pub async fn cm_position_information(&self, symbol: impl Into<String>) -> Result<Vec<CmPosition>> {
-        let payload = build_signed_request_p(PairQuery { symbol: symbol.into() }, self.recv_window)?;
+        let payload = self
+            .client
+            .build_signed_request_p(PairQuery { symbol: symbol.into() }, self.recv_window)?;
         self.client.get_signed("/papi/v1/cm/positionRisk", &payload).await
     }
 
     /// Place a margin order
     pub async fn margin_place_order(&self, order: MarginOrderRequest) -> Result<MarginOrder> {
-        self.client.post_signed_p("/papi/v1/margin/order", order, self.recv_window).await
+        self.client
+            .post_signed_p("/papi/v1/margin/order", order, self.recv_window)
+            .await
     }
 
     /// Get all open margin orders for a symbol
     pub async fn margin_get_open_orders(&self, symbol: impl Into<String>) -> Result<Vec<MarginOrder>> {
-        let payload = build_signed_request_p(PairQuery { symbol: symbol.into() }, self.recv_window)?;
+        let payload = self
+            .client
+            .build_signed_request_p(PairQuery { symbol: symbol.into() }, self.recv_window)?;
         self.client.get_signed("/papi/v1/margin/openOrders", &payload).await
     }
 
@@ -162,20 +177,20 @@ impl PortfolioMarginAccount {
     }
 
     /// Borrow margin funds
-    pub async fn margin_borrow(&self, asset: impl Into<String>, amount: f64) -> Result<RepayResult> {
+    pub async fn margin_borrow(&self, asset: impl Into<String>, amount: Decimal) -> Result<RepayResult> {
         let mut params = BTreeMap::<String, String>::new();
         params.insert("asset".into(), asset.into());
         params.insert("amount".into(), amount.to_string());
-        let request = build_signed_request(params, self.recv_window)?;
+        let request = self.client.build_signed_request(params, self.recv_window)?;
         self.client.post_signed_d("/papi/v1/marginLoan", &request).await
     }
 
     /// Repay margin loan
-    pub async fn margin_repay(&self, asset: impl Into<String>, amount: f64) -> Result<RepayResult> {
+    pub async fn margin_repay(&self, asset: impl Into<String>, amount: Decimal) -> Result<RepayResult> {
         let mut params = BTreeMap::<String, String>::new();
         params.insert("asset".into(), asset.into());
         params.insert("amount".into(), amount.to_string());
-        let request = build_signed_request(params, self.recv_window)?;
+        let request = self.client.build_signed_request(params, self.recv_window)?;
         self.client.post_signed_d("/papi/v1/repayLoan", &request).await
     }
 }
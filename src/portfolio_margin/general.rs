@@ -18,13 +18,17 @@ impl PortfolioMarginGeneral {
 
     /// Get unified account information
     pub async fn account_information(&self) -> Result<AccountInformation> {
-        let request = crate::util::build_signed_request(BTreeMap::<String, String>::new(), self.recv_window)?;
+        let request = self
+            .client
+            .build_signed_request(BTreeMap::<String, String>::new(), self.recv_window)?;
         self.client.get_signed_d("/papi/v1/account", &request).await
     }
 
     /// Get account balance per asset
     pub async fn account_balance(&self) -> Result<Vec<AccountBalance>> {
-        let request = crate::util::build_signed_request(BTreeMap::<String, String>::new(), self.recv_window)?;
+        let request = self
+            .client
+            .build_signed_request(BTreeMap::<String, String>::new(), self.recv_window)?;
         self.client.get_signed_d("/papi/v1/balance", &request).await
     }
 
@@ -32,7 +36,7 @@ impl PortfolioMarginGeneral {
     pub async fn max_borrowable(&self, asset: impl Into<String>) -> Result<MaxBorrowable> {
         let mut params = BTreeMap::<String, String>::new();
         params.insert("asset".into(), asset.into());
-        let request = crate::util::build_signed_request(params, self.recv_window)?;
+        let request = self.client.build_signed_request(params, self.recv_window)?;
         self.client.get_signed_d("/papi/v1/margin/maxBorrowable", &request).await
     }
 }
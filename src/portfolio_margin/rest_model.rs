@@ -1,4 +1,4 @@
-pub use crate::rest_model::{string_or_float, string_or_u64, OrderSide, OrderStatus, TimeInForce};
+pub use crate::rest_model::{string_or_float, string_or_u64, Decimal, OrderSide, OrderStatus, TimeInForce};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -17,28 +17,28 @@ pub struct AccountInformation {
 pub struct AccountBalance {
     pub asset: String,
     #[serde(with = "string_or_float")]
-    pub total_wallet_balance: f64,
+    pub total_wallet_balance: Decimal,
     #[serde(with = "string_or_float")]
-    pub cross_margin_asset: f64,
+    pub cross_margin_asset: Decimal,
     #[serde(with = "string_or_float")]
-    pub cross_margin_borrowed: f64,
+    pub cross_margin_borrowed: Decimal,
     #[serde(with = "string_or_float")]
-    pub cross_margin_free: f64,
+    pub cross_margin_free: Decimal,
     #[serde(with = "string_or_float")]
-    pub cross_margin_interest: f64,
+    pub cross_margin_interest: Decimal,
     #[serde(with = "string_or_float")]
-    pub cross_margin_locked: f64,
+    pub cross_margin_locked: Decimal,
     #[serde(with = "string_or_float")]
-    pub um_wallet_balance: f64,
+    pub um_wallet_balance: Decimal,
     #[serde(with = "string_or_float", rename = "umUnrealizedPNL")]
-    pub um_unrealized_pnl: f64,
+    pub um_unrealized_pnl: Decimal,
     #[serde(with = "string_or_float")]
-    pub cm_wallet_balance: f64,
+    pub cm_wallet_balance: Decimal,
     #[serde(with = "string_or_float", rename = "cmUnrealizedPNL")]
-    pub cm_unrealized_pnl: f64,
+    pub cm_unrealized_pnl: Decimal,
     pub update_time: u64,
     #[serde(with = "string_or_float")]
-    pub negative_balance: f64,
+    pub negative_balance: Decimal,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -46,25 +46,25 @@ pub struct AccountBalance {
 pub struct UmPosition {
     pub symbol: String,
     #[serde(with = "string_or_float")]
-    pub entry_price: f64,
+    pub entry_price: Decimal,
     #[serde(with = "string_or_float")]
-    pub mark_price: f64,
+    pub mark_price: Decimal,
     #[serde(with = "string_or_float", rename = "positionAmt")]
-    pub position_amount: f64,
+    pub position_amount: Decimal,
     #[serde(with = "string_or_float", rename = "unRealizedProfit")]
-    pub unrealized_profit: f64,
+    pub unrealized_profit: Decimal,
     #[serde(with = "string_or_float")]
-    pub liquidation_price: f64,
+    pub liquidation_price: Decimal,
     #[serde(with = "string_or_u64")]
     pub leverage: u64,
     pub position_side: String,
     pub update_time: u64,
     #[serde(with = "string_or_float")]
-    pub notional: f64,
+    pub notional: Decimal,
     #[serde(with = "string_or_float")]
-    pub isolated_wallet: f64,
+    pub isolated_wallet: Decimal,
     #[serde(with = "string_or_float")]
-    pub isolated_margin: f64,
+    pub isolated_margin: Decimal,
     pub margin_type: String,
 }
 
@@ -73,28 +73,28 @@ pub struct UmPosition {
 pub struct CmPosition {
     pub symbol: String,
     #[serde(with = "string_or_float")]
-    pub entry_price: f64,
+    pub entry_price: Decimal,
     #[serde(with = "string_or_float")]
-    pub mark_price: f64,
+    pub mark_price: Decimal,
     #[serde(with = "string_or_float", rename = "positionAmt")]
-    pub position_amount: f64,
+    pub position_amount: Decimal,
     #[serde(with = "string_or_float", rename = "unRealizedProfit")]
-    pub unrealized_profit: f64,
+    pub unrealized_profit: Decimal,
     #[serde(with = "string_or_float")]
-    pub liquidation_price: f64,
+    pub liquidation_price: Decimal,
     #[serde(with = "string_or_u64")]
     pub leverage: u64,
     pub position_side: String,
     pub update_time: u64,
     #[serde(with = "string_or_float")]
-    pub notional: f64,
+    pub notional: Decimal,
     #[serde(with = "string_or_float")]
-    pub isolated_wallet: f64,
+    pub isolated_wallet: Decimal,
     #[serde(with = "string_or_float")]
-    pub isolated_margin: f64,
+    pub isolated_margin: Decimal,
     pub margin_type: String,
     #[serde(with = "string_or_float")]
-    pub max_qty: f64,
+    pub max_qty: Decimal,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, Default)]
@@ -115,23 +115,23 @@ pub enum OrderType {
 pub struct UmOrder {
     pub client_order_id: String,
     #[serde(with = "string_or_float")]
-    pub cum_qty: f64,
+    pub cum_qty: Decimal,
     #[serde(with = "string_or_float")]
-    pub cum_quote: f64,
+    pub cum_quote: Decimal,
     #[serde(with = "string_or_float")]
-    pub executed_qty: f64,
+    pub executed_qty: Decimal,
     pub order_id: u64,
     #[serde(with = "string_or_float")]
-    pub avg_price: f64,
+    pub avg_price: Decimal,
     #[serde(with = "string_or_float")]
-    pub orig_qty: f64,
+    pub orig_qty: Decimal,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Decimal,
     pub side: OrderSide,
     pub position_side: String,
     pub status: OrderStatus,
     #[serde(with = "string_or_float", default = "default_stop_price")]
-    pub stop_price: f64,
+    pub stop_price: Decimal,
     pub symbol: String,
     pub time_in_force: TimeInForce,
     #[serde(rename = "type")]
@@ -145,23 +145,23 @@ pub struct UmOrder {
 pub struct CmOrder {
     pub client_order_id: String,
     #[serde(with = "string_or_float")]
-    pub cum_qty: f64,
+    pub cum_qty: Decimal,
     #[serde(with = "string_or_float")]
-    pub cum_base: f64,
+    pub cum_base: Decimal,
     #[serde(with = "string_or_float")]
-    pub executed_qty: f64,
+    pub executed_qty: Decimal,
     pub order_id: u64,
     #[serde(with = "string_or_float")]
-    pub avg_price: f64,
+    pub avg_price: Decimal,
     #[serde(with = "string_or_float")]
-    pub orig_qty: f64,
+    pub orig_qty: Decimal,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Decimal,
     pub side: OrderSide,
     pub position_side: String,
     pub status: OrderStatus,
     #[serde(with = "string_or_float", default = "default_stop_price")]
-    pub stop_price: f64,
+    pub stop_price: Decimal,
     pub symbol: String,
     pub pair: String,
     pub time_in_force: TimeInForce,
@@ -178,13 +178,13 @@ pub struct MarginOrder {
     pub order_id: u64,
     pub client_order_id: String,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Decimal,
     #[serde(with = "string_or_float")]
-    pub orig_qty: f64,
+    pub orig_qty: Decimal,
     #[serde(with = "string_or_float")]
-    pub executed_qty: f64,
+    pub executed_qty: Decimal,
     #[serde(with = "string_or_float")]
-    pub cummulative_quote_qty: f64,
+    pub cummulative_quote_qty: Decimal,
     pub status: String,
     pub time_in_force: String,
     pub side: String,
@@ -196,9 +196,9 @@ pub struct MarginOrder {
 #[serde(rename_all = "camelCase")]
 pub struct MaxBorrowable {
     #[serde(with = "string_or_float")]
-    pub amount: f64,
+    pub amount: Decimal,
     #[serde(with = "string_or_float")]
-    pub borrowable_limit: f64,
+    pub borrowable_limit: Decimal,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -216,11 +216,11 @@ pub struct UmOrderRequest {
     #[serde(rename = "type")]
     pub order_type: OrderType,
     pub time_in_force: Option<TimeInForce>,
-    pub quantity: Option<f64>,
+    pub quantity: Option<Decimal>,
     pub reduce_only: Option<bool>,
-    pub price: Option<f64>,
+    pub price: Option<Decimal>,
     pub new_client_order_id: Option<String>,
-    pub stop_price: Option<f64>,
+    pub stop_price: Option<Decimal>,
     pub working_type: Option<String>,
 }
 
@@ -233,11 +233,11 @@ pub struct CmOrderRequest {
     #[serde(rename = "type")]
     pub order_type: OrderType,
     pub time_in_force: Option<TimeInForce>,
-    pub quantity: Option<f64>,
+    pub quantity: Option<Decimal>,
     pub reduce_only: Option<bool>,
-    pub price: Option<f64>,
+    pub price: Option<Decimal>,
     pub new_client_order_id: Option<String>,
-    pub stop_price: Option<f64>,
+    pub stop_price: Option<Decimal>,
     pub working_type: Option<String>,
 }
 
@@ -248,13 +248,13 @@ pub struct MarginOrderRequest {
     pub side: String,
     #[serde(rename = "type")]
     pub order_type: String,
-    pub quantity: Option<f64>,
-    pub quote_order_qty: Option<f64>,
-    pub price: Option<f64>,
-    pub stop_price: Option<f64>,
+    pub quantity: Option<Decimal>,
+    pub quote_order_qty: Option<Decimal>,
+    pub price: Option<Decimal>,
+    pub stop_price: Option<Decimal>,
     pub new_client_order_id: Option<String>,
     pub time_in_force: Option<String>,
     pub side_effect_type: Option<String>,
 }
 
-fn default_stop_price() -> f64 { 0.0 }
+fn default_stop_price() -> Decimal { Decimal::default() }
@@ -0,0 +1,419 @@
+//! Client side rate limiting
+//!
+//! Binance bans IPs that keep exceeding their request weight or order count, this keeps track of both
+//! and delays (or rejects) requests that would go over the budget. Limits are taken from the `rateLimits`
+//! of the exchange information, loaded automatically by `exchange_info` or with [`RateLimiter::set_limits`],
+//! and usage is synchronized with the `X-MBX-USED-WEIGHT-*` and `X-MBX-ORDER-COUNT-*` response headers.
+//!
+//! Limits are tracked per host, and a limiter is shared by all clients created from the same configuration.
+//!
+//! Independently, a [`RetryPolicy`] retries GET requests the server rejected for exceeding a limit.
+//!
+//! # Examples
+//! ```rust,no_run
+//! use binance::{api::*, config::*, general::*, market::*, rate_limit::*};
+//! # tokio_test::block_on(async {
+//! let conf = Config::default().set_rate_limiter(RateLimiter::new(OnLimit::Delay));
+//! let general: General = Binance::new_with_config(None, None, &conf);
+//! // Loads the limits
+//! general.exchange_info().await.unwrap();
+//! let market: Market = Binance::new_with_config(None, None, &conf);
+//! let prices = market.get_all_prices().await.unwrap();
+//! # });
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use chrono::Utc;
+use reqwest::header::HeaderMap;
+use reqwest::Method;
+
+use crate::errors::*;
+use crate::rest_model::{RateLimit, RateLimitInterval, RateLimitType};
+use crate::runtime;
+
+static USED_WEIGHT_HEADER: &str = "x-mbx-used-weight-";
+static ORDER_COUNT_HEADER: &str = "x-mbx-order-count-";
+
+/// Weight of requests that do not weigh 1, for the most common parameters
+static DEFAULT_WEIGHTS: &[(Method, &str, u32)] = &[
+    (Method::GET, "/api/v3/exchangeInfo", 20),
+    (Method::GET, "/api/v3/depth", 5),
+    (Method::GET, "/api/v3/aggTrades", 2),
+    (Method::GET, "/api/v3/klines", 2),
+    (Method::GET, "/api/v3/avgPrice", 2),
+    (Method::GET, "/api/v3/ticker/24hr", 2),
+    (Method::GET, "/api/v3/ticker/price", 2),
+    (Method::GET, "/api/v3/ticker/bookTicker", 2),
+    (Method::GET, "/api/v3/account", 20),
+    (Method::GET, "/api/v3/openOrders", 6),
+    (Method::GET, "/api/v3/allOrders", 20),
+    (Method::GET, "/api/v3/myTrades", 20),
+    (Method::GET, "/api/v3/order", 4),
+    (Method::GET, "/fapi/v1/depth", 5),
+    (Method::GET, "/fapi/v1/klines", 5),
+    (Method::GET, "/fapi/v1/ticker/24hr", 40),
+    (Method::GET, "/fapi/v1/openOrders", 40),
+    (Method::GET, "/fapi/v1/allOrders", 5),
+    (Method::GET, "/fapi/v1/userTrades", 5),
+    (Method::GET, "/fapi/v2/account", 5),
+    (Method::GET, "/fapi/v2/balance", 5),
+    (Method::GET, "/fapi/v2/positionRisk", 5),
+    (Method::GET, "/dapi/v1/depth", 5),
+    (Method::GET, "/dapi/v1/klines", 5),
+    (Method::GET, "/dapi/v1/ticker/24hr", 40),
+    (Method::GET, "/dapi/v1/openOrders", 40),
+    (Method::GET, "/dapi/v1/allOrders", 20),
+    (Method::GET, "/dapi/v1/userTrades", 20),
+    (Method::GET, "/dapi/v1/account", 5),
+    (Method::GET, "/dapi/v1/balance", 1),
+];
+
+/// What to do with a request that would exceed a limit
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OnLimit {
+    /// Wait until the limit's interval is over, requests weighing more than the limit fail with
+    /// [`Error::RateLimitBudgetExceeded`] as they could never be sent
+    Delay,
+    /// Fail with [`Error::RateLimitBudgetExceeded`]
+    Reject,
+}
+
+/// Retries of GET requests rejected with [`Error::RateLimited`] or [`Error::IpBanned`],
+/// see [`Config::set_retry_policy`](crate::config::Config::set_retry_policy)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RetryPolicy {
+    pub max_retries: u32,
+    /// Requests are not retried if the server asks to wait longer
+    pub max_delay: Duration,
+    /// Delay when the server does not give one
+    pub default_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_retries: 3,
+            max_delay: Duration::from_secs(60),
+            default_delay: Duration::from_secs(1),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Delay before retrying a request that failed with `error` after `retries` retries, if it should be
+    pub fn delay(&self, error: &Error, retries: u32) -> Option<Duration> {
+        if retries >= self.max_retries {
+            return None;
+        }
+        let delay = match error {
+            Error::RateLimited { retry_after, .. } => retry_after.unwrap_or(self.default_delay),
+            Error::IpBanned { until: Some(until), .. } => Duration::from_millis(until.saturating_sub(now_millis())),
+            _ => return None,
+        };
+        (delay <= self.max_delay).then_some(delay)
+    }
+}
+
+/// Usage of a limit in its current interval
+#[derive(Clone, Debug)]
+pub struct LimitUsage {
+    pub limit_type: RateLimitType,
+    pub interval: Duration,
+    /// Request weight or order count
+    pub used: u32,
+    pub limit: u32,
+}
+
+/// A limit and its usage in the current interval
+#[derive(Clone, Debug)]
+struct Budget {
+    limit_type: RateLimitType,
+    interval_ms: u64,
+    limit: u32,
+    /// Index of the interval `used` was counted in
+    window: u64,
+    used: u32,
+}
+
+impl Budget {
+    fn used(&self, window: u64) -> u32 {
+        if window == self.window {
+            self.used
+        } else {
+            0
+        }
+    }
+
+    fn add(&mut self, window: u64, count: u32) {
+        self.used = self.used(window) + count;
+        self.window = window;
+    }
+}
+
+struct State {
+    on_limit: OnLimit,
+    hosts: HashMap<String, Vec<Budget>>,
+    weights: HashMap<(Method, String), u32>,
+}
+
+impl State {
+    fn weight(&self, method: Method, endpoint: &str) -> u32 {
+        self.weights.get(&(method, endpoint.to_string())).copied().unwrap_or(1)
+    }
+}
+
+/// Request weight and order count tracker, cheap to clone and shared by clones
+#[derive(Clone)]
+pub struct RateLimiter {
+    state: Arc<Mutex<State>>,
+}
+
+impl RateLimiter {
+    pub fn new(on_limit: OnLimit) -> Self {
+        RateLimiter {
+            state: Arc::new(Mutex::new(State {
+                on_limit,
+                hosts: HashMap::new(),
+                weights: DEFAULT_WEIGHTS
+                    .iter()
+                    .map(|(m, e, w)| ((m.clone(), e.to_string()), *w))
+                    .collect(),
+            })),
+        }
+    }
+
+    /// Set the limits of `host`, usually the `rate_limits` of its exchange information
+    pub fn set_limits(&self, host: &str, limits: &[RateLimit]) {
+        let budgets = limits
+            .iter()
+            .filter(|l| matches!(l.rate_limit_type, RateLimitType::RequestWeight | RateLimitType::Orders))
+            .map(|l| Budget {
+                limit_type: l.rate_limit_type.clone(),
+                interval_ms: interval_millis(&l.interval) * l.interval_num.max(1) as u64,
+                limit: l.limit.max(0) as u32,
+                window: 0,
+                used: 0,
+            })
+            .collect();
+        self.state.lock().unwrap().hosts.insert(host.to_string(), budgets);
+    }
+
+    /// Override the weight of `method` requests to `endpoint`, requests weigh 1 unless set otherwise
+    pub fn set_weight(&self, method: Method, endpoint: &str, weight: u32) {
+        self.state
+            .lock()
+            .unwrap()
+            .weights
+            .insert((method, endpoint.to_string()), weight);
+    }
+
+    /// Weight counted for `method` requests to `endpoint`
+    pub fn weight(&self, method: Method, endpoint: &str) -> u32 { self.state.lock().unwrap().weight(method, endpoint) }
+
+    /// Usage of the limits of `host` in their current interval
+    pub fn usage(&self, host: &str) -> Vec<LimitUsage> {
+        let now = now_millis();
+        self.state
+            .lock()
+            .unwrap()
+            .hosts
+            .get(host)
+            .map(|budgets| {
+                budgets
+                    .iter()
+                    .map(|b| LimitUsage {
+                        limit_type: b.limit_type.clone(),
+                        interval: Duration::from_millis(b.interval_ms),
+                        used: b.used(now / b.interval_ms),
+                        limit: b.limit,
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Wait for, or fail if `on_limit` is `Reject`, enough budget to send a `method` request to `endpoint`
+    pub(crate) async fn acquire(&self, host: &str, method: Method, endpoint: &str, is_order: bool) -> Result<()> {
+        loop {
+            let (wait, on_limit, fits) = {
+                let mut state = self.state.lock().unwrap();
+                let weight = state.weight(method.clone(), endpoint);
+                let fits = fits(&state, host, weight, is_order);
+                (reserve(&mut state, host, weight, is_order), state.on_limit, fits)
+            };
+            match (wait, on_limit) {
+                (None, _) => return Ok(()),
+                (Some(retry_after), OnLimit::Delay) if fits => runtime::sleep(retry_after).await,
+                (Some(retry_after), _) => return Err(Error::RateLimitBudgetExceeded { retry_after }),
+            }
+        }
+    }
+
+    /// Synchronize usage with the counts reported by the server
+    pub(crate) fn update(&self, host: &str, headers: &HeaderMap) {
+        let now = now_millis();
+        let mut state = self.state.lock().unwrap();
+        let Some(budgets) = state.hosts.get_mut(host) else {
+            return;
+        };
+        for (name, value) in headers {
+            let name = name.as_str();
+            let (limit_type, interval) = if let Some(interval) = name.strip_prefix(USED_WEIGHT_HEADER) {
+                (RateLimitType::RequestWeight, interval)
+            } else if let Some(interval) = name.strip_prefix(ORDER_COUNT_HEADER) {
+                (RateLimitType::Orders, interval)
+            } else {
+                continue;
+            };
+            let (Some(interval_ms), Some(count)) = (
+                parse_interval(interval),
+                value.to_str().ok().and_then(|v| v.parse::<u32>().ok()),
+            ) else {
+                continue;
+            };
+            for budget in budgets
+                .iter_mut()
+                .filter(|b| b.limit_type == limit_type && b.interval_ms == interval_ms)
+            {
+                let window = now / interval_ms;
+                // Requests in flight are already counted locally but not yet by the server
+                budget.used = budget.used(window).max(count);
+                budget.window = window;
+            }
+        }
+    }
+}
+
+/// Count of a request in `budget`
+fn counted(budget: &Budget, weight: u32, is_order: bool) -> u32 {
+    match budget.limit_type {
+        RateLimitType::RequestWeight => weight,
+        RateLimitType::Orders if is_order => 1,
+        _ => 0,
+    }
+}
+
+/// Whether the request fits in every limit once their intervals are over
+fn fits(state: &State, host: &str, weight: u32, is_order: bool) -> bool {
+    state
+        .hosts
+        .get(host)
+        .is_none_or(|budgets| budgets.iter().all(|b| counted(b, weight, is_order) <= b.limit))
+}
+
+/// Count the request if every limit allows it, otherwise return how long until they do
+fn reserve(state: &mut State, host: &str, weight: u32, is_order: bool) -> Option<Duration> {
+    let budgets = state.hosts.get_mut(host)?;
+    let now = now_millis();
+    let counted = |b: &Budget| counted(b, weight, is_order);
+    let wait = budgets
+        .iter()
+        .filter(|b| counted(b) > 0 && b.used(now / b.interval_ms) + counted(b) > b.limit)
+        .map(|b| b.interval_ms - now % b.interval_ms)
+        .max();
+    if let Some(wait) = wait {
+        return Some(Duration::from_millis(wait));
+    }
+    for budget in budgets.iter_mut() {
+        let count = counted(budget);
+        budget.add(now / budget.interval_ms, count);
+    }
+    None
+}
+
+/// Whether a request to `endpoint` places orders, test orders are not counted
+pub(crate) fn is_order_endpoint(endpoint: &str) -> bool {
+    !endpoint.ends_with("/test") && (endpoint.contains("/order") || endpoint.ends_with("/batchOrders"))
+}
+
+fn interval_millis(interval: &RateLimitInterval) -> u64 {
+    match interval {
+        RateLimitInterval::Second => 1000,
+        RateLimitInterval::Minute => 60_000,
+        RateLimitInterval::Day => 86_400_000,
+    }
+}
+
+/// Interval of a header suffix such as `1m`, `10s` or `1d`
+fn parse_interval(interval: &str) -> Option<u64> {
+    let (num, unit) = interval.split_at(interval.len().checked_sub(1)?);
+    let unit_ms = match unit {
+        "s" => 1000,
+        "m" => 60_000,
+        "h" => 3_600_000,
+        "d" => 86_400_000,
+        _ => return None,
+    };
+    Some(num.parse::<u64>().ok()? * unit_ms)
+}
+
+fn now_millis() -> u64 { Utc::now().timestamp_millis() as u64 }
+
+impl fmt::Debug for RateLimiter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.write_str("RateLimiter") }
+}
+
+impl PartialEq for RateLimiter {
+    fn eq(&self, other: &Self) -> bool { Arc::ptr_eq(&self.state, &other.state) }
+}
+
+impl Eq for RateLimiter {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn rejects_requests_over_budget() {
+        let limiter = RateLimiter::new(OnLimit::Reject);
+        limiter.set_limits("host", &[RateLimit {
+            interval: RateLimitInterval::Day,
+            rate_limit_type: RateLimitType::RequestWeight,
+            interval_num: 1,
+            limit: 30,
+        }]);
+        let mut headers = HeaderMap::new();
+        headers.insert("x-mbx-used-weight-1d", "8".parse().unwrap());
+        limiter.update("host", &headers);
+        let acquire = |endpoint| tokio_test::block_on(limiter.acquire("host", Method::GET, endpoint, false));
+        assert!(acquire("/api/v3/account").is_ok());
+        assert!(matches!(
+            acquire("/api/v3/account"),
+            Err(Error::RateLimitBudgetExceeded { .. })
+        ));
+        assert!(acquire("/api/v3/ping").is_ok());
+        assert_eq!(limiter.usage("host")[0].used, 29);
+    }
+
+    #[test]
+    fn weights_by_method() {
+        let limiter = RateLimiter::new(OnLimit::Reject);
+        assert_eq!(limiter.weight(Method::GET, "/api/v3/order"), 4);
+        assert_eq!(limiter.weight(Method::POST, "/api/v3/order"), 1);
+        assert_eq!(limiter.weight(Method::DELETE, "/api/v3/order"), 1);
+        limiter.set_weight(Method::DELETE, "/api/v3/order", 2);
+        assert_eq!(limiter.weight(Method::DELETE, "/api/v3/order"), 2);
+    }
+
+    #[test]
+    fn rejects_requests_over_limit_when_delaying() {
+        let limiter = RateLimiter::new(OnLimit::Delay);
+        limiter.set_limits("host", &[RateLimit {
+            interval: RateLimitInterval::Day,
+            rate_limit_type: RateLimitType::RequestWeight,
+            interval_num: 1,
+            limit: 10,
+        }]);
+        let acquire = |endpoint| tokio_test::block_on(limiter.acquire("host", Method::GET, endpoint, false));
+        assert!(matches!(
+            acquire("/api/v3/account"),
+            Err(Error::RateLimitBudgetExceeded { .. })
+        ));
+        assert!(acquire("/api/v3/order").is_ok());
+        assert_eq!(limiter.usage("host")[0].used, 4);
+    }
+}
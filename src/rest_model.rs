@@ -1,7 +1,14 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Datelike, Utc};
 use serde_repr::{Deserialize_repr, Serialize_repr};
 use std::collections::HashMap;
 
+/// Prices, quantities and other decimal values sent as strings by the exchange,
+/// exact [`rust_decimal::Decimal`]s with the `decimal` feature
+#[cfg(not(feature = "decimal"))]
+pub type Decimal = f64;
+#[cfg(feature = "decimal")]
+pub use rust_decimal::Decimal;
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct ServerTime {
@@ -63,49 +70,60 @@ pub enum Filters {
     #[serde(rename_all = "camelCase")]
     PriceFilter {
         #[serde(with = "string_or_float")]
-        min_price: f64,
+        min_price: Decimal,
         #[serde(with = "string_or_float")]
-        max_price: f64,
+        max_price: Decimal,
         #[serde(with = "string_or_float")]
-        tick_size: f64,
+        tick_size: Decimal,
     },
     #[serde(rename = "PERCENT_PRICE")]
     #[serde(rename_all = "camelCase")]
     PercentPrice {
         #[serde(with = "string_or_float")]
-        multiplier_up: f64,
+        multiplier_up: Decimal,
         #[serde(with = "string_or_float")]
-        multiplier_down: f64,
+        multiplier_down: Decimal,
         avg_price_mins: u64,
     },
     #[serde(rename = "LOT_SIZE")]
     #[serde(rename_all = "camelCase")]
     LotSize {
         #[serde(with = "string_or_float")]
-        min_qty: f64,
+        min_qty: Decimal,
         #[serde(with = "string_or_float")]
-        max_qty: f64,
+        max_qty: Decimal,
         #[serde(with = "string_or_float")]
-        step_size: f64,
+        step_size: Decimal,
     },
     #[serde(rename = "MARKET_LOT_SIZE")]
     #[serde(rename_all = "camelCase")]
     MarketLotSize {
         #[serde(with = "string_or_float")]
-        min_qty: f64,
+        min_qty: Decimal,
         #[serde(with = "string_or_float")]
-        max_qty: f64,
+        max_qty: Decimal,
         #[serde(with = "string_or_float")]
-        step_size: f64,
+        step_size: Decimal,
     },
     #[serde(rename = "MIN_NOTIONAL")]
     #[serde(rename_all = "camelCase")]
     MinNotional {
         #[serde(with = "string_or_float")]
-        min_notional: f64,
+        min_notional: Decimal,
         apply_to_market: bool,
         avg_price_mins: u64,
     },
+    #[serde(rename = "NOTIONAL")]
+    #[serde(rename_all = "camelCase")]
+    Notional {
+        #[serde(with = "string_or_float")]
+        min_notional: Decimal,
+        apply_min_to_market: bool,
+        #[serde(with = "string_or_float")]
+        max_notional: Decimal,
+        apply_max_to_market: bool,
+        avg_price_mins: u64,
+    },
     #[serde(rename = "ICEBERG_PARTS")]
     #[serde(rename_all = "camelCase")]
     IcebergParts { limit: u16 },
@@ -122,7 +140,7 @@ pub enum Filters {
     #[serde(rename_all = "camelCase")]
     MaxPosition {
         #[serde(with = "string_or_float")]
-        max_position: f64,
+        max_position: Decimal,
     },
     #[serde(rename = "EXCHANGE_MAX_NUM_ORDERS")]
     #[serde(rename_all = "camelCase")]
@@ -175,9 +193,9 @@ pub enum AccountType {
 pub struct Balance {
     pub asset: String,
     #[serde(with = "string_or_float")]
-    pub free: f64,
+    pub free: Decimal,
     #[serde(with = "string_or_float")]
-    pub locked: f64,
+    pub locked: Decimal,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -188,27 +206,27 @@ pub struct Order {
     pub order_list_id: i32,
     pub client_order_id: String,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Decimal,
     #[serde(with = "string_or_float")]
-    pub orig_qty: f64,
+    pub orig_qty: Decimal,
     #[serde(with = "string_or_float")]
-    pub executed_qty: f64,
+    pub executed_qty: Decimal,
     #[serde(with = "string_or_float")]
-    pub cummulative_quote_qty: f64,
+    pub cummulative_quote_qty: Decimal,
     pub status: OrderStatus,
     pub time_in_force: TimeInForce,
     #[serde(rename = "type")]
     pub order_type: OrderType,
     pub side: OrderSide,
     #[serde(with = "string_or_float")]
-    pub stop_price: f64,
+    pub stop_price: Decimal,
     #[serde(with = "string_or_float")]
-    pub iceberg_qty: f64,
+    pub iceberg_qty: Decimal,
     pub time: u64,
     pub update_time: u64,
     pub is_working: bool,
     #[serde(with = "string_or_float")]
-    pub orig_quote_order_qty: f64,
+    pub orig_quote_order_qty: Decimal,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -233,11 +251,11 @@ pub struct OrderCanceledReplaced {
 #[serde(rename_all = "camelCase")]
 pub struct Fill {
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Decimal,
     #[serde(with = "string_or_float")]
-    pub qty: f64,
+    pub qty: Decimal,
     #[serde(with = "string_or_float")]
-    pub commission: f64,
+    pub commission: Decimal,
     pub commission_asset: String,
 }
 
@@ -249,13 +267,13 @@ pub struct Transaction {
     pub client_order_id: String,
     pub transact_time: u64,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Decimal,
     #[serde(with = "string_or_float")]
-    pub orig_qty: f64,
+    pub orig_qty: Decimal,
     #[serde(with = "string_or_float")]
-    pub executed_qty: f64,
+    pub executed_qty: Decimal,
     #[serde(with = "string_or_float")]
-    pub cummulative_quote_qty: f64,
+    pub cummulative_quote_qty: Decimal,
     pub status: OrderStatus,
     pub time_in_force: TimeInForce,
     #[serde(rename = "type")]
@@ -289,17 +307,17 @@ pub struct OrderBook {
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Bids {
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Decimal,
     #[serde(with = "string_or_float")]
-    pub qty: f64,
+    pub qty: Decimal,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Asks {
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Decimal,
     #[serde(with = "string_or_float")]
-    pub qty: f64,
+    pub qty: Decimal,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -322,14 +340,14 @@ pub enum Prices {
 pub struct SymbolPrice {
     pub symbol: String,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Decimal,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct AveragePrice {
     pub mins: u64,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Decimal,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -349,13 +367,13 @@ pub enum KlineSummaries {
 pub struct Tickers {
     pub symbol: String,
     #[serde(with = "string_or_float")]
-    pub bid_price: f64,
+    pub bid_price: Decimal,
     #[serde(with = "string_or_float")]
-    pub bid_qty: f64,
+    pub bid_qty: Decimal,
     #[serde(with = "string_or_float")]
-    pub ask_price: f64,
+    pub ask_price: Decimal,
     #[serde(with = "string_or_float")]
-    pub ask_qty: f64,
+    pub ask_qty: Decimal,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -363,9 +381,9 @@ pub struct Tickers {
 pub struct TradeHistory {
     pub id: u64,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Decimal,
     #[serde(with = "string_or_float")]
-    pub qty: f64,
+    pub qty: Decimal,
     pub commission: String,
     pub commission_asset: String,
     pub time: u64,
@@ -381,21 +399,21 @@ pub struct PriceStats {
     pub price_change_percent: String,
     pub weighted_avg_price: String,
     #[serde(with = "string_or_float")]
-    pub prev_close_price: f64,
+    pub prev_close_price: Decimal,
     #[serde(with = "string_or_float")]
-    pub last_price: f64,
+    pub last_price: Decimal,
     #[serde(with = "string_or_float")]
-    pub bid_price: f64,
+    pub bid_price: Decimal,
     #[serde(with = "string_or_float")]
-    pub ask_price: f64,
+    pub ask_price: Decimal,
     #[serde(with = "string_or_float")]
-    pub open_price: f64,
+    pub open_price: Decimal,
     #[serde(with = "string_or_float")]
-    pub high_price: f64,
+    pub high_price: Decimal,
     #[serde(with = "string_or_float")]
-    pub low_price: f64,
+    pub low_price: Decimal,
     #[serde(with = "string_or_float")]
-    pub volume: f64,
+    pub volume: Decimal,
     pub open_time: u64,
     pub close_time: u64,
     pub first_id: u64,
@@ -418,9 +436,9 @@ pub struct AggTrade {
     #[serde(rename = "M")]
     pub best_match: bool,
     #[serde(rename = "p", with = "string_or_float")]
-    pub price: f64,
+    pub price: Decimal,
     #[serde(rename = "q", with = "string_or_float")]
-    pub qty: f64,
+    pub qty: Decimal,
 }
 
 #[derive(Debug, Serialize_repr, Deserialize_repr, Clone, PartialEq, Eq)]
@@ -434,7 +452,7 @@ pub enum MarginTransferType {
 #[serde(rename_all = "camelCase")]
 pub struct Transfer {
     pub asset: String,
-    pub amount: f64,
+    pub amount: Decimal,
     #[serde(rename(serialize = "type", deserialize = "type"))]
     pub transfer_type: MarginTransferType,
 }
@@ -451,7 +469,7 @@ pub enum IsolatedMarginTransferType {
 pub struct IsolatedTransfer {
     pub asset: String,
     pub symbol: String,
-    pub amount: f64,
+    pub amount: Decimal,
     pub trans_from: IsolatedMarginTransferType,
     pub trans_to: IsolatedMarginTransferType,
 }
@@ -460,7 +478,7 @@ pub struct IsolatedTransfer {
 #[serde(rename_all = "camelCase")]
 pub struct Loan {
     pub asset: String,
-    pub amount: f64,
+    pub amount: Decimal,
     pub is_isolated: Option<String>,
     pub symbol: Option<String>,
 }
@@ -561,14 +579,14 @@ pub struct MarginOrder {
     pub side: OrderSide,
     #[serde(rename(serialize = "type", deserialize = "type"))]
     pub order_type: OrderType,
-    pub quantity: Option<f64>,
-    pub quote_order_qty: Option<f64>,
-    pub price: Option<f64>,
+    pub quantity: Option<Decimal>,
+    pub quote_order_qty: Option<Decimal>,
+    pub price: Option<Decimal>,
     /// Used with `OrderType::StopLoss`, `OrderType::StopLossLimit`, `OrderType::TakeProfit` and `OrderType::TakeProfitLimit`
-    pub stop_price: Option<f64>,
+    pub stop_price: Option<Decimal>,
     pub new_client_order_id: Option<String>,
     /// Used with `OrderType::Limit`, `OrderType::StopLossLimit` and `OrderType::TakeProfitLimit` to create an iceberg order
-    pub iceberg_qty: Option<f64>,
+    pub iceberg_qty: Option<Decimal>,
     /// Default is `OrderResponse::ACK`
     pub new_order_resp_type: OrderResponse,
     /// N.B. : do not set with `OrderType::Market`
@@ -598,13 +616,13 @@ pub struct MarginOrderCancellationResult {
     pub orig_client_order_id: Option<String>,
     pub client_order_id: Option<String>,
     #[serde(with = "string_or_float_opt")]
-    pub price: Option<f64>,
+    pub price: Option<Decimal>,
     #[serde(with = "string_or_float_opt")]
-    pub orig_qty: Option<f64>,
+    pub orig_qty: Option<Decimal>,
     #[serde(with = "string_or_float_opt")]
-    pub executed_qty: Option<f64>,
+    pub executed_qty: Option<Decimal>,
     #[serde(with = "string_or_float_opt")]
-    pub cummulative_quote_qty: Option<f64>,
+    pub cummulative_quote_qty: Option<Decimal>,
     pub status: Option<OrderStatus>,
     pub time_in_force: Option<TimeInForce>,
     #[serde(rename(serialize = "type", deserialize = "type"))]
@@ -636,16 +654,16 @@ pub struct MarginOCOOrder {
     /// A unique identifier that will be applied to all orders
     pub list_client_order_id: Option<String>,
     pub side: OrderSide,
-    pub quantity: f64,
+    pub quantity: Decimal,
     /// A unique identifier that will be applied to the limit order
     pub limit_client_order_id: Option<String>,
-    pub price: f64,
-    pub limit_iceberg_qty: Option<f64>,
+    pub price: Decimal,
+    pub limit_iceberg_qty: Option<Decimal>,
     /// A unique identifier that will be applied to the stop order
     pub stop_client_order_id: Option<String>,
-    pub stop_price: f64,
-    pub stop_limit_price: Option<f64>,
-    pub stop_iceberg_qty: Option<f64>,
+    pub stop_price: Decimal,
+    pub stop_limit_price: Option<Decimal>,
+    pub stop_iceberg_qty: Option<Decimal>,
     pub stop_limit_time_in_force: Option<TimeInForce>,
     /// Default is `OrderResponse::ACK`
     pub new_order_resp_type: Option<OrderResponse>,
@@ -664,7 +682,7 @@ pub struct MarginOCOOrderResult {
     pub transaction_time: u128,
     pub symbol: String,
     #[serde(default, with = "string_or_float_opt")]
-    pub margin_buy_borrow_amount: Option<f64>,
+    pub margin_buy_borrow_amount: Option<Decimal>,
     pub margin_buy_borrow_asset: Option<String>,
     pub is_isolated: Option<bool>,
     pub orders: Vec<OCOOrderDetail>,
@@ -687,22 +705,22 @@ pub struct OCOOrderReport {
     pub client_order_id: Option<String>,
     pub transact_time: u128,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Decimal,
     #[serde(with = "string_or_float")]
-    pub orig_qty: f64,
+    pub orig_qty: Decimal,
     #[serde(with = "string_or_float")]
-    pub executed_qty: f64,
+    pub executed_qty: Decimal,
     #[serde(with = "string_or_float")]
-    pub cummulative_quote_qty: f64,
+    pub cummulative_quote_qty: Decimal,
     pub status: OrderStatus,
     pub time_in_force: TimeInForce,
     #[serde(rename = "type")]
     pub order_type: OrderType,
     pub side: OrderSide,
     #[serde(default, with = "string_or_float_opt")]
-    pub stop_price: Option<f64>,
+    pub stop_price: Option<Decimal>,
     #[serde(default, with = "string_or_float_opt")]
-    pub iceberg_qty: Option<f64>,
+    pub iceberg_qty: Option<Decimal>,
 }
 
 /// archived and is_isolated are only applicable to certain endpoints
@@ -777,12 +795,12 @@ pub struct IsolatedTransfersQuery {
 #[serde(rename_all = "camelCase")]
 pub struct RepayState {
     #[serde(with = "string_or_float")]
-    pub amount: f64,
+    pub amount: Decimal,
     pub asset: String,
     #[serde(with = "string_or_float")]
-    pub interest: f64,
+    pub interest: Decimal,
     #[serde(with = "string_or_float")]
-    pub principal: f64,
+    pub principal: Decimal,
     pub status: TransactionStatus,
     pub timestamp: u64,
     pub tx_id: u64,
@@ -804,7 +822,7 @@ pub enum TransactionStatus {
 pub struct LoanState {
     pub asset: String,
     #[serde(with = "string_or_float")]
-    pub principal: f64,
+    pub principal: Decimal,
     pub timestamp: u64,
     pub status: TransactionStatus,
     pub isolated_symbol: Option<String>,
@@ -825,7 +843,7 @@ pub enum TransferType {
 #[serde(rename_all = "camelCase")]
 pub struct OrderState {
     #[serde(with = "string_or_float")]
-    pub amount: f64,
+    pub amount: Decimal,
     pub asset: String,
     pub status: TransactionStatus,
     pub timestamp: u64,
@@ -854,12 +872,12 @@ pub enum InterestType {
 pub struct InterestState {
     pub asset: String,
     #[serde(with = "string_or_float")]
-    pub interest: f64,
+    pub interest: Decimal,
     pub interest_accured_time: u64,
     #[serde(with = "string_or_float")]
-    pub interest_rate: f64,
+    pub interest_rate: Decimal,
     #[serde(with = "string_or_float")]
-    pub principal: f64,
+    pub principal: Decimal,
     #[serde(rename = "type")]
     pub interest_type: InterestType,
     pub isolated_symbol: Option<String>,
@@ -869,14 +887,14 @@ pub struct InterestState {
 #[serde(rename_all = "camelCase")]
 pub struct ForcedLiquidationState {
     #[serde(with = "string_or_float")]
-    pub avg_price: f64,
+    pub avg_price: Decimal,
     #[serde(with = "string_or_float")]
-    pub executed_qty: f64,
+    pub executed_qty: Decimal,
     pub order_id: u64,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Decimal,
     #[serde(with = "string_or_float")]
-    pub qty: f64,
+    pub qty: Decimal,
     pub side: OrderSide,
     pub symbol: String,
     pub time_in_force: TimeInForce,
@@ -896,15 +914,15 @@ pub struct RecordsQueryResult<R> {
 pub struct UserAsset {
     pub asset: String,
     #[serde(with = "string_or_float")]
-    pub borrowed: f64,
+    pub borrowed: Decimal,
     #[serde(with = "string_or_float")]
-    pub free: f64,
+    pub free: Decimal,
     #[serde(with = "string_or_float")]
-    pub interest: f64,
+    pub interest: Decimal,
     #[serde(with = "string_or_float")]
-    pub locked: f64,
+    pub locked: Decimal,
     #[serde(with = "string_or_float")]
-    pub net_asset: f64,
+    pub net_asset: Decimal,
 }
 
 pub type UserAssets = Vec<UserAsset>;
@@ -914,13 +932,13 @@ pub type UserAssets = Vec<UserAsset>;
 pub struct MarginAccountDetails {
     pub borrow_enabled: bool,
     #[serde(with = "string_or_float")]
-    pub margin_level: f64,
+    pub margin_level: Decimal,
     #[serde(with = "string_or_float")]
-    pub total_asset_of_btc: f64,
+    pub total_asset_of_btc: Decimal,
     #[serde(with = "string_or_float")]
-    pub total_liability_of_btc: f64,
+    pub total_liability_of_btc: Decimal,
     #[serde(with = "string_or_float")]
-    pub total_net_asset_of_btc: f64,
+    pub total_net_asset_of_btc: Decimal,
     pub trade_enabled: bool,
     pub transfer_enabled: bool,
     pub user_assets: UserAssets,
@@ -932,20 +950,20 @@ pub struct IsolatedMarginAccountAsset {
     pub asset: String,
     pub borrow_enabled: bool,
     #[serde(with = "string_or_float")]
-    pub borrowed: f64,
+    pub borrowed: Decimal,
     #[serde(with = "string_or_float")]
-    pub free: f64,
+    pub free: Decimal,
     #[serde(with = "string_or_float")]
-    pub interest: f64,
+    pub interest: Decimal,
     #[serde(with = "string_or_float")]
-    pub locked: f64,
+    pub locked: Decimal,
     #[serde(with = "string_or_float")]
-    pub net_asset: f64,
+    pub net_asset: Decimal,
     #[serde(with = "string_or_float")]
-    pub net_asset_of_btc: f64,
+    pub net_asset_of_btc: Decimal,
     pub repay_enabled: bool,
     #[serde(with = "string_or_float")]
-    pub total_asset: f64,
+    pub total_asset: Decimal,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -957,16 +975,16 @@ pub struct IsolatedMarginAccountAssetDetails {
     pub isolated_created: bool,
     pub enabled: bool,
     #[serde(with = "string_or_float")]
-    pub margin_level: f64,
+    pub margin_level: Decimal,
     #[serde(with = "string_or_float")]
-    pub margin_ratio: f64,
+    pub margin_ratio: Decimal,
     pub margin_level_status: MarginLevelStatus,
     #[serde(with = "string_or_float")]
-    pub index_price: f64,
+    pub index_price: Decimal,
     #[serde(with = "string_or_float")]
-    pub liquidate_price: f64,
+    pub liquidate_price: Decimal,
     #[serde(with = "string_or_float")]
-    pub liquidate_rate: f64,
+    pub liquidate_rate: Decimal,
     pub trade_enabled: bool,
 }
 
@@ -987,11 +1005,11 @@ pub enum MarginLevelStatus {
 pub struct IsolatedMarginAccountDetails {
     pub assets: Vec<IsolatedMarginAccountAssetDetails>,
     #[serde(default, with = "string_or_float_opt")]
-    pub total_asset_of_btc: Option<f64>,
+    pub total_asset_of_btc: Option<Decimal>,
     #[serde(default, with = "string_or_float_opt")]
-    pub total_liability_of_btc: Option<f64>,
+    pub total_liability_of_btc: Option<Decimal>,
     #[serde(default, with = "string_or_float_opt")]
-    pub total_net_asset_of_btc: Option<f64>,
+    pub total_net_asset_of_btc: Option<Decimal>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -1015,9 +1033,9 @@ pub struct AssetDetails {
     pub is_borrowable: bool,
     pub is_mortgageable: bool,
     #[serde(with = "string_or_float")]
-    pub user_min_borrow: f64,
+    pub user_min_borrow: Decimal,
     #[serde(with = "string_or_float")]
-    pub user_min_repay: f64,
+    pub user_min_repay: Decimal,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -1080,7 +1098,7 @@ pub type AllIsolatedPairs = Vec<IsolatedPairDetails>;
 pub struct PriceIndex {
     pub calc_time: u128,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Decimal,
     pub symbol: String,
 }
 
@@ -1102,20 +1120,20 @@ pub struct MarginOrderResult {
     pub client_order_id: String,
     pub transact_time: u128,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Decimal,
     #[serde(with = "string_or_float")]
-    pub orig_qty: f64,
+    pub orig_qty: Decimal,
     #[serde(with = "string_or_float")]
-    pub executed_qty: f64,
+    pub executed_qty: Decimal,
     #[serde(with = "string_or_float")]
-    pub cummulative_quote_qty: f64,
+    pub cummulative_quote_qty: Decimal,
     pub status: OrderStatus,
     pub time_in_force: TimeInForce,
     #[serde(rename(serialize = "type", deserialize = "type"))]
     pub order_type: OrderType,
     pub side: OrderSide,
     #[serde(default, with = "string_or_float_opt")]
-    pub margin_buy_borrow_amount: Option<f64>,
+    pub margin_buy_borrow_amount: Option<Decimal>,
     pub margin_buy_borrow_asset: Option<String>,
     pub is_isolated: Option<bool>,
     pub fills: Vec<Fill>,
@@ -1126,21 +1144,21 @@ pub struct MarginOrderResult {
 pub struct MarginOrderState {
     pub client_order_id: String,
     #[serde(with = "string_or_float")]
-    pub cummulative_quote_qty: f64,
+    pub cummulative_quote_qty: Decimal,
     #[serde(with = "string_or_float")]
-    pub executed_qty: f64,
+    pub executed_qty: Decimal,
     #[serde(with = "string_or_float")]
-    pub iceberg_qty: f64,
+    pub iceberg_qty: Decimal,
     pub is_working: bool,
     pub order_id: u64,
     #[serde(with = "string_or_float")]
-    pub orig_qty: f64,
+    pub orig_qty: Decimal,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Decimal,
     pub side: OrderSide,
     pub status: OrderStatus,
     #[serde(with = "string_or_float")]
-    pub stop_price: f64,
+    pub stop_price: Decimal,
     pub symbol: String,
     pub is_isolated: Option<bool>,
     pub time: u64,
@@ -1154,9 +1172,9 @@ pub struct MarginOrderState {
 #[serde(rename_all = "camelCase")]
 pub struct OrderSumaryState {
     pub id: u64,
-    pub price: f64,
-    pub qty: f64,
-    pub quote_qty: f64,
+    pub price: Decimal,
+    pub qty: Decimal,
+    pub quote_qty: Decimal,
     pub symbol: String,
     pub time: u128,
 }
@@ -1165,7 +1183,7 @@ pub struct OrderSumaryState {
 #[serde(rename_all = "camelCase")]
 pub struct OwnTradesState {
     #[serde(with = "string_or_float")]
-    pub commission: f64,
+    pub commission: Decimal,
     pub commission_asset: String,
     pub id: u64,
     pub is_best_match: bool,
@@ -1173,9 +1191,9 @@ pub struct OwnTradesState {
     pub is_maker: bool,
     pub order_id: u64,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Decimal,
     #[serde(with = "string_or_float")]
-    pub qty: f64,
+    pub qty: Decimal,
     pub symbol: String,
     pub time: u128,
     pub is_isolated: bool,
@@ -1185,16 +1203,16 @@ pub struct OwnTradesState {
 #[serde(rename_all = "camelCase")]
 pub struct MaxBorrowableAmount {
     #[serde(with = "string_or_float")]
-    pub amount: f64,
+    pub amount: Decimal,
     #[serde(with = "string_or_float")]
-    pub borrow_limit: f64,
+    pub borrow_limit: Decimal,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct MaxTransferableAmount {
     #[serde(with = "string_or_float")]
-    pub amount: f64,
+    pub amount: Decimal,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
@@ -1378,27 +1396,389 @@ pub struct InterestRateHistoryQuery {
 pub struct InterestRateAssetHistory {
     pub asset: String,
     #[serde(with = "string_or_float")]
-    pub daily_interest_rate: f64,
+    pub daily_interest_rate: Decimal,
     pub timestamp: u128,
     pub vip_level: u8,
 }
 
 pub type InterestRateHistory = Vec<InterestRateAssetHistory>;
 
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+/// Implement `as_str`, `Display`, `FromStr` and `TryFrom` strings for an enum of intervals,
+/// failing with `$error` on unknown strings
+macro_rules! interval_strings {
+    ($name:ident, $error:ident, { $($variant:ident => $value:literal),+ $(,)? }) => {
+        impl $name {
+            /// Every value, shortest first
+            pub const ALL: &'static [$name] = &[$($name::$variant),+];
+
+            /// Representation in requests and streams, e.g. `"1m"`
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    $($name::$variant => $value),+
+                }
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result { f.write_str(self.as_str()) }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = crate::errors::Error;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                $name::ALL
+                    .iter()
+                    .find(|value| value.as_str() == s)
+                    .copied()
+                    .ok_or_else(|| crate::errors::Error::$error(s.to_string()))
+            }
+        }
+
+        impl TryFrom<&str> for $name {
+            type Error = crate::errors::Error;
+
+            fn try_from(s: &str) -> Result<Self, Self::Error> { s.parse() }
+        }
+
+        impl TryFrom<String> for $name {
+            type Error = crate::errors::Error;
+
+            fn try_from(s: String) -> Result<Self, Self::Error> { s.parse() }
+        }
+    };
+}
+
+/// Interval of klines, `"1m"` for one minute
+///
+/// Accepted by every kline method as the enum or as its string, which is parsed before sending the
+/// request.
+///
+/// # Examples
+/// ```rust
+/// use binance::rest_model::KlineInterval;
+/// let interval: KlineInterval = "15m".parse().unwrap();
+/// assert_eq!(interval, KlineInterval::FifteenMinutes);
+/// // 2024-01-01T10:20:00Z is in the kline opened at 10:15
+/// assert_eq!(interval.open_time(1_704_104_400_000), Some(1_704_104_100_000));
+/// assert!("15 minutes".parse::<KlineInterval>().is_err());
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum KlineInterval {
+    /// Spot only
+    #[serde(rename = "1s")]
+    OneSecond,
+    #[serde(rename = "1m")]
+    OneMinute,
+    #[serde(rename = "3m")]
+    ThreeMinutes,
+    #[serde(rename = "5m")]
+    FiveMinutes,
+    #[serde(rename = "15m")]
+    FifteenMinutes,
+    #[serde(rename = "30m")]
+    ThirtyMinutes,
+    #[serde(rename = "1h")]
+    OneHour,
+    #[serde(rename = "2h")]
+    TwoHours,
+    #[serde(rename = "4h")]
+    FourHours,
+    #[serde(rename = "6h")]
+    SixHours,
+    #[serde(rename = "8h")]
+    EightHours,
+    #[serde(rename = "12h")]
+    TwelveHours,
+    #[serde(rename = "1d")]
+    OneDay,
+    #[serde(rename = "3d")]
+    ThreeDays,
+    /// Opened on Mondays
+    #[serde(rename = "1w")]
+    OneWeek,
+    /// Opened on the first day of the month
+    #[serde(rename = "1M")]
+    OneMonth,
+}
+
+interval_strings!(KlineInterval, InvalidInterval, {
+    OneSecond => "1s",
+    OneMinute => "1m",
+    ThreeMinutes => "3m",
+    FiveMinutes => "5m",
+    FifteenMinutes => "15m",
+    ThirtyMinutes => "30m",
+    OneHour => "1h",
+    TwoHours => "2h",
+    FourHours => "4h",
+    SixHours => "6h",
+    EightHours => "8h",
+    TwelveHours => "12h",
+    OneDay => "1d",
+    ThreeDays => "3d",
+    OneWeek => "1w",
+    OneMonth => "1M",
+});
+
+const MINUTE_MS: i64 = 60_000;
+const DAY_MS: i64 = 24 * 60 * MINUTE_MS;
+
+impl KlineInterval {
+    /// Length of the klines, None for months which vary in length
+    pub fn duration(&self) -> Option<std::time::Duration> {
+        self.millis().map(|ms| std::time::Duration::from_millis(ms as u64))
+    }
+
+    fn millis(&self) -> Option<i64> {
+        Some(match self {
+            KlineInterval::OneSecond => 1_000,
+            KlineInterval::OneMinute => MINUTE_MS,
+            KlineInterval::ThreeMinutes => 3 * MINUTE_MS,
+            KlineInterval::FiveMinutes => 5 * MINUTE_MS,
+            KlineInterval::FifteenMinutes => 15 * MINUTE_MS,
+            KlineInterval::ThirtyMinutes => 30 * MINUTE_MS,
+            KlineInterval::OneHour => 60 * MINUTE_MS,
+            KlineInterval::TwoHours => 120 * MINUTE_MS,
+            KlineInterval::FourHours => 240 * MINUTE_MS,
+            KlineInterval::SixHours => 360 * MINUTE_MS,
+            KlineInterval::EightHours => 480 * MINUTE_MS,
+            KlineInterval::TwelveHours => 720 * MINUTE_MS,
+            KlineInterval::OneDay => DAY_MS,
+            KlineInterval::ThreeDays => 3 * DAY_MS,
+            KlineInterval::OneWeek => 7 * DAY_MS,
+            KlineInterval::OneMonth => return None,
+        })
+    }
+
+    /// Open time of the kline containing `timestamp`, both in milliseconds since the epoch, in UTC
+    ///
+    /// None if the open time is out of the range of [`DateTime`] for monthly klines, or of `i64`
+    pub fn open_time(&self, timestamp: i64) -> Option<i64> {
+        match (self, self.millis()) {
+            // The epoch is a Thursday, weeks are opened on Mondays
+            (KlineInterval::OneWeek, Some(ms)) => Some(timestamp - timestamp.checked_add(3 * DAY_MS)?.rem_euclid(ms)),
+            (_, Some(ms)) => Some(timestamp - timestamp.rem_euclid(ms)),
+            (_, None) => {
+                let date = utc_date(timestamp)?;
+                month_millis(date.year(), date.month())
+            }
+        }
+    }
+
+    /// Open time of the kline following the one containing `timestamp`, None if out of range
+    pub fn next_open_time(&self, timestamp: i64) -> Option<i64> {
+        match self.millis() {
+            Some(ms) => self.open_time(timestamp)?.checked_add(ms),
+            None => {
+                let date = utc_date(timestamp)?;
+                match date.month() {
+                    12 => month_millis(date.year().checked_add(1)?, 1),
+                    month => month_millis(date.year(), month + 1),
+                }
+            }
+        }
+    }
+
+    /// Close time of the kline containing `timestamp`, the last millisecond before the next open time,
+    /// None if out of range
+    pub fn close_time(&self, timestamp: i64) -> Option<i64> { Some(self.next_open_time(timestamp)? - 1) }
+}
+
+fn utc_date(timestamp: i64) -> Option<chrono::NaiveDate> {
+    DateTime::<Utc>::from_timestamp_millis(timestamp).map(|time| time.date_naive())
+}
+
+fn month_millis(year: i32, month: u32) -> Option<i64> {
+    let start = chrono::NaiveDate::from_ymd_opt(year, month, 1)?.and_hms_opt(0, 0, 0)?;
+    Some(start.and_utc().timestamp_millis())
+}
+
+/// Period of the futures trading data statistics, open interest history and long/short ratios
+///
+/// Accepted by every period method as the enum or as its string, which is parsed before sending the
+/// request.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum DataPeriod {
+    #[serde(rename = "5m")]
+    FiveMinutes,
+    #[serde(rename = "15m")]
+    FifteenMinutes,
+    #[serde(rename = "30m")]
+    ThirtyMinutes,
+    #[serde(rename = "1h")]
+    OneHour,
+    #[serde(rename = "2h")]
+    TwoHours,
+    #[serde(rename = "4h")]
+    FourHours,
+    #[serde(rename = "6h")]
+    SixHours,
+    #[serde(rename = "12h")]
+    TwelveHours,
+    #[serde(rename = "1d")]
+    OneDay,
+}
+
+interval_strings!(DataPeriod, InvalidPeriod, {
+    FiveMinutes => "5m",
+    FifteenMinutes => "15m",
+    ThirtyMinutes => "30m",
+    OneHour => "1h",
+    TwoHours => "2h",
+    FourHours => "4h",
+    SixHours => "6h",
+    TwelveHours => "12h",
+    OneDay => "1d",
+});
+
+impl DataPeriod {
+    /// Length of the period
+    pub fn duration(&self) -> std::time::Duration { KlineInterval::from(*self).duration().unwrap_or_default() }
+}
+
+impl From<DataPeriod> for KlineInterval {
+    fn from(period: DataPeriod) -> Self {
+        match period {
+            DataPeriod::FiveMinutes => KlineInterval::FiveMinutes,
+            DataPeriod::FifteenMinutes => KlineInterval::FifteenMinutes,
+            DataPeriod::ThirtyMinutes => KlineInterval::ThirtyMinutes,
+            DataPeriod::OneHour => KlineInterval::OneHour,
+            DataPeriod::TwoHours => KlineInterval::TwoHours,
+            DataPeriod::FourHours => KlineInterval::FourHours,
+            DataPeriod::SixHours => KlineInterval::SixHours,
+            DataPeriod::TwelveHours => KlineInterval::TwelveHours,
+            DataPeriod::OneDay => KlineInterval::OneDay,
+        }
+    }
+}
+
+/// Kline of any product
+///
+/// Deserialized from the rows of spot and futures klines, e.g. `[1499040000000, "0.01634790", ...]`,
+/// with any trailing columns ignored, or from the objects of options klines.
+#[derive(Serialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct KlineSummary {
     pub open_time: i64,
-    pub open: f64,
-    pub high: f64,
-    pub low: f64,
-    pub close: f64,
-    pub volume: f64,
+    pub open: Decimal,
+    pub high: Decimal,
+    pub low: Decimal,
+    pub close: Decimal,
+    pub volume: Decimal,
     pub close_time: i64,
-    pub quote_asset_volume: f64,
+    pub quote_asset_volume: Decimal,
     pub number_of_trades: i64,
-    pub taker_buy_base_asset_volume: f64,
-    pub taker_buy_quote_asset_volume: f64,
+    pub taker_buy_base_asset_volume: Decimal,
+    pub taker_buy_quote_asset_volume: Decimal,
+}
+
+impl<'de> serde::Deserialize<'de> for KlineSummary {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_any(KlineSummaryVisitor)
+    }
+}
+
+/// Decimal column of a kline row
+struct KlineDecimal(Decimal);
+
+impl<'de> serde::Deserialize<'de> for KlineDecimal {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        string_or_float::deserialize(deserializer).map(KlineDecimal)
+    }
+}
+
+/// Kline object of options, with the field names of [`KlineSummary`] also accepted
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct KlineObject {
+    open_time: i64,
+    #[serde(with = "string_or_float")]
+    open: Decimal,
+    #[serde(with = "string_or_float")]
+    high: Decimal,
+    #[serde(with = "string_or_float")]
+    low: Decimal,
+    #[serde(with = "string_or_float")]
+    close: Decimal,
+    #[serde(with = "string_or_float")]
+    volume: Decimal,
+    close_time: i64,
+    #[serde(with = "string_or_float", alias = "amount")]
+    quote_asset_volume: Decimal,
+    #[serde(alias = "tradeCount")]
+    number_of_trades: i64,
+    #[serde(with = "string_or_float", alias = "takerVolume")]
+    taker_buy_base_asset_volume: Decimal,
+    #[serde(with = "string_or_float", alias = "takerAmount")]
+    taker_buy_quote_asset_volume: Decimal,
+}
+
+struct KlineSummaryVisitor;
+
+impl<'de> serde::de::Visitor<'de> for KlineSummaryVisitor {
+    type Value = KlineSummary;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a kline row or object")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<KlineSummary, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        fn column<'de, A, T>(seq: &mut A, index: usize) -> Result<T, A::Error>
+        where
+            A: serde::de::SeqAccess<'de>,
+            T: serde::Deserialize<'de>,
+        {
+            seq.next_element()?
+                .ok_or_else(|| serde::de::Error::invalid_length(index, &"a kline row of 11 columns"))
+        }
+
+        let kline = KlineSummary {
+            open_time: column(&mut seq, 0)?,
+            open: column::<_, KlineDecimal>(&mut seq, 1)?.0,
+            high: column::<_, KlineDecimal>(&mut seq, 2)?.0,
+            low: column::<_, KlineDecimal>(&mut seq, 3)?.0,
+            close: column::<_, KlineDecimal>(&mut seq, 4)?.0,
+            volume: column::<_, KlineDecimal>(&mut seq, 5)?.0,
+            close_time: column(&mut seq, 6)?,
+            quote_asset_volume: column::<_, KlineDecimal>(&mut seq, 7)?.0,
+            number_of_trades: column(&mut seq, 8)?,
+            taker_buy_base_asset_volume: column::<_, KlineDecimal>(&mut seq, 9)?.0,
+            taker_buy_quote_asset_volume: column::<_, KlineDecimal>(&mut seq, 10)?.0,
+        };
+        // Trailing columns are unused
+        while seq.next_element::<serde::de::IgnoredAny>()?.is_some() {}
+        Ok(kline)
+    }
+
+    fn visit_map<A>(self, map: A) -> Result<KlineSummary, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        let kline: KlineObject = serde::Deserialize::deserialize(serde::de::value::MapAccessDeserializer::new(map))?;
+        Ok(KlineSummary {
+            open_time: kline.open_time,
+            open: kline.open,
+            high: kline.high,
+            low: kline.low,
+            close: kline.close,
+            volume: kline.volume,
+            close_time: kline.close_time,
+            quote_asset_volume: kline.quote_asset_volume,
+            number_of_trades: kline.number_of_trades,
+            taker_buy_base_asset_volume: kline.taker_buy_base_asset_volume,
+            taker_buy_quote_asset_volume: kline.taker_buy_quote_asset_volume,
+        })
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1434,24 +1814,24 @@ pub struct WalletCoinInfo {
     pub coin: String,
     pub deposit_all_enable: bool,
     #[serde(with = "string_or_float")]
-    pub free: f64,
+    pub free: Decimal,
     #[serde(with = "string_or_float")]
-    pub freeze: f64,
+    pub freeze: Decimal,
     #[serde(with = "string_or_float")]
-    pub ipoable: f64,
+    pub ipoable: Decimal,
     #[serde(with = "string_or_float")]
-    pub ipoing: f64,
+    pub ipoing: Decimal,
     pub is_legal_money: bool,
     #[serde(with = "string_or_float")]
-    pub locked: f64,
+    pub locked: Decimal,
     pub name: String,
     pub network_list: Vec<CoinNetwork>,
     #[serde(with = "string_or_float")]
-    pub storage: f64,
+    pub storage: Decimal,
     pub trading: bool,
     pub withdraw_all_enable: bool,
     #[serde(with = "string_or_float")]
-    pub withdrawing: f64,
+    pub withdrawing: Decimal,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1474,13 +1854,13 @@ pub struct CoinNetwork {
     pub withdraw_desc: String,
     pub withdraw_enable: bool,
     #[serde(with = "string_or_float")]
-    pub withdraw_fee: f64,
+    pub withdraw_fee: Decimal,
     #[serde(with = "string_or_float")]
-    pub withdraw_integer_multiple: f64,
+    pub withdraw_integer_multiple: Decimal,
     #[serde(with = "string_or_float")]
-    pub withdraw_max: f64,
+    pub withdraw_max: Decimal,
     #[serde(with = "string_or_float")]
-    pub withdraw_min: f64,
+    pub withdraw_min: Decimal,
     #[serde(default)]
     pub same_address: bool,
 }
@@ -1507,7 +1887,7 @@ pub struct SnapshotVos {
 pub struct SnapshotVosData {
     pub balances: Vec<Balance>,
     #[serde(with = "string_or_float")]
-    pub total_asset_of_btc: f64,
+    pub total_asset_of_btc: Decimal,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
@@ -1538,7 +1918,7 @@ pub struct CoinWithdrawalQuery {
     pub address: String,
     /// Secondary address identifier for coins like XRP,XMR etc.
     pub address_tag: Option<String>,
-    pub amount: f64,
+    pub amount: Decimal,
     /// When making internal transfer, true for returning the fee to the destination account; false for returning the fee back to the departure account. Default false.
     pub transaction_fee_flag: Option<bool>,
     /// Description of the address. Space in name should be encoded into %20.
@@ -1568,7 +1948,7 @@ pub struct DepositHistoryQuery {
 pub struct DepositRecord {
     pub coin: String,
     #[serde(with = "string_or_float")]
-    pub amount: f64,
+    pub amount: Decimal,
     pub network: String,
     pub status: u8,
     pub address: String,
@@ -1612,7 +1992,7 @@ pub struct RecordHistory<T> {
 pub struct WithdrawalRecord {
     pub address: String,
     #[serde(with = "string_or_float")]
-    pub amount: f64,
+    pub amount: Decimal,
     pub apply_time: String,
     pub coin: String,
     pub id: String,
@@ -1623,7 +2003,7 @@ pub struct WithdrawalRecord {
     pub transfer_type: u8,
     pub status: u8,
     #[serde(with = "string_or_float")]
-    pub transaction_fee: f64,
+    pub transaction_fee: Decimal,
     /// // confirm times for withdraw
     pub confirm_no: Option<u64>,
     pub info: Option<String>,
@@ -1700,7 +2080,7 @@ pub enum UniversalTransferType {
 #[serde(rename_all = "camelCase")]
 pub struct UniversalTransfer {
     pub asset: String,
-    pub amount: f64,
+    pub amount: Decimal,
     pub from_symbol: Option<String>,
     pub to_symbol: Option<String>,
     #[serde(rename(serialize = "type", deserialize = "type"))]
@@ -1735,7 +2115,7 @@ pub enum UniversalTransferStatus {
 pub struct UniversalTransferRecord {
     pub asset: String,
     #[serde(with = "string_or_float")]
-    pub amount: f64,
+    pub amount: Decimal,
     #[serde(rename(serialize = "type", deserialize = "type"))]
     pub transfer_type: UniversalTransferType,
     pub status: UniversalTransferStatus,
@@ -1791,10 +2171,10 @@ pub struct UserAssetDribblet {
     pub operate_time: u64,
     /// Total transfered BNB amount for this exchange.
     #[serde(with = "string_or_float")]
-    pub total_transfered_amount: f64,
+    pub total_transfered_amount: Decimal,
     ///Total service charge amount for this exchange.
     #[serde(with = "string_or_float")]
-    pub total_service_charge_amount: f64,
+    pub total_service_charge_amount: Decimal,
     pub trans_id: u64,
     pub user_asset_dribblet_details: Vec<UserAssetDribbletDetail>,
 }
@@ -1804,11 +2184,11 @@ pub struct UserAssetDribblet {
 pub struct UserAssetDribbletDetail {
     pub trans_id: u64,
     #[serde(with = "string_or_float")]
-    pub amount: f64,
+    pub amount: Decimal,
     #[serde(with = "string_or_float")]
-    pub transfered_amount: f64,
+    pub transfered_amount: Decimal,
     #[serde(with = "string_or_float")]
-    pub service_charge_amount: f64,
+    pub service_charge_amount: Decimal,
     pub operate_time: u64,
     pub from_asset: String,
 }
@@ -1819,12 +2199,12 @@ pub struct ConvertibleAssets {
     pub details: Vec<ConvertibleAssetDetails>,
     #[serde(with = "string_or_float")]
     #[serde(rename = "totalTransferBtc")]
-    pub total_transfer_btc: f64,
+    pub total_transfer_btc: Decimal,
     #[serde(with = "string_or_float")]
     #[serde(rename = "totalTransferBNB")]
-    pub total_transfer_bnb: f64,
+    pub total_transfer_bnb: Decimal,
     #[serde(with = "string_or_float_opt", default)]
-    pub driblet_percentage: Option<f64>,
+    pub driblet_percentage: Option<Decimal>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -1833,27 +2213,27 @@ pub struct ConvertibleAssetDetails {
     pub asset: String,
     pub asset_full_name: String,
     #[serde(with = "string_or_float")]
-    pub amount_free: f64,
+    pub amount_free: Decimal,
     #[serde(with = "string_or_float")]
     #[serde(rename = "toBNB")]
-    pub to_bnb: f64,
+    pub to_bnb: Decimal,
     #[serde(with = "string_or_float")]
     #[serde(rename = "toBTC")]
-    pub to_btc: f64,
+    pub to_btc: Decimal,
     #[serde(with = "string_or_float")]
     #[serde(rename = "toBNBOffExchange")]
-    pub to_bnb_off_exchange: f64,
+    pub to_bnb_off_exchange: Decimal,
     #[serde(with = "string_or_float")]
-    pub exchange: f64,
+    pub exchange: Decimal,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct DustTransfer {
     #[serde(with = "string_or_float")]
-    pub total_service_charge: f64,
+    pub total_service_charge: Decimal,
     #[serde(with = "string_or_float")]
-    pub total_transferred: f64,
+    pub total_transferred: Decimal,
     pub transfer_result: Vec<DustTransferResult>,
 }
 
@@ -1861,14 +2241,14 @@ pub struct DustTransfer {
 #[serde(rename_all = "camelCase")]
 pub struct DustTransferResult {
     #[serde(with = "string_or_float")]
-    pub amount: f64,
+    pub amount: Decimal,
     pub from_asset: String,
     pub operate_time: u64,
     #[serde(with = "string_or_float")]
-    pub service_charge_amount: f64,
+    pub service_charge_amount: Decimal,
     pub tran_id: u64,
     #[serde(with = "string_or_float")]
-    pub transfered_amount: f64,
+    pub transfered_amount: Decimal,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -1876,7 +2256,7 @@ pub struct DustTransferResult {
 pub struct AssetDividend {
     pub id: u64,
     #[serde(with = "string_or_float")]
-    pub amount: f64,
+    pub amount: Decimal,
     pub asset: String,
     pub div_time: u64,
     pub en_info: String,
@@ -1901,12 +2281,12 @@ pub struct SupportedAssetDetail {
     /// min withdraw amount
     #[serde(with = "string_or_float_opt")]
     #[serde(rename = "minWithdrawAmount")]
-    pub min_withdrawal_amount: Option<f64>,
+    pub min_withdrawal_amount: Option<Decimal>,
     /// deposit status (false if ALL of networks' are false)
     pub deposit_status: bool,
     /// withdraw fee
     #[serde(with = "string_or_float_opt")]
-    pub withdraw_fee: Option<f64>,
+    pub withdraw_fee: Option<Decimal>,
     /// withdraw status (false if ALL of networks' are false)
     pub withdraw_status: bool,
     /// reason
@@ -1920,9 +2300,9 @@ pub type TradeFees = Vec<TradeFee>;
 pub struct TradeFee {
     pub symbol: String,
     #[serde(with = "string_or_float")]
-    pub maker_commission: f64,
+    pub maker_commission: Decimal,
     #[serde(with = "string_or_float")]
-    pub taker_commission: f64,
+    pub taker_commission: Decimal,
 }
 
 pub type WalletFundings = Vec<WalletFunding>;
@@ -1932,15 +2312,15 @@ pub type WalletFundings = Vec<WalletFunding>;
 pub struct WalletFunding {
     pub asset: String,
     #[serde(with = "string_or_float")]
-    pub free: f64,
+    pub free: Decimal,
     #[serde(with = "string_or_float")]
-    pub locked: f64,
+    pub locked: Decimal,
     #[serde(with = "string_or_float")]
-    pub freeze: f64,
+    pub freeze: Decimal,
     #[serde(with = "string_or_float")]
-    pub withdrawing: f64,
+    pub withdrawing: Decimal,
     #[serde(with = "string_or_float")]
-    pub btc_valuation: f64,
+    pub btc_valuation: Decimal,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -1982,9 +2362,24 @@ pub struct WalletBalance {
 
 pub mod string_or_float {
     use std::fmt;
+    use std::str::FromStr;
 
     use serde::{de, Deserialize, Deserializer, Serializer};
 
+    /// Values parsed from either a string or a float
+    pub trait FromFloat: FromStr {
+        fn from_float(value: f64) -> Option<Self>;
+    }
+
+    impl FromFloat for f64 {
+        fn from_float(value: f64) -> Option<Self> { Some(value) }
+    }
+
+    #[cfg(feature = "decimal")]
+    impl FromFloat for rust_decimal::Decimal {
+        fn from_float(value: f64) -> Option<Self> { rust_decimal::prelude::FromPrimitive::from_f64(value) }
+    }
+
     pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
     where
         T: fmt::Display,
@@ -1993,9 +2388,11 @@ pub mod string_or_float {
         serializer.collect_str(value)
     }
 
-    pub fn deserialize<'de, D>(deserializer: D) -> Result<f64, D::Error>
+    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
     where
         D: Deserializer<'de>,
+        T: FromFloat,
+        T::Err: fmt::Display,
     {
         #[derive(Deserialize)]
         #[serde(untagged)]
@@ -2006,7 +2403,7 @@ pub mod string_or_float {
 
         match StringOrFloat::deserialize(deserializer)? {
             StringOrFloat::String(s) => s.parse().map_err(de::Error::custom),
-            StringOrFloat::Float(i) => Ok(i),
+            StringOrFloat::Float(i) => T::from_float(i).ok_or_else(|| de::Error::custom(format!("invalid number {i}"))),
         }
     }
 }
@@ -2027,9 +2424,11 @@ pub(crate) mod string_or_float_opt {
         }
     }
 
-    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<f64>, D::Error>
+    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
     where
         D: Deserializer<'de>,
+        T: crate::rest_model::string_or_float::FromFloat,
+        T::Err: fmt::Display,
     {
         Ok(Some(crate::rest_model::string_or_float::deserialize(deserializer)?))
     }
@@ -2145,4 +2544,90 @@ mod test {
         let result = serde_json::from_str::<ExchangeInformation>(&fc);
         assert!(result.is_ok(), "{result:?}");
     }
+
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn decimal_keeps_exchange_representation() {
+        let ticker: super::Tickers = serde_json::from_str(
+            r#"{"symbol":"BTCUSDT","bidPrice":"0.10000000","bidQty":"0.20000000","askPrice":"0.3","askQty":"1"}"#,
+        )
+        .unwrap();
+        assert_eq!(ticker.bid_price + ticker.bid_qty, ticker.ask_price);
+        assert_eq!(ticker.bid_price.to_string(), "0.10000000");
+    }
+
+    #[test]
+    fn kline_rows_and_objects() {
+        let rows: Vec<super::KlineSummary> = serde_json::from_str(
+            r#"[[1499040000000,"0.01634790","0.80000000","0.01575800","0.01577100","148976.11427815",1499644799999,"2434.19055334",308,"1756.87402397","28.46694368","0"]]"#,
+        )
+        .unwrap();
+        let objects: Vec<super::KlineSummary> = serde_json::from_str(
+            r#"[{"open":"0.01634790","high":"0.80000000","low":"0.01575800","close":"0.01577100","volume":"148976.11427815","interval":"5m","tradeCount":308,"takerVolume":"1756.87402397","takerAmount":"28.46694368","amount":"2434.19055334","openTime":1499040000000,"closeTime":1499644799999}]"#,
+        )
+        .unwrap();
+        assert_eq!(rows, objects);
+        assert_eq!(rows[0].number_of_trades, 308);
+    }
+
+    #[test]
+    fn malformed_kline_is_an_error() {
+        for row in [
+            r#"[1499040000000,null,"0.8","0.01","0.01","148976",1499644799999,"2434",308,"1756","28"]"#,
+            r#"[1499040000000,"0.01","0.8","0.01","0.01","148976",1499644799999]"#,
+            r#"["1499040000000","0.01","0.8","0.01","0.01","148976",1499644799999,"2434",308,"1756","28"]"#,
+        ] {
+            assert!(serde_json::from_str::<super::KlineSummary>(row).is_err(), "{row}");
+        }
+    }
+
+    #[test]
+    fn kline_interval_strings() {
+        use super::{DataPeriod, KlineInterval};
+        for interval in KlineInterval::ALL {
+            assert_eq!(interval.to_string().parse::<KlineInterval>().unwrap(), *interval);
+            assert_eq!(serde_json::to_string(interval).unwrap(), format!("\"{interval}\""));
+        }
+        for period in DataPeriod::ALL {
+            assert_eq!(period.to_string(), KlineInterval::from(*period).to_string());
+        }
+        assert_ne!("1m".parse::<KlineInterval>().unwrap(), "1M".parse().unwrap());
+        assert!(matches!(
+            "1s".parse::<DataPeriod>(),
+            Err(crate::errors::Error::InvalidPeriod(_))
+        ));
+        assert!(matches!(
+            KlineInterval::try_from("1y"),
+            Err(crate::errors::Error::InvalidInterval(_))
+        ));
+    }
+
+    #[test]
+    fn kline_interval_alignment() {
+        use super::KlineInterval;
+        // Wednesday 2024-01-03T12:00:00Z
+        let wednesday = 1_704_283_200_000;
+        assert_eq!(KlineInterval::OneWeek.open_time(wednesday), Some(1_704_067_200_000));
+        assert_eq!(KlineInterval::OneDay.close_time(wednesday), Some(1_704_326_399_999));
+        // 2024-02-15T08:00:00Z and 2023-12-10T00:00:00Z
+        assert_eq!(
+            KlineInterval::OneMonth.open_time(1_707_984_000_000),
+            Some(1_706_745_600_000)
+        );
+        assert_eq!(
+            KlineInterval::OneMonth.next_open_time(1_707_984_000_000),
+            Some(1_709_251_200_000)
+        );
+        assert_eq!(
+            KlineInterval::OneMonth.next_open_time(1_702_166_400_000),
+            Some(1_704_067_200_000)
+        );
+        assert_eq!(KlineInterval::OneMonth.open_time(i64::MAX), None);
+        assert_eq!(KlineInterval::OneWeek.close_time(i64::MAX), None);
+        assert_eq!(KlineInterval::OneMonth.duration(), None);
+        assert_eq!(
+            KlineInterval::FourHours.duration(),
+            Some(std::time::Duration::from_secs(4 * 3600))
+        );
+    }
 }
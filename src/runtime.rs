@@ -0,0 +1,54 @@
+//! Async runtime the crate runs on
+//!
+//! Timers and sockets come from tokio with the `tokio-runtime` feature, enabled by default, or from
+//! async-io, the reactor of smol and async-std, with the `smol-runtime` feature. tokio is used when both
+//! are enabled.
+
+use std::future::Future;
+use std::time::Duration;
+
+use crate::errors::*;
+
+#[cfg(not(any(feature = "tokio-runtime", feature = "smol-runtime")))]
+compile_error!("one of the `tokio-runtime` and `smol-runtime` features must be enabled");
+
+/// TCP connection, read and written with the `futures` io traits
+#[cfg(feature = "tokio-runtime")]
+pub(crate) type TcpStream = tokio_util::compat::Compat<tokio::net::TcpStream>;
+
+/// TCP connection, read and written with the `futures` io traits
+#[cfg(all(feature = "smol-runtime", not(feature = "tokio-runtime")))]
+pub(crate) type TcpStream = async_net::TcpStream;
+
+/// Open a TCP connection to `host:port`
+pub(crate) async fn connect_tcp(host: &str, port: u16) -> Result<TcpStream> {
+    #[cfg(feature = "tokio-runtime")]
+    {
+        use tokio_util::compat::TokioAsyncReadCompatExt;
+        Ok(tokio::net::TcpStream::connect((host, port)).await?.compat())
+    }
+    #[cfg(all(feature = "smol-runtime", not(feature = "tokio-runtime")))]
+    {
+        Ok(async_net::TcpStream::connect((host, port)).await?)
+    }
+}
+
+/// Wait for `duration`
+pub(crate) async fn sleep(duration: Duration) {
+    #[cfg(feature = "tokio-runtime")]
+    tokio::time::sleep(duration).await;
+    #[cfg(all(feature = "smol-runtime", not(feature = "tokio-runtime")))]
+    async_io::Timer::after(duration).await;
+}
+
+/// Run a future of reqwest, which needs the tokio reactor, on the runtime
+pub(crate) async fn reqwest_compat<F: Future>(future: F) -> F::Output {
+    #[cfg(feature = "tokio-runtime")]
+    {
+        future.await
+    }
+    #[cfg(all(feature = "smol-runtime", not(feature = "tokio-runtime")))]
+    {
+        async_compat::Compat::new(future).await
+    }
+}
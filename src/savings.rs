@@ -1,7 +1,6 @@
 use crate::client::*;
 use crate::errors::*;
 use crate::rest_model::*;
-use crate::util::*;
 use std::collections::BTreeMap;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -10,24 +9,24 @@ pub struct CoinInfo {
     pub coin: String,
     pub deposit_all_enable: bool,
     #[serde(with = "string_or_float")]
-    pub free: f64,
+    pub free: Decimal,
     #[serde(with = "string_or_float")]
-    pub freeze: f64,
+    pub freeze: Decimal,
     #[serde(with = "string_or_float")]
-    pub ipoable: f64,
+    pub ipoable: Decimal,
     #[serde(with = "string_or_float")]
-    pub ipoing: f64,
+    pub ipoing: Decimal,
     pub is_legal_money: bool,
     #[serde(with = "string_or_float")]
-    pub locked: f64,
+    pub locked: Decimal,
     pub name: String,
     pub network_list: Vec<Network>,
     #[serde(with = "string_or_float")]
-    pub storage: f64,
+    pub storage: Decimal,
     pub trading: bool,
     pub withdraw_all_enable: bool,
     #[serde(with = "string_or_float")]
-    pub withdrawing: f64,
+    pub withdrawing: Decimal,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -52,9 +51,9 @@ pub struct Network {
     pub withdraw_desc: Option<String>,
     pub withdraw_enable: bool,
     #[serde(with = "string_or_float")]
-    pub withdraw_fee: f64,
+    pub withdraw_fee: Decimal,
     #[serde(with = "string_or_float")]
-    pub withdraw_min: f64,
+    pub withdraw_min: Decimal,
     // pub insert_time: Option<u64>, //commented out for now, because they are not inside the actual response (only the api doc example)
     // pub update_time: Option<u64>,
     pub withdraw_integer_multiple: Option<String>,
@@ -64,11 +63,11 @@ pub struct Network {
 #[serde(rename_all = "camelCase")]
 pub struct AssetDetail {
     #[serde(with = "string_or_float")]
-    pub min_withdraw_amount: f64,
+    pub min_withdraw_amount: Decimal,
     /// false if ALL of networks' are false
     pub deposit_status: bool,
     #[serde(with = "string_or_float")]
-    pub withdraw_fee: f64,
+    pub withdraw_fee: Decimal,
     /// false if ALL of networks' are false
     pub withdraw_status: bool,
     /// reason
@@ -99,7 +98,7 @@ impl Savings {
     /// assert!(coins.is_ok(), "{:?}", coins)
     /// ```
     pub async fn get_all_coins(&self) -> Result<Vec<CoinInfo>> {
-        let request = build_signed_request([("", "")], self.recv_window)?;
+        let request = self.client.build_signed_request([("", "")], self.recv_window)?;
         self.client
             .get_signed_d("/sapi/v1/capital/config/getall", request.as_str())
             .await
@@ -120,7 +119,7 @@ impl Savings {
             [("", "")]
         };
 
-        let request = build_signed_request(parameters, self.recv_window)?;
+        let request = self.client.build_signed_request(parameters, self.recv_window)?;
         self.client
             .get_signed_d("/sapi/v1/asset/assetDetail", request.as_str())
             .await
@@ -143,10 +142,10 @@ impl Savings {
     {
         let request = if let Some(network) = network {
             let parameters = [("network", network), ("coin", coin.as_ref())];
-            build_signed_request(parameters, self.recv_window)?
+            self.client.build_signed_request(parameters, self.recv_window)?
         } else {
             let parameters = [("coin", coin.as_ref())];
-            build_signed_request(parameters, self.recv_window)?
+            self.client.build_signed_request(parameters, self.recv_window)?
         };
 
         self.client
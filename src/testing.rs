@@ -0,0 +1,1016 @@
+//! In-process mock of the Binance APIs, for integration tests without network
+//!
+//! [`MockServer`] emulates the core spot and USD-M futures endpoints (ping, time, exchange
+//! information, depth, prices, account, orders and listen keys), the websocket streams and the spot
+//! websocket API, whose methods are served by the matching REST endpoints.
+//! Signed requests are verified as Binance does: the api key, the HMAC SHA256 signature with the
+//! secret key, and the timestamp within the receive window.
+//!
+//! Responses can be scripted with [`MockServer::enqueue`], and events are sent to the subscribers
+//! of a stream with [`MockServer::push_event`]. Clients are pointed at the server with
+//! [`MockServer::config`].
+//!
+//! # Examples
+//! ```rust
+//! use binance::{account::*, api::*, general::*, rest_model::*, testing::*};
+//! # tokio_test::block_on(async {
+//! let server = MockServer::start().await.unwrap();
+//! let conf = server.config();
+//! let account: Account = Binance::new_with_config(Some(MOCK_API_KEY.into()), Some(MOCK_SECRET_KEY.into()), &conf);
+//! let order = OrderRequest {
+//!     symbol: "BTCUSDT".to_string(),
+//!     quantity: Some("0.001".parse().unwrap()),
+//!     price: Some("19000.00".parse().unwrap()),
+//!     order_type: OrderType::Limit,
+//!     side: OrderSide::Buy,
+//!     time_in_force: Some(TimeInForce::GTC),
+//!     ..OrderRequest::default()
+//! };
+//! let transaction = account.place_order(order).await.unwrap();
+//! assert_eq!(transaction.status, OrderStatus::New);
+//! let request = server.requests().pop().unwrap();
+//! assert_eq!(request.param("symbol"), Some("BTCUSDT"));
+//!
+//! // Scripted responses are served before the emulated ones
+//! server.enqueue(
+//!     reqwest::Method::GET,
+//!     "/api/v3/ping",
+//!     MockResponse::error(503, -1001, "Internal error"),
+//! );
+//! let general: General = Binance::new_with_config(None, None, &conf);
+//! assert!(general.ping().await.is_err());
+//! assert!(general.ping().await.is_ok());
+//! # });
+//! ```
+
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+
+use futures::channel::mpsc;
+use futures::future::{self, Either};
+use futures::{SinkExt, StreamExt};
+use reqwest::Method;
+use ring::hmac;
+use serde_json::{json, Value};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::task::JoinHandle;
+use tokio_tungstenite::tungstenite::handshake::server::{Request, Response};
+use tokio_tungstenite::tungstenite::Message;
+
+use crate::config::Config;
+use crate::errors::*;
+use crate::util::get_timestamp;
+
+/// Api key accepted by the server until changed with [`MockServer::set_credentials`]
+pub static MOCK_API_KEY: &str = "mock_api_key";
+/// Secret key accepted by the server until changed with [`MockServer::set_credentials`]
+pub static MOCK_SECRET_KEY: &str = "mock_secret_key";
+
+static DEFAULT_PRICE: &str = "20000.00";
+static MAX_RECV_WINDOW: u64 = 60000;
+
+/// Endpoints requiring a signature
+static SIGNED_ENDPOINTS: &[&str] = &[
+    "/api/v3/order",
+    "/api/v3/order/test",
+    "/api/v3/openOrders",
+    "/api/v3/allOrders",
+    "/api/v3/account",
+    "/api/v3/myTrades",
+    "/fapi/v1/order",
+    "/fapi/v1/order/test",
+    "/fapi/v1/openOrders",
+    "/fapi/v1/allOpenOrders",
+    "/fapi/v2/account",
+    "/fapi/v2/balance",
+    "/fapi/v2/positionRisk",
+    "/fapi/v1/leverage",
+];
+
+/// REST endpoint serving each websocket API method
+static WS_API_METHODS: &[(&str, Method, &str)] = &[
+    ("ping", Method::GET, "/api/v3/ping"),
+    ("time", Method::GET, "/api/v3/time"),
+    ("order.place", Method::POST, "/api/v3/order"),
+    ("order.test", Method::POST, "/api/v3/order/test"),
+    ("order.cancel", Method::DELETE, "/api/v3/order"),
+    ("order.status", Method::GET, "/api/v3/order"),
+    ("openOrders.status", Method::GET, "/api/v3/openOrders"),
+    ("account.status", Method::GET, "/api/v3/account"),
+];
+
+/// Endpoints requiring an api key but no signature
+static USER_STREAM_ENDPOINTS: &[&str] = &["/api/v3/userDataStream", "/fapi/v1/listenKey"];
+
+/// A response served by the mock server
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MockResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl MockResponse {
+    /// Response with a json body
+    pub fn json(status: u16, body: &Value) -> Self {
+        MockResponse {
+            status,
+            headers: vec![],
+            body: body.to_string(),
+        }
+    }
+
+    /// Binance error response, `{"code": code, "msg": msg}`
+    pub fn error(status: u16, code: i32, msg: &str) -> Self { Self::json(status, &json!({ "code": code, "msg": msg })) }
+
+    /// Add a response header
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    fn ok(body: Value) -> Self { Self::json(200, &body) }
+}
+
+/// A request received by the mock server
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MockRequest {
+    pub method: Method,
+    pub path: String,
+    /// Query string and form body parameters, in order
+    pub params: Vec<(String, String)>,
+    /// Header names are lowercase
+    pub headers: Vec<(String, String)>,
+}
+
+impl MockRequest {
+    /// Value of the parameter `name`
+    pub fn param(&self, name: &str) -> Option<&str> {
+        self.params.iter().find(|(k, _)| k == name).map(|(_, v)| v.as_str())
+    }
+
+    /// Value of the header `name`
+    pub fn header(&self, name: &str) -> Option<&str> {
+        let name = name.to_lowercase();
+        self.headers.iter().find(|(k, _)| *k == name).map(|(_, v)| v.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Market {
+    Spot,
+    Futures,
+}
+
+#[derive(Debug, Clone)]
+struct MockOrder {
+    market: Market,
+    symbol: String,
+    order_id: u64,
+    client_order_id: String,
+    side: String,
+    order_type: String,
+    time_in_force: String,
+    price: String,
+    orig_qty: String,
+    executed_qty: String,
+    status: String,
+    time: u64,
+}
+
+struct Connection {
+    streams: HashSet<String>,
+    combined: bool,
+    sender: mpsc::UnboundedSender<Message>,
+}
+
+struct State {
+    api_key: String,
+    secret_key: String,
+    clock_offset: i64,
+    scripted: HashMap<(Method, String), VecDeque<MockResponse>>,
+    requests: Vec<MockRequest>,
+    prices: BTreeMap<String, String>,
+    orders: BTreeMap<u64, MockOrder>,
+    last_order_id: u64,
+    listen_keys: HashSet<String>,
+    connections: Vec<Connection>,
+    scripted_ws_api: HashMap<String, VecDeque<Value>>,
+    held_ws_api_replies: usize,
+    held: Vec<Value>,
+}
+
+impl State {
+    fn now(&self) -> u64 { (get_timestamp().unwrap_or_default() as i64 + self.clock_offset) as u64 }
+
+    fn price(&self, symbol: &str) -> String {
+        self.prices
+            .get(symbol)
+            .cloned()
+            .unwrap_or_else(|| DEFAULT_PRICE.to_string())
+    }
+}
+
+/// Mock Binance server listening on localhost, stopped when dropped
+pub struct MockServer {
+    state: Arc<Mutex<State>>,
+    http_addr: SocketAddr,
+    ws_addr: SocketAddr,
+    tasks: Vec<JoinHandle<()>>,
+}
+
+impl MockServer {
+    /// Start the server on random ports, within a tokio runtime
+    pub async fn start() -> Result<Self> {
+        let state = Arc::new(Mutex::new(State {
+            api_key: MOCK_API_KEY.to_string(),
+            secret_key: MOCK_SECRET_KEY.to_string(),
+            clock_offset: 0,
+            scripted: HashMap::new(),
+            requests: vec![],
+            prices: BTreeMap::new(),
+            orders: BTreeMap::new(),
+            last_order_id: 0,
+            listen_keys: HashSet::new(),
+            connections: vec![],
+            scripted_ws_api: HashMap::new(),
+            held_ws_api_replies: 0,
+            held: vec![],
+        }));
+        let http = TcpListener::bind("127.0.0.1:0").await?;
+        let ws = TcpListener::bind("127.0.0.1:0").await?;
+        let (http_addr, ws_addr) = (http.local_addr()?, ws.local_addr()?);
+        let tasks = vec![
+            tokio::spawn(accept_http(http, state.clone())),
+            tokio::spawn(accept_ws(ws, state.clone())),
+        ];
+        Ok(MockServer {
+            state,
+            http_addr,
+            ws_addr,
+            tasks,
+        })
+    }
+
+    /// Base url of the REST endpoints
+    pub fn rest_url(&self) -> String { format!("http://{}", self.http_addr) }
+
+    /// Base url of the websocket streams
+    pub fn ws_url(&self) -> String { format!("ws://{}", self.ws_addr) }
+
+    /// Configuration with the spot, websocket API and USD-M futures endpoints of the server
+    pub fn config(&self) -> Config {
+        Config::default()
+            .set_rest_api_endpoint(self.rest_url())
+            .set_ws_endpoint(self.ws_url())
+            .set_ws_api_endpoint(format!("{}/ws-api/v3", self.ws_url()))
+            .set_futures_rest_api_endpoint(self.rest_url())
+            .set_futures_ws_endpoint(self.ws_url())
+    }
+
+    /// Accept another api key and secret key for authenticated requests
+    pub fn set_credentials(&self, api_key: &str, secret_key: &str) {
+        let mut state = self.state.lock().unwrap();
+        state.api_key = api_key.to_string();
+        state.secret_key = secret_key.to_string();
+    }
+
+    /// Run the server clock `offset` milliseconds ahead of the local clock, or behind if negative
+    pub fn set_clock_offset(&self, offset: i64) { self.state.lock().unwrap().clock_offset = offset; }
+
+    /// Price of `symbol`, used by tickers and to fill market orders, 20000 by default
+    pub fn set_price(&self, symbol: &str, price: &str) {
+        self.state
+            .lock()
+            .unwrap()
+            .prices
+            .insert(symbol.to_string(), price.to_string());
+    }
+
+    /// Serve `response` once to the next request to `path`, after authentication
+    pub fn enqueue(&self, method: Method, path: &str, response: MockResponse) {
+        self.state
+            .lock()
+            .unwrap()
+            .scripted
+            .entry((method, path.to_string()))
+            .or_default()
+            .push_back(response);
+    }
+
+    /// Send `reply` as is once to the next websocket API request calling `method`, before authentication
+    pub fn enqueue_ws_api(&self, method: &str, reply: &Value) {
+        self.state
+            .lock()
+            .unwrap()
+            .scripted_ws_api
+            .entry(method.to_string())
+            .or_default()
+            .push_back(reply.clone());
+    }
+
+    /// Hold the replies to the next `count` websocket API requests and send them in reverse order once
+    /// all are ready, they are never sent if fewer requests arrive
+    pub fn hold_ws_api_replies(&self, count: usize) {
+        let mut state = self.state.lock().unwrap();
+        state.held_ws_api_replies = count;
+        state.held.clear();
+    }
+
+    /// Requests received so far, websocket API requests are recorded with their REST endpoint
+    pub fn requests(&self) -> Vec<MockRequest> { self.state.lock().unwrap().requests.clone() }
+
+    /// Send `event` to the connections subscribed to `stream`, returns the number of connections
+    pub fn push_event(&self, stream: &str, event: &Value) -> usize {
+        let mut state = self.state.lock().unwrap();
+        state.connections.retain(|c| !c.sender.is_closed());
+        let mut sent = 0;
+        for connection in state.connections.iter().filter(|c| c.streams.contains(stream)) {
+            let message = if connection.combined {
+                json!({ "stream": stream, "data": event })
+            } else {
+                event.clone()
+            };
+            if connection
+                .sender
+                .unbounded_send(Message::Text(message.to_string().into()))
+                .is_ok()
+            {
+                sent += 1;
+            }
+        }
+        sent
+    }
+
+    /// Close every websocket connection
+    pub fn disconnect_all(&self) {
+        for connection in self.state.lock().unwrap().connections.drain(..) {
+            connection.sender.close_channel();
+        }
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        for task in &self.tasks {
+            task.abort();
+        }
+    }
+}
+
+async fn accept_http(listener: TcpListener, state: Arc<Mutex<State>>) {
+    while let Ok((stream, _)) = listener.accept().await {
+        tokio::spawn(serve_http(stream, state.clone()));
+    }
+}
+
+/// Serve a single request, the connection is closed after the response
+async fn serve_http(mut stream: TcpStream, state: Arc<Mutex<State>>) {
+    let Some((method, target, headers, body)) = read_request(&mut stream).await else {
+        return;
+    };
+    let response = handle(&state, method, &target, headers, &body);
+    let reason = reqwest::StatusCode::from_u16(response.status)
+        .ok()
+        .and_then(|s| s.canonical_reason())
+        .unwrap_or("");
+    let mut head = format!(
+        "HTTP/1.1 {} {}\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n",
+        response.status,
+        reason,
+        response.body.len()
+    );
+    for (name, value) in &response.headers {
+        head.push_str(&format!("{name}: {value}\r\n"));
+    }
+    head.push_str("\r\n");
+    let _ = stream.write_all(head.as_bytes()).await;
+    let _ = stream.write_all(response.body.as_bytes()).await;
+    let _ = stream.shutdown().await;
+}
+
+type RawRequest = (Method, String, Vec<(String, String)>, String);
+
+async fn read_request(stream: &mut TcpStream) -> Option<RawRequest> {
+    let mut buffer = vec![];
+    let mut chunk = [0; 4096];
+    let head_end = loop {
+        if let Some(end) = buffer.windows(4).position(|w| w == b"\r\n\r\n") {
+            break end;
+        }
+        let read = stream.read(&mut chunk).await.ok().filter(|read| *read > 0)?;
+        buffer.extend_from_slice(&chunk[..read]);
+    };
+    let head = String::from_utf8_lossy(&buffer[..head_end]).to_string();
+    let mut lines = head.split("\r\n");
+    let mut request_line = lines.next()?.split(' ');
+    let method = Method::from_bytes(request_line.next()?.as_bytes()).ok()?;
+    let target = request_line.next()?.to_string();
+    let headers: Vec<(String, String)> = lines
+        .filter_map(|line| line.split_once(':'))
+        .map(|(name, value)| (name.trim().to_lowercase(), value.trim().to_string()))
+        .collect();
+    let content_length: usize = headers
+        .iter()
+        .find(|(name, _)| name == "content-length")
+        .and_then(|(_, value)| value.parse().ok())
+        .unwrap_or_default();
+    let mut body = buffer[head_end + 4..].to_vec();
+    while body.len() < content_length {
+        let read = stream.read(&mut chunk).await.ok().filter(|read| *read > 0)?;
+        body.extend_from_slice(&chunk[..read]);
+    }
+    Some((method, target, headers, String::from_utf8_lossy(&body).to_string()))
+}
+
+fn handle(
+    state: &Mutex<State>,
+    method: Method,
+    target: &str,
+    headers: Vec<(String, String)>,
+    body: &str,
+) -> MockResponse {
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    let params: Vec<(String, String)> = url::form_urlencoded::parse(query.as_bytes())
+        .into_owned()
+        .chain(url::form_urlencoded::parse(body.as_bytes()).into_owned())
+        .collect();
+    let request = MockRequest {
+        method: method.clone(),
+        path: path.to_string(),
+        params,
+        headers,
+    };
+    let mut state = state.lock().unwrap();
+    state.requests.push(request.clone());
+    if let Err(response) = authenticate(&state, &request, query, body) {
+        return response;
+    }
+    if let Some(response) = state
+        .scripted
+        .get_mut(&(method, path.to_string()))
+        .and_then(VecDeque::pop_front)
+    {
+        return response;
+    }
+    route(&mut state, &request)
+}
+
+/// Check the api key, signature and timestamp of authenticated endpoints
+fn authenticate(
+    state: &State,
+    request: &MockRequest,
+    query: &str,
+    body: &str,
+) -> std::result::Result<(), MockResponse> {
+    let signed = SIGNED_ENDPOINTS.contains(&request.path.as_str());
+    if !signed && !USER_STREAM_ENDPOINTS.contains(&request.path.as_str()) {
+        return Ok(());
+    }
+    if request.header("x-mbx-apikey") != Some(state.api_key.as_str()) {
+        return Err(MockResponse::error(
+            401,
+            -2015,
+            "Invalid API-key, IP, or permissions for action.",
+        ));
+    }
+    if !signed {
+        return Ok(());
+    }
+    let Some(signature) = request.param("signature") else {
+        return Err(missing_param("signature"));
+    };
+    // The signature is computed over the parameters as sent, query string then body
+    let payload = format!("{query}{body}");
+    let payload = payload
+        .split('&')
+        .filter(|param| !param.starts_with("signature="))
+        .collect::<Vec<_>>()
+        .join("&");
+    let key = hmac::Key::new(hmac::HMAC_SHA256, state.secret_key.as_bytes());
+    if hex::encode(hmac::sign(&key, payload.as_bytes()).as_ref()) != signature {
+        return Err(MockResponse::error(
+            400,
+            -1022,
+            "Signature for this request is not valid.",
+        ));
+    }
+    let Some(timestamp) = request.param("timestamp").and_then(|t| t.parse::<u64>().ok()) else {
+        return Err(missing_param("timestamp"));
+    };
+    let recv_window = match request.param("recvWindow") {
+        Some(recv_window) => recv_window.parse().map_err(|_| missing_param("recvWindow"))?,
+        None => 5000,
+    };
+    if recv_window > MAX_RECV_WINDOW {
+        return Err(MockResponse::error(400, -1131, "recvWindow must be less than 60000"));
+    }
+    let now = state.now();
+    if timestamp >= now + 1000 {
+        return Err(MockResponse::error(
+            400,
+            -1021,
+            "Timestamp for this request was 1000ms ahead of the server's time.",
+        ));
+    }
+    if now.saturating_sub(timestamp) > recv_window {
+        return Err(MockResponse::error(
+            400,
+            -1021,
+            "Timestamp for this request is outside of the recvWindow.",
+        ));
+    }
+    Ok(())
+}
+
+fn missing_param(name: &str) -> MockResponse {
+    MockResponse::error(
+        400,
+        -1102,
+        &format!("Mandatory parameter '{name}' was not sent, was empty/null, or malformed."),
+    )
+}
+
+fn route(state: &mut State, request: &MockRequest) -> MockResponse {
+    let method = request.method.clone();
+    match (method, request.path.as_str()) {
+        (Method::GET, "/api/v3/ping" | "/fapi/v1/ping") => MockResponse::ok(json!({})),
+        (Method::GET, "/api/v3/time" | "/fapi/v1/time") => MockResponse::ok(json!({ "serverTime": state.now() })),
+        (Method::GET, "/api/v3/exchangeInfo") => MockResponse::ok(spot_exchange_info(state.now())),
+        (Method::GET, "/fapi/v1/exchangeInfo") => MockResponse::ok(futures_exchange_info(state.now())),
+        (Method::GET, "/api/v3/depth" | "/fapi/v1/depth") => {
+            let price: f64 = state
+                .price(request.param("symbol").unwrap_or_default())
+                .parse()
+                .unwrap_or_default();
+            MockResponse::ok(json!({
+                "lastUpdateId": 1,
+                "E": state.now(),
+                "T": state.now(),
+                "bids": [[format!("{:.2}", price - 0.01), "1.00000000"]],
+                "asks": [[format!("{:.2}", price + 0.01), "1.00000000"]],
+            }))
+        }
+        (Method::GET, "/api/v3/ticker/price" | "/fapi/v2/ticker/price") => match request.param("symbol") {
+            Some(symbol) => {
+                MockResponse::ok(json!({ "symbol": symbol, "price": state.price(symbol), "time": state.now() }))
+            }
+            None => MockResponse::ok(
+                state
+                    .prices
+                    .iter()
+                    .map(|(symbol, price)| json!({ "symbol": symbol, "price": price }))
+                    .collect(),
+            ),
+        },
+        (Method::GET, "/api/v3/account") => MockResponse::ok(json!({
+            "makerCommission": 10,
+            "takerCommission": 10,
+            "buyerCommission": 0,
+            "sellerCommission": 0,
+            "canTrade": true,
+            "canWithdraw": true,
+            "canDeposit": true,
+            "accountType": "SPOT",
+            "balances": [
+                { "asset": "BTC", "free": "1.00000000", "locked": "0.00000000" },
+                { "asset": "USDT", "free": "10000.00000000", "locked": "0.00000000" },
+            ],
+            "permissions": ["SPOT"],
+            "updateTime": state.now(),
+        })),
+        (Method::POST, "/api/v3/order/test" | "/fapi/v1/order/test") => MockResponse::ok(json!({})),
+        (Method::POST, "/api/v3/order") => place_order(state, request, Market::Spot),
+        (Method::POST, "/fapi/v1/order") => place_order(state, request, Market::Futures),
+        (Method::GET, "/api/v3/order") => find_order(state, request, Market::Spot, false),
+        (Method::GET, "/fapi/v1/order") => find_order(state, request, Market::Futures, false),
+        (Method::DELETE, "/api/v3/order") => find_order(state, request, Market::Spot, true),
+        (Method::DELETE, "/fapi/v1/order") => find_order(state, request, Market::Futures, true),
+        (Method::GET, "/api/v3/openOrders") => open_orders(state, request, Market::Spot),
+        (Method::GET, "/fapi/v1/openOrders") => open_orders(state, request, Market::Futures),
+        (Method::POST, "/api/v3/userDataStream" | "/fapi/v1/listenKey") => {
+            let listen_key = format!("mock{:060}", state.listen_keys.len() + 1);
+            state.listen_keys.insert(listen_key.clone());
+            MockResponse::ok(json!({ "listenKey": listen_key }))
+        }
+        (Method::PUT | Method::DELETE, "/api/v3/userDataStream" | "/fapi/v1/listenKey") => {
+            let listen_key = request.param("listenKey").unwrap_or_default();
+            let known = if request.method == Method::DELETE {
+                state.listen_keys.remove(listen_key)
+            } else {
+                state.listen_keys.contains(listen_key)
+            };
+            if known {
+                MockResponse::ok(json!({}))
+            } else {
+                MockResponse::error(400, -1125, "This listenKey does not exist.")
+            }
+        }
+        _ => MockResponse::error(404, -1000, &format!("Unsupported endpoint {}", request.path)),
+    }
+}
+
+fn place_order(state: &mut State, request: &MockRequest, market: Market) -> MockResponse {
+    // Unset optional parameters may be sent empty
+    let param = |name| request.param(name).filter(|value| !value.is_empty());
+    let Some(symbol) = param("symbol") else {
+        return missing_param("symbol");
+    };
+    let Some(side) = param("side") else {
+        return missing_param("side");
+    };
+    let Some(order_type) = param("type") else {
+        return missing_param("type");
+    };
+    let Some(quantity) = param("quantity") else {
+        return missing_param("quantity");
+    };
+    let is_market = order_type == "MARKET";
+    let price = match (param("price"), is_market) {
+        (Some(price), _) => price.to_string(),
+        (None, true) => state.price(symbol),
+        (None, false) => return missing_param("price"),
+    };
+    state.last_order_id += 1;
+    let order = MockOrder {
+        market,
+        symbol: symbol.to_string(),
+        order_id: state.last_order_id,
+        client_order_id: request
+            .param("newClientOrderId")
+            .map(ToString::to_string)
+            .unwrap_or_else(|| format!("mock{}", state.last_order_id)),
+        side: side.to_string(),
+        order_type: order_type.to_string(),
+        time_in_force: param("timeInForce").unwrap_or("GTC").to_string(),
+        price,
+        orig_qty: quantity.to_string(),
+        // Market orders are filled immediately, others stay open
+        executed_qty: if is_market {
+            quantity.to_string()
+        } else {
+            "0".to_string()
+        },
+        status: if is_market { "FILLED" } else { "NEW" }.to_string(),
+        time: state.now(),
+    };
+    state.orders.insert(order.order_id, order.clone());
+    MockResponse::ok(order_json(&order))
+}
+
+fn find_order(state: &mut State, request: &MockRequest, market: Market, cancel: bool) -> MockResponse {
+    let order_id = request.param("orderId").and_then(|id| id.parse::<u64>().ok());
+    let client_order_id = request.param("origClientOrderId");
+    let order = state.orders.values_mut().find(|o| {
+        o.market == market
+            && Some(o.symbol.as_str()) == request.param("symbol")
+            && (Some(o.order_id) == order_id || Some(o.client_order_id.as_str()) == client_order_id)
+    });
+    let Some(order) = order else {
+        return match cancel {
+            true => MockResponse::error(400, -2011, "Unknown order sent."),
+            false => MockResponse::error(400, -2013, "Order does not exist."),
+        };
+    };
+    if cancel {
+        if order.status != "NEW" && order.status != "PARTIALLY_FILLED" {
+            return MockResponse::error(400, -2011, "Unknown order sent.");
+        }
+        order.status = "CANCELED".to_string();
+    }
+    MockResponse::ok(order_json(order))
+}
+
+fn open_orders(state: &State, request: &MockRequest, market: Market) -> MockResponse {
+    let symbol = request.param("symbol");
+    MockResponse::ok(
+        state
+            .orders
+            .values()
+            .filter(|o| o.market == market && (o.status == "NEW" || o.status == "PARTIALLY_FILLED"))
+            .filter(|o| symbol.is_none_or(|symbol| o.symbol == symbol))
+            .map(order_json)
+            .collect(),
+    )
+}
+
+/// Order in the union of the formats of the order endpoints
+fn order_json(order: &MockOrder) -> Value {
+    let price: f64 = order.price.parse().unwrap_or_default();
+    let executed: f64 = order.executed_qty.parse().unwrap_or_default();
+    let quote_qty = format!("{:.8}", price * executed);
+    let fills = if executed > 0.0 {
+        json!([{ "price": order.price, "qty": order.executed_qty, "commission": "0", "commissionAsset": "BNB" }])
+    } else {
+        json!([])
+    };
+    match order.market {
+        Market::Spot => json!({
+            "symbol": order.symbol,
+            "orderId": order.order_id,
+            "orderListId": -1,
+            "clientOrderId": order.client_order_id,
+            "origClientOrderId": order.client_order_id,
+            "transactTime": order.time,
+            "price": order.price,
+            "origQty": order.orig_qty,
+            "executedQty": order.executed_qty,
+            "cummulativeQuoteQty": quote_qty,
+            "status": order.status,
+            "timeInForce": order.time_in_force,
+            "type": order.order_type,
+            "side": order.side,
+            "stopPrice": "0",
+            "icebergQty": "0",
+            "time": order.time,
+            "updateTime": order.time,
+            "isWorking": true,
+            "origQuoteOrderQty": "0",
+            "fills": fills,
+        }),
+        Market::Futures => json!({
+            "symbol": order.symbol,
+            "orderId": order.order_id,
+            "clientOrderId": order.client_order_id,
+            "price": order.price,
+            "avgPrice": if executed > 0.0 { order.price.as_str() } else { "0" },
+            "origQty": order.orig_qty,
+            "executedQty": order.executed_qty,
+            "cumQty": order.executed_qty,
+            "cumQuote": quote_qty,
+            "status": order.status,
+            "timeInForce": order.time_in_force,
+            "type": order.order_type,
+            "origType": order.order_type,
+            "side": order.side,
+            "positionSide": "BOTH",
+            "reduceOnly": false,
+            "closePosition": false,
+            "stopPrice": "0",
+            "activatePrice": "0",
+            "priceRate": "0",
+            "workingType": "CONTRACT_PRICE",
+            "priceProtect": false,
+            "updateTime": order.time,
+        }),
+    }
+}
+
+fn rate_limits() -> Value {
+    json!([
+        { "rateLimitType": "REQUEST_WEIGHT", "interval": "MINUTE", "intervalNum": 1, "limit": 6000 },
+        { "rateLimitType": "ORDERS", "interval": "SECOND", "intervalNum": 10, "limit": 100 },
+        { "rateLimitType": "RAW_REQUESTS", "interval": "MINUTE", "intervalNum": 5, "limit": 61000 },
+    ])
+}
+
+fn spot_exchange_info(now: u64) -> Value {
+    json!({
+        "timezone": "UTC",
+        "serverTime": now,
+        "rateLimits": rate_limits(),
+        "exchangeFilters": [],
+        "symbols": [{
+            "symbol": "BTCUSDT",
+            "status": "TRADING",
+            "baseAsset": "BTC",
+            "baseAssetPrecision": 8,
+            "quoteAsset": "USDT",
+            "quotePrecision": 8,
+            "quoteAssetPrecision": 8,
+            "baseCommissionPrecision": 8,
+            "quoteCommissionPrecision": 8,
+            "orderTypes": ["LIMIT", "LIMIT_MAKER", "MARKET", "STOP_LOSS_LIMIT", "TAKE_PROFIT_LIMIT"],
+            "icebergAllowed": true,
+            "ocoAllowed": true,
+            "quoteOrderQtyMarketAllowed": true,
+            "isSpotTradingAllowed": true,
+            "isMarginTradingAllowed": true,
+            "filters": [
+                { "filterType": "PRICE_FILTER", "minPrice": "0.01000000", "maxPrice": "1000000.00000000", "tickSize": "0.01000000" },
+                { "filterType": "LOT_SIZE", "minQty": "0.00001000", "maxQty": "9000.00000000", "stepSize": "0.00001000" },
+                { "filterType": "ICEBERG_PARTS", "limit": 10 },
+                { "filterType": "MARKET_LOT_SIZE", "minQty": "0.00000000", "maxQty": "100.00000000", "stepSize": "0.00000000" },
+                {
+                    "filterType": "NOTIONAL",
+                    "minNotional": "5.00000000",
+                    "applyMinToMarket": true,
+                    "maxNotional": "9000000.00000000",
+                    "applyMaxToMarket": false,
+                    "avgPriceMins": 5
+                },
+            ],
+            "permissions": ["SPOT", "MARGIN"],
+        }],
+    })
+}
+
+fn futures_exchange_info(now: u64) -> Value {
+    json!({
+        "timezone": "UTC",
+        "serverTime": now,
+        "futuresType": "U_MARGINED",
+        "rateLimits": rate_limits(),
+        "exchangeFilters": [],
+        "assets": [],
+        "symbols": [{
+            "symbol": "BTCUSDT",
+            "pair": "BTCUSDT",
+            "contractType": "PERPETUAL",
+            "deliveryDate": 4133404800000u64,
+            "onboardDate": 1569398400000u64,
+            "status": "TRADING",
+            "maintMarginPercent": "2.5000",
+            "requiredMarginPercent": "5.0000",
+            "baseAsset": "BTC",
+            "quoteAsset": "USDT",
+            "marginAsset": "USDT",
+            "pricePrecision": 2,
+            "quantityPrecision": 3,
+            "baseAssetPrecision": 8,
+            "quotePrecision": 8,
+            "underlyingType": "COIN",
+            "underlyingSubType": [],
+            "settlePlan": 0,
+            "triggerProtect": "0.0500",
+            "filters": [
+                { "filterType": "PRICE_FILTER", "minPrice": "556.80", "maxPrice": "4529764", "tickSize": "0.10" },
+                { "filterType": "LOT_SIZE", "minQty": "0.001", "maxQty": "1000", "stepSize": "0.001" },
+                { "filterType": "MARKET_LOT_SIZE", "minQty": "0.001", "maxQty": "120", "stepSize": "0.001" },
+                { "filterType": "MAX_NUM_ORDERS", "limit": 200 },
+                { "filterType": "MIN_NOTIONAL", "notional": "100" },
+                { "filterType": "PERCENT_PRICE", "multiplierUp": "1.0500", "multiplierDown": "0.9500", "multiplierDecimal": "4" },
+            ],
+            "orderTypes": ["LIMIT", "MARKET", "STOP", "STOP_MARKET", "TAKE_PROFIT", "TAKE_PROFIT_MARKET", "TRAILING_STOP_MARKET"],
+            "timeInForce": ["GTC", "IOC", "FOK", "GTX"],
+        }],
+    })
+}
+
+async fn accept_ws(listener: TcpListener, state: Arc<Mutex<State>>) {
+    while let Ok((stream, _)) = listener.accept().await {
+        tokio::spawn(serve_ws(stream, state.clone()));
+    }
+}
+
+/// Serve a connection to `/ws/<stream>` or `/stream?streams=<stream>/<stream>`
+async fn serve_ws(stream: TcpStream, state: Arc<Mutex<State>>) {
+    let mut uri = String::new();
+    // The error type is the handshake's
+    #[allow(clippy::result_large_err)]
+    let callback = |request: &Request, response: Response| {
+        uri = request.uri().to_string();
+        Ok(response)
+    };
+    let Ok(socket) = tokio_tungstenite::accept_hdr_async(stream, callback).await else {
+        return;
+    };
+    let (combined, streams) = match uri.split_once("/stream?streams=") {
+        Some((_, streams)) => (true, streams),
+        None => (false, uri.split_once("/ws/").map_or("", |(_, stream)| stream)),
+    };
+    let streams: HashSet<String> = streams.split('/').filter(|s| !s.is_empty()).map(String::from).collect();
+    let ws_api = uri.starts_with("/ws-api/");
+    let (sender, mut outgoing) = mpsc::unbounded();
+    state.lock().unwrap().connections.push(Connection {
+        streams,
+        combined,
+        sender: sender.clone(),
+    });
+    let (mut sink, mut incoming) = socket.split();
+    loop {
+        match future::select(incoming.next(), outgoing.next()).await {
+            Either::Left((Some(Ok(Message::Text(text))), _)) if ws_api => {
+                for reply in handle_ws_api(&state, text.as_str()) {
+                    if sink.send(Message::Text(reply.to_string().into())).await.is_err() {
+                        return;
+                    }
+                }
+            }
+            Either::Left((Some(Ok(Message::Text(text))), _)) => {
+                if let Some(reply) = handle_command(&state, &sender, text.as_str()) {
+                    if sink.send(Message::Text(reply.to_string().into())).await.is_err() {
+                        break;
+                    }
+                }
+            }
+            Either::Left((Some(Ok(Message::Close(_))) | Some(Err(_)) | None, _)) => break,
+            Either::Left(_) => {}
+            Either::Right((Some(message), _)) => {
+                if sink.send(message).await.is_err() {
+                    break;
+                }
+            }
+            // Disconnected by the server
+            Either::Right((None, _)) => {
+                let _ = sink.close().await;
+                break;
+            }
+        }
+    }
+    sender.close_channel();
+}
+
+/// Replies to a websocket API request, none while they are held
+fn handle_ws_api(state: &Mutex<State>, text: &str) -> Vec<Value> {
+    let Ok(request) = serde_json::from_str::<Value>(text) else {
+        return vec![json!({ "id": null, "status": 400, "error": { "code": -1000, "msg": "Malformed request." } })];
+    };
+    let id = request.get("id").cloned().unwrap_or(Value::Null);
+    let method = request.get("method").and_then(Value::as_str).unwrap_or_default();
+    let scripted = state
+        .lock()
+        .unwrap()
+        .scripted_ws_api
+        .get_mut(method)
+        .and_then(VecDeque::pop_front);
+    let reply = match (scripted, WS_API_METHODS.iter().find(|(name, ..)| *name == method)) {
+        (Some(reply), _) => reply,
+        (None, Some((_, http_method, path))) => {
+            let params: BTreeMap<String, String> = request
+                .get("params")
+                .and_then(Value::as_object)
+                .map(|params| {
+                    params
+                        .iter()
+                        .map(|(k, v)| {
+                            // Numbers and booleans are signed as they are written
+                            let v = v.as_str().map_or_else(|| v.to_string(), str::to_string);
+                            (k.clone(), v)
+                        })
+                        .collect()
+                })
+                .unwrap_or_default();
+            // Sorted parameters are signed, as the query string of a REST request with the signature last
+            let mut query: Vec<String> = params
+                .iter()
+                .filter(|(k, _)| *k != "signature")
+                .map(|(k, v)| format!("{k}={v}"))
+                .collect();
+            query.extend(
+                params
+                    .get("signature")
+                    .map(|signature| format!("signature={signature}")),
+            );
+            let headers = params
+                .get("apiKey")
+                .map(|api_key| vec![("x-mbx-apikey".to_string(), api_key.clone())])
+                .unwrap_or_default();
+            let target = format!("{path}?{}", query.join("&"));
+            let response = handle(state, http_method.clone(), &target, headers, "");
+            let body: Value = serde_json::from_str(&response.body).unwrap_or_default();
+            if response.status == 200 {
+                json!({ "id": id, "status": 200, "result": body, "rateLimits": [] })
+            } else {
+                json!({ "id": id, "status": response.status, "error": body })
+            }
+        }
+        (None, None) => json!({ "id": id, "status": 400, "error": { "code": -1000, "msg": "Unknown method." } }),
+    };
+    let mut state = state.lock().unwrap();
+    if state.held_ws_api_replies == 0 {
+        return vec![reply];
+    }
+    state.held.push(reply);
+    if state.held.len() < state.held_ws_api_replies {
+        return vec![];
+    }
+    state.held_ws_api_replies = 0;
+    state.held.drain(..).rev().collect()
+}
+
+/// Reply to a SUBSCRIBE, UNSUBSCRIBE, LIST_SUBSCRIPTIONS, SET_PROPERTY or GET_PROPERTY request
+fn handle_command(state: &Mutex<State>, sender: &mpsc::UnboundedSender<Message>, text: &str) -> Option<Value> {
+    let command: Value = serde_json::from_str(text).ok()?;
+    let id = command.get("id").cloned().unwrap_or(Value::Null);
+    let params: Vec<String> = command
+        .get("params")
+        .and_then(Value::as_array)
+        .map(|params| params.iter().filter_map(Value::as_str).map(String::from).collect())
+        .unwrap_or_default();
+    let mut state = state.lock().unwrap();
+    let connection = state.connections.iter_mut().find(|c| c.sender.same_receiver(sender))?;
+    let result = match command.get("method").and_then(Value::as_str)? {
+        "SUBSCRIBE" => {
+            connection.streams.extend(params);
+            Value::Null
+        }
+        "UNSUBSCRIBE" => {
+            for stream in &params {
+                connection.streams.remove(stream);
+            }
+            Value::Null
+        }
+        "LIST_SUBSCRIPTIONS" => {
+            let mut streams: Vec<&String> = connection.streams.iter().collect();
+            streams.sort();
+            json!(streams)
+        }
+        "SET_PROPERTY" => Value::Null,
+        "GET_PROPERTY" => json!(connection.combined),
+        _ => return Some(json!({ "error": { "code": 2, "msg": "Invalid request" }, "id": id })),
+    };
+    Some(json!({ "result": result, "id": id }))
+}
@@ -0,0 +1,178 @@
+//! Server clock synchronization
+//!
+//! Signed requests are rejected with -1021 when their timestamp is outside of the receive window,
+//! which happens to every signed request once the local clock drifts. [`TimeSync`] keeps a smoothed
+//! estimate of the offset between the server clock and the local clock, from the server time and
+//! the round trip time of the request.
+//!
+//! Clients created with a configuration holding a [`TimeSync`] stamp signed requests with the server
+//! clock, synchronize it again when the interval elapsed and on -1021 errors, which are retried once.
+//!
+//! # Examples
+//! ```rust,no_run
+//! use binance::{account::*, api::*, config::*, general::*, time_sync::TimeSync};
+//! # tokio_test::block_on(async {
+//! let time_sync = TimeSync::default();
+//! let conf = Config::default().set_time_sync(time_sync.clone());
+//! let general: General = Binance::new_with_config(None, None, &conf);
+//! // Optional, the first signed request synchronizes the clock otherwise
+//! time_sync.sync(|| general.get_server_time()).await.unwrap();
+//! println!("offset {:?}ms, rtt {:?}", time_sync.offset(), time_sync.rtt());
+//! let account: Account = Binance::new_with_config(Some("api_key".into()), Some("secret_key".into()), &conf);
+//! let info = account.get_account().await;
+//! # });
+//! ```
+
+use std::fmt;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::errors::*;
+use crate::rest_model::ServerTime;
+use crate::util::get_timestamp;
+
+/// Weight of a new sample in the smoothed estimates
+static SMOOTHING: f64 = 0.25;
+
+/// Offset between the server clock and the local clock, shared by the clients of a configuration
+#[derive(Clone)]
+pub struct TimeSync {
+    state: Arc<Mutex<State>>,
+    interval: Duration,
+}
+
+#[derive(Default)]
+struct State {
+    /// Milliseconds to add to the local clock
+    offset: Option<f64>,
+    /// Round trip time in milliseconds
+    rtt: f64,
+    last_sync: Option<Instant>,
+    /// The next sample replaces the estimates instead of being smoothed
+    stale: bool,
+}
+
+impl TimeSync {
+    /// Synchronized again every `interval`
+    pub fn new(interval: Duration) -> Self {
+        TimeSync {
+            state: Arc::new(Mutex::new(State::default())),
+            interval,
+        }
+    }
+
+    /// Estimated milliseconds between the server clock and the local clock, if synchronized
+    pub fn offset(&self) -> Option<i64> { self.state.lock().unwrap().offset.map(|o| o.round() as i64) }
+
+    /// Estimated round trip time of the requests to the server, if synchronized
+    pub fn rtt(&self) -> Option<Duration> {
+        let state = self.state.lock().unwrap();
+        state.offset.map(|_| Duration::from_secs_f64(state.rtt / 1000.0))
+    }
+
+    /// Current time of the server clock in milliseconds, the local clock until synchronized
+    pub fn now(&self) -> Result<u64> {
+        let offset = self.offset().unwrap_or_default();
+        Ok((get_timestamp()? as i64 + offset) as u64)
+    }
+
+    /// Whether the clock was never synchronized, is stale or the interval elapsed
+    pub fn is_due(&self) -> bool {
+        let state = self.state.lock().unwrap();
+        state.stale || state.last_sync.is_none_or(|last| last.elapsed() >= self.interval)
+    }
+
+    /// Discard the estimates at the next synchronization, after the server rejected a timestamp
+    pub fn invalidate(&self) { self.state.lock().unwrap().stale = true; }
+
+    /// Synchronize with the server time returned by `server_time`, such as `General::get_server_time`
+    /// or the equivalents of the futures and options modules
+    pub async fn sync<F, Fut>(&self, server_time: F) -> Result<()>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<ServerTime>>,
+    {
+        let sent = get_timestamp()?;
+        let server_time = server_time().await?.server_time;
+        self.record(sent, server_time, get_timestamp()?);
+        Ok(())
+    }
+
+    /// Add a sample of the server time, for a request sent and received at the local times
+    pub fn record(&self, sent: u64, server_time: u64, received: u64) {
+        let rtt = received.saturating_sub(sent) as f64;
+        // The server time is assumed to be taken halfway through the round trip
+        let offset = server_time as f64 - (sent as f64 + rtt / 2.0);
+        let mut state = self.state.lock().unwrap();
+        match state.offset {
+            Some(previous) if !state.stale => {
+                state.offset = Some(previous + SMOOTHING * (offset - previous));
+                state.rtt += SMOOTHING * (rtt - state.rtt);
+            }
+            _ => {
+                state.offset = Some(offset);
+                state.rtt = rtt;
+            }
+        }
+        state.stale = false;
+        state.last_sync = Some(Instant::now());
+    }
+}
+
+impl Default for TimeSync {
+    /// Synchronized every 10 minutes
+    fn default() -> Self { Self::new(Duration::from_secs(600)) }
+}
+
+impl fmt::Debug for TimeSync {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.write_str("TimeSync") }
+}
+
+impl PartialEq for TimeSync {
+    fn eq(&self, other: &Self) -> bool { Arc::ptr_eq(&self.state, &other.state) }
+}
+
+impl Eq for TimeSync {}
+
+/// Server time endpoint of the API of `endpoint`, `/api/v3/time` is served by the spot API host
+pub(crate) fn time_endpoint(endpoint: &str) -> Option<&'static str> {
+    match endpoint.split('/').nth(1)? {
+        // Portfolio margin has no time endpoint and shares the clock of the spot API
+        "api" | "sapi" | "papi" => Some("/api/v3/time"),
+        "fapi" => Some("/fapi/v1/time"),
+        "dapi" => Some("/dapi/v1/time"),
+        "eapi" => Some("/eapi/v1/time"),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn smoothed_offset() {
+        let time_sync = TimeSync::default();
+        assert!(time_sync.is_due());
+        time_sync.record(1000, 2050, 1100);
+        assert_eq!(time_sync.offset(), Some(1000));
+        assert_eq!(time_sync.rtt(), Some(Duration::from_millis(100)));
+        assert!(!time_sync.is_due());
+        time_sync.record(1000, 1450, 1100);
+        assert_eq!(time_sync.offset(), Some(850));
+        time_sync.invalidate();
+        assert!(time_sync.is_due());
+        time_sync.record(1000, 1450, 1100);
+        assert_eq!(time_sync.offset(), Some(400));
+    }
+
+    #[test]
+    fn time_endpoints() {
+        assert_eq!(time_endpoint("/sapi/v1/margin/order"), Some("/api/v3/time"));
+        assert_eq!(time_endpoint("/papi/v1/um/order"), Some("/api/v3/time"));
+        assert_eq!(time_endpoint("/fapi/v1/order"), Some("/fapi/v1/time"));
+        assert_eq!(time_endpoint("/dapi/v1/order"), Some("/dapi/v1/time"));
+        assert_eq!(time_endpoint("/futures/data/openInterestHist"), None);
+    }
+}
@@ -0,0 +1,250 @@
+//! HTTP transport of the REST clients
+//!
+//! Requests are sent with [`reqwest`] by default. Any other [`Transport`] is used with
+//! [`Config::set_transport`](crate::config::Config::set_transport), such as an in-memory transport
+//! returning canned responses in tests.
+//!
+//! # Examples
+//! ```rust
+//! use binance::{api::*, config::*, errors::*, general::*, transport::*};
+//! use futures::future::{self, BoxFuture};
+//! use reqwest::StatusCode;
+//!
+//! struct Canned;
+//!
+//! impl Transport for Canned {
+//!     fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse>> {
+//!         assert!(request.url.ends_with("/api/v3/time"));
+//!         Box::pin(future::ready(Ok(HttpResponse {
+//!             status: StatusCode::OK,
+//!             headers: Default::default(),
+//!             body: br#"{"serverTime":1499827319559}"#.to_vec(),
+//!         })))
+//!     }
+//! }
+//!
+//! let conf = Config::default().set_transport(Canned);
+//! let general: General = Binance::new_with_config(None, None, &conf);
+//! let time = tokio_test::block_on(general.get_server_time()).unwrap();
+//! assert_eq!(time.server_time, 1499827319559);
+//! ```
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use futures::future::BoxFuture;
+use reqwest::header::HeaderMap;
+use reqwest::{Method, StatusCode};
+
+use crate::config::Config;
+use crate::errors::*;
+use crate::runtime;
+
+/// A request, parameters are in the query string of the url
+#[derive(Debug, Clone)]
+pub struct HttpRequest {
+    pub method: Method,
+    pub url: String,
+    pub headers: HeaderMap,
+}
+
+/// A response with its whole body
+#[derive(Debug, Clone)]
+pub struct HttpResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: Vec<u8>,
+}
+
+/// Metadata of a response, returned with the output of the calls run by
+/// [`with_meta`](crate::api::with_meta)
+#[derive(Debug, Clone)]
+pub struct ResponseMeta {
+    pub status: StatusCode,
+    /// Time between sending the request and receiving the whole response
+    pub latency: Duration,
+    /// Request weight used by the IP per interval, such as `1m`, from the `X-MBX-USED-WEIGHT-*` headers
+    pub used_weight: BTreeMap<String, u32>,
+    /// Orders placed by the account per interval, such as `10s`, from the `X-MBX-ORDER-COUNT-*` headers
+    pub order_count: BTreeMap<String, u32>,
+    /// Weight used by the IP on SAPI endpoints per interval, from the `X-SAPI-USED-IP-WEIGHT-*` headers
+    pub sapi_used_ip_weight: BTreeMap<String, u32>,
+    /// Weight used by the account on SAPI endpoints per interval, from the `X-SAPI-USED-UID-WEIGHT-*` headers
+    pub sapi_used_uid_weight: BTreeMap<String, u32>,
+    /// Identifier of the request on the server, from the `X-MBX-UUID` or `X-MBX-TRACEID` headers
+    pub trace_id: Option<String>,
+    pub headers: HeaderMap,
+}
+
+impl ResponseMeta {
+    pub fn new(response: &HttpResponse, latency: Duration) -> Self {
+        let counters = |prefix: &str| {
+            response
+                .headers
+                .iter()
+                .filter_map(|(name, value)| {
+                    let interval = name.as_str().strip_prefix(prefix)?;
+                    let count = value.to_str().ok()?.parse().ok()?;
+                    Some((interval.to_string(), count))
+                })
+                .collect()
+        };
+        let trace_id = ["x-mbx-uuid", "x-mbx-traceid"]
+            .iter()
+            .find_map(|name| response.headers.get(*name)?.to_str().ok())
+            .map(String::from);
+        ResponseMeta {
+            status: response.status,
+            latency,
+            used_weight: counters("x-mbx-used-weight-"),
+            order_count: counters("x-mbx-order-count-"),
+            sapi_used_ip_weight: counters("x-sapi-used-ip-weight-"),
+            sapi_used_uid_weight: counters("x-sapi-used-uid-weight-"),
+            trace_id,
+            headers: response.headers.clone(),
+        }
+    }
+}
+
+pub(crate) type MetaSlot = Arc<Mutex<Option<ResponseMeta>>>;
+
+thread_local! {
+    /// Slot of the call run by [`with_meta`](crate::api::with_meta) being polled on this thread
+    static META_SLOT: RefCell<Option<MetaSlot>> = const { RefCell::new(None) };
+}
+
+/// Future of a call returning the metadata of its last response with its output
+pub(crate) struct MetaScope<F> {
+    call: Pin<Box<F>>,
+    slot: MetaSlot,
+}
+
+impl<F: Future> MetaScope<F> {
+    pub(crate) fn new(call: F) -> Self {
+        MetaScope {
+            call: Box::pin(call),
+            slot: MetaSlot::default(),
+        }
+    }
+}
+
+impl<F: Future> Future for MetaScope<F> {
+    type Output = (F::Output, Option<ResponseMeta>);
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        let output = futures::ready!(in_meta_scope(&this.slot, || this.call.as_mut().poll(cx)));
+        Poll::Ready((output, this.slot.lock().unwrap().take()))
+    }
+}
+
+/// Run `f`, recording the metadata of the responses received meanwhile on this thread in `slot`
+pub(crate) fn in_meta_scope<R>(slot: &MetaSlot, f: impl FnOnce() -> R) -> R {
+    struct Restore(Option<MetaSlot>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) { META_SLOT.with(|current| *current.borrow_mut() = self.0.take()); }
+    }
+
+    let _restore = Restore(META_SLOT.with(|current| current.replace(Some(slot.clone()))));
+    f()
+}
+
+/// Record the metadata of a response for the call being polled, if run by [`with_meta`](crate::api::with_meta)
+pub(crate) fn record_meta(meta: impl FnOnce() -> ResponseMeta) {
+    if let Some(slot) = META_SLOT.with(|current| current.borrow().clone()) {
+        *slot.lock().unwrap() = Some(meta());
+    }
+}
+
+/// Sends the requests of the REST clients
+pub trait Transport: Send + Sync {
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse>>;
+}
+
+/// The default transport
+pub struct ReqwestTransport {
+    client: reqwest::Client,
+}
+
+impl ReqwestTransport {
+    /// Transport with the timeout and the proxy of the configuration
+    pub fn new(config: &Config) -> Result<Self> {
+        let mut builder = reqwest::ClientBuilder::new();
+        if let Some(timeout_secs) = config.timeout {
+            builder = builder.timeout(Duration::from_secs(timeout_secs))
+        }
+        if let Some(proxy) = &config.proxy {
+            builder = builder.proxy(proxy.reqwest_proxy()?)
+        }
+        Ok(ReqwestTransport {
+            client: builder.build()?,
+        })
+    }
+
+    /// Transport sending requests with an existing client, its settings replace the timeout and
+    /// the proxy of the configuration
+    pub fn with_client(client: reqwest::Client) -> Self { ReqwestTransport { client } }
+}
+
+impl Transport for ReqwestTransport {
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse>> {
+        Box::pin(runtime::reqwest_compat(async move {
+            let response = self
+                .client
+                .request(request.method, request.url)
+                .headers(request.headers)
+                .send()
+                .await?;
+            let status = response.status();
+            let headers = response.headers().clone();
+            let body = response.bytes().await?.to_vec();
+            Ok(HttpResponse { status, headers, body })
+        }))
+    }
+}
+
+/// Transport shared between clients, see [`Config::set_transport`](crate::config::Config::set_transport)
+#[derive(Clone)]
+pub struct SharedTransport(pub Arc<dyn Transport>);
+
+impl SharedTransport {
+    /// The transport of the configuration, or a new reqwest transport
+    pub(crate) fn for_config(config: &Config) -> Self {
+        if let Some(transport) = &config.transport {
+            return transport.clone();
+        }
+        match ReqwestTransport::new(config) {
+            Ok(transport) => SharedTransport(Arc::new(transport)),
+            Err(e) => SharedTransport(Arc::new(Unavailable(e.to_string()))),
+        }
+    }
+
+    pub fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse>> { self.0.send(request) }
+}
+
+impl fmt::Debug for SharedTransport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.write_str("SharedTransport") }
+}
+
+impl PartialEq for SharedTransport {
+    fn eq(&self, other: &Self) -> bool { Arc::ptr_eq(&self.0, &other.0) }
+}
+
+impl Eq for SharedTransport {}
+
+/// Clients are created infallibly, a transport that could not be built fails requests instead
+struct Unavailable(String);
+
+impl Transport for Unavailable {
+    fn send(&self, _request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse>> {
+        let msg = format!("HTTP transport unavailable: {}", self.0);
+        Box::pin(async move { Err(Error::Msg(msg)) })
+    }
+}
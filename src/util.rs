@@ -24,6 +24,15 @@ where
 pub fn build_signed_request(
     parameters: impl IntoIterator<Item = (impl AsRef<str>, impl AsRef<str>)>,
     recv_window: u64,
+) -> Result<String> {
+    build_signed_request_at(parameters, recv_window, get_timestamp()?)
+}
+
+/// Signed request stamped with `timestamp`, in milliseconds
+pub fn build_signed_request_at(
+    parameters: impl IntoIterator<Item = (impl AsRef<str>, impl AsRef<str>)>,
+    recv_window: u64,
+    timestamp: u64,
 ) -> Result<String> {
     let s = IntoIterator::into_iter([
         // Include recvWindow if window > 0
@@ -33,7 +42,7 @@ pub fn build_signed_request(
             None
         },
         // Always include timestamp
-        Some(("timestamp", get_timestamp()?)),
+        Some(("timestamp", timestamp)),
     ])
     .flatten()
     .map(|(k, v)| format!("{k}={v}"))
@@ -50,6 +59,14 @@ pub fn build_signed_request(
 }
 
 pub fn build_signed_request_p<S>(payload: S, recv_window: u64) -> Result<String>
+where
+    S: serde::Serialize,
+{
+    build_signed_request_p_at(payload, recv_window, get_timestamp()?)
+}
+
+/// Signed request stamped with `timestamp`, in milliseconds
+pub fn build_signed_request_p_at<S>(payload: S, recv_window: u64, timestamp: u64) -> Result<String>
 where
     S: serde::Serialize,
 {
@@ -63,7 +80,7 @@ where
             None
         },
         // Always include timestamp
-        Some(("timestamp", get_timestamp()?)),
+        Some(("timestamp", timestamp)),
     ])
     .flatten()
     .map(|(k, v)| format!("{k}={v}"))
@@ -1,16 +1,25 @@
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+use std::hash::{BuildHasher, Hasher};
+use std::marker::PhantomData;
+use std::pin::Pin;
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::task::{Context, Poll};
+use std::time::Duration;
 
-use futures::StreamExt;
-use serde_json::from_str;
-use tokio::net::TcpStream;
-use tokio_tungstenite::tungstenite::handshake::client::Response;
-use tokio_tungstenite::tungstenite::Message;
-use tokio_tungstenite::WebSocketStream;
-use tokio_tungstenite::{connect_async, MaybeTlsStream};
+use futures::channel::{mpsc, oneshot};
+use futures::future::{self, Either};
+use futures::stream::{SplitSink, SplitStream};
+use futures::{SinkExt, Stream, StreamExt};
+use serde_json::{from_str, Value};
 use url::Url;
 
-use crate::config::Config;
+use crate::config::{Config, Product};
 use crate::errors::*;
+use crate::rest_model::KlineInterval;
+use crate::runtime;
+use crate::ws_model::{QueryResult, ReconnectedEvent, StreamMethod, StreamRequest};
+use crate::ws_transport::{connect_websocket, redact_url, Message, WsConnection};
 
 pub static STREAM_ENDPOINT: &str = "stream";
 pub static WS_ENDPOINT: &str = "ws";
@@ -32,7 +41,14 @@ pub fn agg_trade_stream(symbol: &str) -> String { format!("{symbol}@aggTrade") }
 
 pub fn trade_stream(symbol: &str) -> String { format!("{symbol}@trade") }
 
-pub fn kline_stream(symbol: &str, interval: &str) -> String { format!("{symbol}@kline_{interval}") }
+/// Kline stream of `symbol`, the interval is a [`KlineInterval`] or its string such as `"1m"`
+pub fn kline_stream<I>(symbol: &str, interval: I) -> Result<String>
+where
+    I: TryInto<KlineInterval>,
+    Error: From<I::Error>,
+{
+    Ok(format!("{symbol}@kline_{}", interval.try_into()?))
+}
 
 pub fn book_ticker_stream(symbol: &str) -> String { format!("{symbol}@bookTicker") }
 
@@ -65,9 +81,170 @@ pub fn diff_book_depth_stream(symbol: &str, update_speed: u16) -> String { forma
 
 fn combined_stream(streams: Vec<String>) -> String { streams.join("/") }
 
+/// Url of a combined stream, events are wrapped in a `CombinedStreamEvent`
+pub(crate) fn combined_stream_url(ws_endpoint: &str, endpoints: Vec<String>) -> Result<Url> {
+    let mut url = Url::parse(ws_endpoint)?;
+    url.path_segments_mut()
+        .map_err(|_| Error::UrlParserError(url::ParseError::RelativeUrlWithoutBase))?
+        .push(STREAM_ENDPOINT);
+    url.set_query(Some(&format!("streams={}", combined_stream(endpoints))));
+    Ok(url)
+}
+
+/// Url of a raw stream
+pub(crate) fn stream_url(ws_endpoint: &str, endpoint: &str) -> Result<Url> {
+    Ok(Url::parse(&format!("{ws_endpoint}/{WS_ENDPOINT}/{endpoint}"))?)
+}
+
+/// Reconnection policy for websocket connections, see [`Config::set_ws_reconnect_policy`]
+///
+/// The first attempt is made as soon as the connection is lost, then delays grow exponentially from
+/// `initial_backoff` up to `max_backoff`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReconnectPolicy {
+    /// Delay after the first failed attempt
+    pub initial_backoff: Duration,
+    /// Maximum delay between two attempts
+    pub max_backoff: Duration,
+    /// Maximum number of consecutive attempts, retries forever if None, never reconnects if `Some(0)`
+    pub max_attempts: Option<u32>,
+    /// Randomize delays so that many clients do not reconnect all at once
+    pub jitter: bool,
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(60),
+            max_attempts: None,
+            jitter: true,
+        }
+    }
+}
+
+impl ReconnectPolicy {
+    /// Delay to wait after the nth failed attempt, starting at 1
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let exp = attempt.saturating_sub(1).min(31);
+        let delay = self.initial_backoff.saturating_mul(1 << exp).min(self.max_backoff);
+        if self.jitter {
+            // Keep half of the delay and randomize the other half
+            let half = delay / 2;
+            let random = RandomState::new().build_hasher().finish();
+            half + half.mul_f64((random % 1000) as f64 / 1000.0)
+        } else {
+            delay
+        }
+    }
+
+    /// Whether another attempt is allowed after `attempts` failed ones
+    pub fn can_retry(&self, attempts: u32) -> bool { self.max_attempts.is_none_or(|max| attempts < max) }
+}
+
+/// Reconnect to `url` following `policy`, returns the new socket and the number of attempts it took
+pub(crate) async fn reconnect(conf: &Config, url: &Url, policy: &ReconnectPolicy) -> Result<(WsConnection, u32)> {
+    let mut attempts = 0;
+    loop {
+        if attempts > 0 {
+            runtime::sleep(policy.backoff(attempts)).await;
+        }
+        attempts += 1;
+        match connect_websocket(conf, url.as_str()).await {
+            Ok(socket) => {
+                tracing::info!(url = %redact_url(url.as_str()), attempts, "websocket reconnected");
+                return Ok((socket, attempts));
+            }
+            Err(e) if !policy.can_retry(attempts) => {
+                return Err(Error::Msg(format!(
+                    "Failed to reconnect after {attempts} attempts: {e}"
+                )));
+            }
+            Err(_) => continue,
+        }
+    }
+}
+
+/// Subscription command sent from a [`SubscriptionHandle`] to the event loop
+struct Command {
+    method: StreamMethod,
+    params: Vec<Value>,
+    reply: oneshot::Sender<Result<QueryResult>>,
+}
+
+/// Request sent on the connection and waiting for its reply
+struct PendingRequest {
+    method: StreamMethod,
+    params: Vec<Value>,
+    /// None for requests issued internally, such as resubscriptions after a reconnection
+    reply: Option<oneshot::Sender<Result<QueryResult>>>,
+}
+
+/// Manages the streams of a running [`WebSockets`] event loop, obtained with [`WebSockets::subscriptions`]
+///
+/// Requests are sent by the event loop, so futures returned by the handle only complete while it runs.
+#[derive(Clone)]
+pub struct SubscriptionHandle {
+    commands: mpsc::UnboundedSender<Command>,
+}
+
+impl SubscriptionHandle {
+    async fn request(&self, method: StreamMethod, params: Vec<Value>) -> Result<QueryResult> {
+        let (reply, response) = oneshot::channel();
+        self.commands
+            .unbounded_send(Command { method, params, reply })
+            .map_err(|_| Error::Msg("Websocket event loop is gone".to_string()))?;
+        response
+            .await
+            .map_err(|_| Error::Msg("Websocket event loop is gone".to_string()))?
+    }
+
+    /// Subscribe to additional streams
+    pub async fn subscribe(&self, streams: Vec<String>) -> Result<QueryResult> {
+        self.request(StreamMethod::Subscribe, streams.into_iter().map(Value::from).collect())
+            .await
+    }
+
+    /// Unsubscribe from streams
+    pub async fn unsubscribe(&self, streams: Vec<String>) -> Result<QueryResult> {
+        self.request(
+            StreamMethod::Unsubscribe,
+            streams.into_iter().map(Value::from).collect(),
+        )
+        .await
+    }
+
+    /// Streams the connection is subscribed to, as reported by the server
+    pub async fn list_subscriptions(&self) -> Result<Vec<String>> {
+        let reply = self.request(StreamMethod::ListSubscriptions, vec![]).await?;
+        Ok(serde_json::from_value(reply.result.unwrap_or_default())?)
+    }
+
+    /// Set a connection property, currently only `combined`
+    pub async fn set_property(&self, property: &str, value: bool) -> Result<QueryResult> {
+        self.request(StreamMethod::SetProperty, vec![property.into(), value.into()])
+            .await
+    }
+
+    /// Get a connection property, currently only `combined`
+    pub async fn get_property(&self, property: &str) -> Result<QueryResult> {
+        self.request(StreamMethod::GetProperty, vec![property.into()]).await
+    }
+}
+
 pub struct WebSockets<'a, WE> {
-    pub socket: Option<(WebSocketStream<MaybeTlsStream<TcpStream>>, Response)>,
+    pub socket: Option<WsConnection>,
     handler: Box<dyn FnMut(WE) -> Result<()> + 'a + Send>,
+    reconnect_handler: Option<Box<dyn FnMut(ReconnectedEvent) -> Result<()> + 'a + Send>>,
+    url: Option<Url>,
+    /// Streams of the connection url
+    url_streams: Vec<String>,
+    /// Streams currently subscribed to
+    streams: Vec<String>,
+    commands: mpsc::UnboundedReceiver<Command>,
+    commands_tx: mpsc::UnboundedSender<Command>,
+    pending: HashMap<i64, PendingRequest>,
+    last_request_id: i64,
     conf: Config,
 }
 
@@ -89,80 +266,288 @@ impl<'a, WE: serde::de::DeserializeOwned> WebSockets<'a, WE> {
     where
         Callback: FnMut(WE) -> Result<()> + 'a + Send,
     {
+        let (commands_tx, commands) = mpsc::unbounded();
         WebSockets {
             socket: None,
             handler: Box::new(handler),
+            reconnect_handler: None,
+            url: None,
+            url_streams: vec![],
+            streams: vec![],
+            commands,
+            commands_tx,
+            pending: HashMap::new(),
+            last_request_id: 0,
             conf,
         }
     }
 
+    /// Notify `handler` every time the connection is re-established following the
+    /// [`ReconnectPolicy`], events sent while disconnected are lost so derived state such as an order
+    /// book has to be resynchronized
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{config::Config, websockets::*, ws_model::WebsocketEvent};
+    /// let conf = Config::default().set_ws_reconnect_policy(ReconnectPolicy::default());
+    /// let web_socket: WebSockets<'_, WebsocketEvent> = WebSockets::new_with_options(|event| Ok(()), conf)
+    ///     .on_reconnect(|reconnected| {
+    ///         println!("reconnected after {} attempts", reconnected.attempts);
+    ///         Ok(())
+    ///     });
+    /// ```
+    pub fn on_reconnect<Callback>(mut self, handler: Callback) -> Self
+    where
+        Callback: FnMut(ReconnectedEvent) -> Result<()> + 'a + Send,
+    {
+        self.reconnect_handler = Some(Box::new(handler));
+        self
+    }
+
+    /// Handle to subscribe and unsubscribe streams while the event loop runs,
+    /// subscriptions are restored when reconnecting
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{websockets::*, ws_model::{CombinedStreamEvent, WebsocketEvent}};
+    /// use std::sync::atomic::AtomicBool;
+    /// # tokio_test::block_on(async {
+    /// let running = AtomicBool::new(true);
+    /// let mut web_socket: WebSockets<'_, CombinedStreamEvent<WebsocketEvent>> = WebSockets::new(|event| Ok(()));
+    /// web_socket.connect_multiple(vec![trade_stream("btcusdt")]).await.unwrap();
+    /// let subscriptions = web_socket.subscriptions();
+    /// let rotate = async {
+    ///     subscriptions.subscribe(vec![trade_stream("ethusdt")]).await.unwrap();
+    ///     subscriptions.unsubscribe(vec![trade_stream("btcusdt")]).await.unwrap();
+    /// };
+    /// let (_, result) = futures::join!(rotate, web_socket.event_loop(&running));
+    /// # });
+    /// ```
+    pub fn subscriptions(&self) -> SubscriptionHandle {
+        SubscriptionHandle {
+            commands: self.commands_tx.clone(),
+        }
+    }
+
+    /// Streams currently subscribed to
+    pub fn streams(&self) -> &[String] { &self.streams }
+
+    pub fn conf(&self) -> &Config { &self.conf }
+
     /// Connect to multiple websocket endpoints
     /// N.B: WE has to be CombinedStreamEvent
     pub async fn connect_multiple(&mut self, endpoints: Vec<String>) -> Result<()> {
-        let mut url = Url::parse(&self.conf.ws_endpoint)?;
-        url.path_segments_mut()
-            .map_err(|_| Error::UrlParserError(url::ParseError::RelativeUrlWithoutBase))?
-            .push(STREAM_ENDPOINT);
-        url.set_query(Some(&format!("streams={}", combined_stream(endpoints))));
+        self.conf.environment.check(Product::Spot)?;
+        let url = combined_stream_url(&self.conf.ws_endpoint, endpoints.clone())?;
 
-        self.handle_connect(url).await
+        self.handle_connect(url, endpoints).await
     }
 
     /// Connect to a websocket endpoint
     pub async fn connect(&mut self, endpoint: &str) -> Result<()> {
-        let wss: String = format!("{}/{}/{}", self.conf.ws_endpoint, WS_ENDPOINT, endpoint);
-        let url = Url::parse(&wss)?;
+        self.conf.environment.check(Product::Spot)?;
+        let url = stream_url(&self.conf.ws_endpoint, endpoint)?;
 
-        self.handle_connect(url).await
+        self.handle_connect(url, vec![endpoint.to_string()]).await
     }
 
     /// Connect to a futures websocket endpoint
     pub async fn connect_futures(&mut self, endpoint: &str) -> Result<()> {
-        let wss: String = format!("{}/{}/{}", self.conf.futures_ws_endpoint, WS_ENDPOINT, endpoint);
-        let url = Url::parse(&wss)?;
+        self.conf.environment.check(Product::UsdMFutures)?;
+        let url = stream_url(&self.conf.futures_ws_endpoint, endpoint)?;
 
-        self.handle_connect(url).await
+        self.handle_connect(url, vec![endpoint.to_string()]).await
     }
 
-    async fn handle_connect(&mut self, url: Url) -> Result<()> {
-        match connect_async(url.as_str()).await {
-            Ok(answer) => {
-                self.socket = Some(answer);
+    pub(crate) async fn handle_connect(&mut self, url: Url, streams: Vec<String>) -> Result<()> {
+        match connect_websocket(&self.conf, url.as_str()).await {
+            Ok(socket) => {
+                self.socket = Some(socket);
+                self.url = Some(url);
+                self.url_streams = streams.clone();
+                self.streams = streams;
+                self.fail_pending();
                 Ok(())
             }
             Err(e) => Err(Error::Msg(format!("Error during handshake {e}"))),
         }
     }
 
+    /// Reconnect to the last url if a policy is configured, otherwise fail with `cause`
+    async fn handle_disconnect(&mut self, cause: Error) -> Result<()> {
+        tracing::warn!(url = ?self.url.as_ref().map(|url| redact_url(url.as_str())), error = %cause, "websocket disconnected");
+        self.fail_pending();
+        let (Some(policy), Some(url)) = (self.conf.ws_reconnect_policy.clone(), self.url.clone()) else {
+            return Err(cause);
+        };
+        if !policy.can_retry(0) {
+            return Err(cause);
+        }
+        let (socket, attempts) = reconnect(&self.conf, &url, &policy).await?;
+        self.socket = Some(socket);
+        self.restore_subscriptions().await?;
+        let event = ReconnectedEvent {
+            attempts,
+            streams: self.streams.clone(),
+        };
+        match self.reconnect_handler.as_mut() {
+            Some(handler) => handler(event),
+            None => {
+                tracing::warn!(
+                    attempts,
+                    "websocket reconnected without reconnect handler, events may have been missed"
+                );
+                Ok(())
+            }
+        }
+    }
+
+    /// Apply the changes made to the streams of the url since the initial connection
+    async fn restore_subscriptions(&mut self) -> Result<()> {
+        let removed: Vec<Value> = self
+            .url_streams
+            .iter()
+            .filter(|s| !self.streams.contains(s))
+            .map(|s| Value::from(s.as_str()))
+            .collect();
+        let added: Vec<Value> = self
+            .streams
+            .iter()
+            .filter(|s| !self.url_streams.contains(s))
+            .map(|s| Value::from(s.as_str()))
+            .collect();
+        if !removed.is_empty() {
+            self.send_request(StreamMethod::Unsubscribe, removed, None).await?;
+        }
+        if !added.is_empty() {
+            self.send_request(StreamMethod::Subscribe, added, None).await?;
+        }
+        Ok(())
+    }
+
+    async fn send_request(
+        &mut self,
+        method: StreamMethod,
+        params: Vec<Value>,
+        reply: Option<oneshot::Sender<Result<QueryResult>>>,
+    ) -> Result<()> {
+        let Some(ref mut socket) = self.socket else {
+            return Err(Error::Msg("Not connected".to_string()));
+        };
+        self.last_request_id += 1;
+        let id = self.last_request_id;
+        let request = serde_json::to_string(&StreamRequest {
+            method,
+            params: params.clone(),
+            id,
+        })?;
+        socket.send(Message::Text(request)).await?;
+        self.pending.insert(id, PendingRequest { method, params, reply });
+        Ok(())
+    }
+
+    /// Requests sent on a closed connection will never be answered
+    fn fail_pending(&mut self) {
+        for (_, pending) in self.pending.drain() {
+            if let Some(reply) = pending.reply {
+                let _ = reply.send(Err(Error::Msg("Disconnected before a reply was received".to_string())));
+            }
+        }
+    }
+
+    /// Complete the pending request `msg` replies to, returns false if it is not a reply
+    fn handle_reply(&mut self, msg: &str) -> bool {
+        let Ok(reply) = from_str::<QueryResult>(msg) else {
+            return false;
+        };
+        let Some(pending) = self.pending.remove(&reply.id) else {
+            return false;
+        };
+        let result = match &reply.error {
+            Some(error) => Err(Error::Msg(format!(
+                "{:?} rejected with code {}: {}",
+                pending.method, error.code, error.msg
+            ))),
+            None => {
+                let streams = pending.params.iter().filter_map(|p| p.as_str());
+                match pending.method {
+                    StreamMethod::Subscribe => {
+                        for stream in streams {
+                            if !self.streams.iter().any(|s| s == stream) {
+                                self.streams.push(stream.to_string());
+                            }
+                        }
+                    }
+                    StreamMethod::Unsubscribe => {
+                        let streams: Vec<&str> = streams.collect();
+                        self.streams.retain(|s| !streams.contains(&s.as_str()));
+                    }
+                    _ => {}
+                }
+                Ok(reply)
+            }
+        };
+        if let Some(tx) = pending.reply {
+            let _ = tx.send(result);
+        }
+        true
+    }
+
     /// Disconnect from the endpoint
     pub async fn disconnect(&mut self) -> Result<()> {
         if let Some(ref mut socket) = self.socket {
-            socket.0.close(None).await?;
+            socket.close().await?;
+            tracing::debug!(url = ?self.url.as_ref().map(|url| redact_url(url.as_str())), "websocket closed");
             Ok(())
         } else {
             Err(Error::Msg("Not able to close the connection".to_string()))
         }
     }
 
-    pub fn socket(&self) -> &Option<(WebSocketStream<MaybeTlsStream<TcpStream>>, Response)> { &self.socket }
+    pub fn socket(&self) -> &Option<WsConnection> { &self.socket }
 
     pub async fn event_loop(&mut self, running: &AtomicBool) -> Result<()> {
         while running.load(Ordering::Relaxed) {
-            if let Some((ref mut socket, _)) = self.socket {
-                // TODO: return error instead of panic?
-                let message = socket.next().await.unwrap()?;
+            if let Some(ref mut socket) = self.socket {
+                let next = match future::select(socket.next(), self.commands.next()).await {
+                    Either::Left((message, _)) => Ok(message),
+                    Either::Right((command, _)) => Err(command),
+                };
+                let message = match next {
+                    Ok(Some(Ok(message))) => message,
+                    Ok(Some(Err(e))) => {
+                        self.handle_disconnect(e).await?;
+                        continue;
+                    }
+                    Ok(None) => {
+                        self.handle_disconnect(Error::Msg("Stream closed".to_string())).await?;
+                        continue;
+                    }
+                    Err(Some(Command { method, params, reply })) => {
+                        if let Err(e) = self.send_request(method, params, None).await {
+                            let _ = reply.send(Err(e));
+                        } else if let Some(pending) = self.pending.get_mut(&self.last_request_id) {
+                            pending.reply = Some(reply);
+                        }
+                        continue;
+                    }
+                    // A sender is kept by self, the channel is never closed
+                    Err(None) => continue,
+                };
 
                 match message {
                     Message::Text(msg) => {
                         if msg.is_empty() {
                             return Ok(());
                         }
-                        let event: WE = from_str(msg.as_str())?;
+                        if !self.pending.is_empty() && self.handle_reply(&msg) {
+                            continue;
+                        }
+                        let event: WE = from_str(&msg).inspect_err(|e| parse_failure(e, &msg))?;
                         (self.handler)(event)?;
                     }
-                    Message::Ping(_) | Message::Pong(_) | Message::Binary(_) | Message::Frame(_) => {}
+                    Message::Ping(_) | Message::Pong(_) | Message::Binary(_) => {}
                     Message::Close(e) => {
-                        return Err(Error::Msg(format!("Disconnected {e:?}")));
+                        self.handle_disconnect(Error::Msg(format!("Disconnected {e:?}")))
+                            .await?;
                     }
                 }
             }
@@ -170,3 +555,196 @@ impl<'a, WE: serde::de::DeserializeOwned> WebSockets<'a, WE> {
         Ok(())
     }
 }
+
+/// Websocket connection consumed as a [`Stream`] of events, an alternative to the callback based [`WebSockets`]
+///
+/// Ping, pong and binary frames are skipped, the stream ends when the server closes the connection.
+/// Dropping the stream closes the connection.
+/// # Examples
+/// ```rust,no_run
+/// use binance::{config::*, websockets::*, ws_model::WebsocketEvent};
+/// use futures::StreamExt;
+/// # tokio_test::block_on(async {
+/// let mut stream: WebsocketStream<WebsocketEvent> =
+///     WebsocketStream::connect(&Config::default(), &agg_trade_stream("ethbtc")).await.unwrap();
+/// while let Some(event) = stream.next().await {
+///     println!("{:?}", event);
+/// }
+/// # });
+/// ```
+pub struct WebsocketStream<WE> {
+    socket: WsConnection,
+    _event: PhantomData<fn() -> WE>,
+}
+
+impl<WE: serde::de::DeserializeOwned> WebsocketStream<WE> {
+    /// Connect to a websocket endpoint
+    pub async fn connect(conf: &Config, endpoint: &str) -> Result<Self> {
+        conf.environment.check(Product::Spot)?;
+        Self::connect_url(conf, stream_url(&conf.ws_endpoint, endpoint)?).await
+    }
+
+    /// Connect to multiple websocket endpoints
+    /// N.B: WE has to be CombinedStreamEvent
+    pub async fn connect_multiple(conf: &Config, endpoints: Vec<String>) -> Result<Self> {
+        conf.environment.check(Product::Spot)?;
+        Self::connect_url(conf, combined_stream_url(&conf.ws_endpoint, endpoints)?).await
+    }
+
+    /// Connect to a futures websocket endpoint
+    pub async fn connect_futures(conf: &Config, endpoint: &str) -> Result<Self> {
+        conf.environment.check(Product::UsdMFutures)?;
+        Self::connect_url(conf, stream_url(&conf.futures_ws_endpoint, endpoint)?).await
+    }
+
+    /// Connect to a COIN-M futures websocket endpoint
+    pub async fn connect_coin_futures(conf: &Config, endpoint: &str) -> Result<Self> {
+        conf.environment.check(Product::CoinMFutures)?;
+        Self::connect_url(conf, stream_url(&conf.coin_futures_ws_endpoint, endpoint)?).await
+    }
+
+    async fn connect_url(conf: &Config, url: Url) -> Result<Self> {
+        match connect_websocket(conf, url.as_str()).await {
+            Ok(socket) => Ok(Self {
+                socket,
+                _event: PhantomData,
+            }),
+            Err(e) => Err(Error::Msg(format!("Error during handshake {e}"))),
+        }
+    }
+
+    /// Send a frame to the server
+    pub async fn send(&mut self, message: Message) -> Result<()> { self.socket.send(message).await }
+
+    /// Close the connection
+    pub async fn close(&mut self) -> Result<()> { self.socket.close().await }
+
+    /// Split into a sink sending frames and a stream of events, so that both can be used from different tasks
+    pub fn split(self) -> (WebsocketSink, WebsocketEvents<WE>) {
+        let (sink, stream) = self.socket.split();
+        (WebsocketSink { sink }, WebsocketEvents {
+            stream,
+            _event: PhantomData,
+        })
+    }
+}
+
+impl<WE: serde::de::DeserializeOwned> Stream for WebsocketStream<WE> {
+    type Item = Result<WE>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        poll_event(Pin::new(&mut self.socket), cx)
+    }
+}
+
+/// Sending half of a split [`WebsocketStream`]
+pub struct WebsocketSink {
+    sink: SplitSink<WsConnection, Message>,
+}
+
+impl WebsocketSink {
+    /// Send a frame to the server
+    pub async fn send(&mut self, message: Message) -> Result<()> { self.sink.send(message).await }
+
+    /// Close the connection
+    pub async fn close(&mut self) -> Result<()> { self.sink.close().await }
+}
+
+/// Receiving half of a split [`WebsocketStream`]
+pub struct WebsocketEvents<WE> {
+    stream: SplitStream<WsConnection>,
+    _event: PhantomData<fn() -> WE>,
+}
+
+impl<WE: serde::de::DeserializeOwned> Stream for WebsocketEvents<WE> {
+    type Item = Result<WE>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        poll_event(Pin::new(&mut self.stream), cx)
+    }
+}
+
+/// Poll messages until an event can be returned
+fn poll_event<WE, S>(mut stream: Pin<&mut S>, cx: &mut Context<'_>) -> Poll<Option<Result<WE>>>
+where
+    WE: serde::de::DeserializeOwned,
+    S: Stream<Item = Result<Message>>,
+{
+    loop {
+        match futures::ready!(stream.as_mut().poll_next(cx)) {
+            Some(Ok(Message::Text(msg))) => {
+                if msg.is_empty() {
+                    continue;
+                }
+                return Poll::Ready(Some(from_str(&msg).map_err(|e| {
+                    parse_failure(&e, &msg);
+                    Error::from(e)
+                })));
+            }
+            Some(Ok(Message::Ping(_) | Message::Pong(_) | Message::Binary(_))) => {}
+            Some(Ok(Message::Close(_))) | None => return Poll::Ready(None),
+            Some(Err(e)) => return Poll::Ready(Some(Err(e))),
+        }
+    }
+}
+
+fn parse_failure(error: &serde_json::Error, message: &str) {
+    tracing::warn!(%error, message, "failed to parse websocket event");
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn policy(jitter: bool) -> ReconnectPolicy {
+        ReconnectPolicy {
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(1),
+            max_attempts: None,
+            jitter,
+        }
+    }
+
+    #[test]
+    fn backoff_grows_up_to_max() {
+        let policy = policy(false);
+        let delays: Vec<u64> = (1..=6).map(|n| policy.backoff(n).as_millis() as u64).collect();
+        assert_eq!(delays, vec![100, 200, 400, 800, 1000, 1000]);
+        assert_eq!(policy.backoff(u32::MAX), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn backoff_jitter_bounds() {
+        let jittered = policy(true);
+        let plain = policy(false);
+        for attempt in 1..=10 {
+            let delay = plain.backoff(attempt);
+            for _ in 0..20 {
+                let backoff = jittered.backoff(attempt);
+                assert!(backoff >= delay / 2 && backoff <= delay, "{backoff:?} out of {delay:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn kline_streams() {
+        assert_eq!(kline_stream("btcusdt", "1m").unwrap(), "btcusdt@kline_1m");
+        assert_eq!(
+            kline_stream("btcusdt", KlineInterval::OneMonth).unwrap(),
+            "btcusdt@kline_1M"
+        );
+        assert!(kline_stream("btcusdt", "2m").is_err());
+    }
+
+    #[test]
+    fn max_attempts() {
+        let mut policy = policy(false);
+        assert!(policy.can_retry(u32::MAX - 1));
+        policy.max_attempts = Some(0);
+        assert!(!policy.can_retry(0));
+        policy.max_attempts = Some(3);
+        assert!(policy.can_retry(0));
+        assert!(policy.can_retry(2));
+        assert!(!policy.can_retry(3));
+    }
+}
@@ -0,0 +1,343 @@
+//! Client for the spot websocket API
+//!
+//! Requests are sent over a single persistent connection and replies are matched to requests with
+//! their id, so requests can be issued concurrently from clones of the same [`WsApiClient`].
+//! There is no background task, the connection is read by whichever request is waiting for its reply.
+//!
+//! # Examples
+//! ```rust,no_run
+//! use binance::{account::OrderRequest, config::Config, rest_model::*, ws_api::WsApiClient};
+//! # tokio_test::block_on(async {
+//! let client = WsApiClient::connect(Some("api_key".into()), Some("secret_key".into()), &Config::testnet())
+//!     .await
+//!     .unwrap();
+//! let order = OrderRequest {
+//!     symbol: "BTCUSDT".to_string(),
+//!     quantity: Some("0.001".parse().unwrap()),
+//!     price: Some("20000.0".parse().unwrap()),
+//!     order_type: OrderType::Limit,
+//!     side: OrderSide::Buy,
+//!     time_in_force: Some(TimeInForce::GTC),
+//!     ..OrderRequest::default()
+//! };
+//! let transaction = client.place_order(order).await.unwrap();
+//! println!("{transaction:?}, weight used {:?}", client.rate_limits());
+//! # });
+//! ```
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex as StdMutex};
+use std::time::Duration;
+
+use futures::channel::oneshot;
+use futures::future::{self, Either};
+use futures::lock::Mutex;
+use futures::stream::{SplitSink, SplitStream};
+use futures::{SinkExt, StreamExt};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::account::{OrderCancellation, OrderRequest, OrderStatusRequest};
+use crate::client::handle_content_error;
+use crate::config::{Config, Product};
+use crate::credentials::ApiKeys;
+use crate::errors::*;
+use crate::rest_model::{AccountInformation, Order, OrderCanceled, RateLimitInterval, RateLimitType, ServerTime,
+                        Transaction};
+use crate::runtime;
+use crate::time_sync::TimeSync;
+use crate::util::get_timestamp;
+use crate::ws_transport::{connect_websocket, Message, WsConnection};
+
+/// Request envelope
+#[derive(Serialize, Debug)]
+struct WsApiRequest<'a> {
+    id: u64,
+    method: &'a str,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    params: BTreeMap<String, String>,
+}
+
+/// Reply envelope, `result` is set on success and `error` on failure
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct WsApiResponse<T> {
+    pub id: Option<u64>,
+    pub status: u16,
+    pub result: Option<T>,
+    pub error: Option<BinanceContentError>,
+    /// Usage of the rate limits affected by the request
+    #[serde(default)]
+    pub rate_limits: Vec<WsApiRateLimit>,
+}
+
+/// Rate limit usage reported with every reply
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WsApiRateLimit {
+    pub rate_limit_type: RateLimitType,
+    pub interval: RateLimitInterval,
+    pub interval_num: i32,
+    pub limit: i32,
+    /// Current usage in the interval
+    pub count: i32,
+}
+
+type Reply = Result<WsApiResponse<Value>>;
+
+/// Time to wait for a reply when the configuration has no timeout
+static DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+struct Inner {
+    sink: Mutex<SplitSink<WsConnection, Message>>,
+    stream: Mutex<SplitStream<WsConnection>>,
+    pending: StdMutex<HashMap<u64, oneshot::Sender<Reply>>>,
+    rate_limits: StdMutex<Vec<WsApiRateLimit>>,
+    last_id: AtomicU64,
+}
+
+/// Websocket API client, cheap to clone
+#[derive(Clone)]
+pub struct WsApiClient {
+    inner: Arc<Inner>,
+    keys: ApiKeys,
+    time_sync: Option<TimeSync>,
+    pub recv_window: u64,
+    /// Time to wait for the reply to a request, the timeout of the configuration or 10 seconds
+    pub timeout: Duration,
+}
+
+impl WsApiClient {
+    /// Connect to the websocket API endpoint of the configuration
+    /// Credentials are only required for signed requests
+    pub async fn connect(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Result<Self> {
+        config.environment.check(Product::WsApi)?;
+        let socket = connect_websocket(config, &config.ws_api_endpoint)
+            .await
+            .map_err(|e| Error::Msg(format!("Error during handshake {e}")))?;
+        let (sink, stream) = socket.split();
+        Ok(WsApiClient {
+            inner: Arc::new(Inner {
+                sink: Mutex::new(sink),
+                stream: Mutex::new(stream),
+                pending: StdMutex::new(HashMap::new()),
+                rate_limits: StdMutex::new(vec![]),
+                last_id: AtomicU64::new(0),
+            }),
+            keys: ApiKeys::new(api_key, secret_key, config),
+            time_sync: config.time_sync.clone(),
+            recv_window: config.recv_window,
+            timeout: config.timeout.map_or(DEFAULT_TIMEOUT, Duration::from_secs),
+        })
+    }
+
+    /// Rate limit usage reported by the last reply
+    pub fn rate_limits(&self) -> Vec<WsApiRateLimit> { self.inner.rate_limits.lock().unwrap().clone() }
+
+    /// Send a request with `params` and wait for its result
+    pub async fn request<T: DeserializeOwned, P: serde::Serialize>(
+        &self,
+        method: &str,
+        params: Option<P>,
+    ) -> Result<T> {
+        let params = params.map(to_params).transpose()?.unwrap_or_default();
+        self.send(method, params).await
+    }
+
+    /// Send a request with `params`, the api key, a timestamp and a signature, and wait for its result
+    ///
+    /// With the time synchronization of the configuration, the server clock is synchronized first
+    /// when due and a rejected timestamp is retried once after synchronizing again.
+    pub async fn signed_request<T: DeserializeOwned, P: serde::Serialize>(
+        &self,
+        method: &str,
+        params: Option<P>,
+    ) -> Result<T> {
+        let params = params.map(to_params).transpose()?.unwrap_or_default();
+        let Some(time_sync) = &self.time_sync else {
+            return self.send_signed(method, params).await;
+        };
+        if time_sync.is_due() {
+            let _ = time_sync.sync(|| self.server_time()).await;
+        }
+        match self.send_signed(method, params.clone()).await {
+            Err(Error::BinanceError { response })
+                if response.error_code() == Some(BinanceErrorCode::InvalidTimestamp) =>
+            {
+                time_sync.invalidate();
+                if time_sync.sync(|| self.server_time()).await.is_err() {
+                    return Err(Error::BinanceError { response });
+                }
+                self.send_signed(method, params).await
+            }
+            result => result,
+        }
+    }
+
+    async fn send_signed<T: DeserializeOwned>(&self, method: &str, mut params: BTreeMap<String, String>) -> Result<T> {
+        let timestamp = match &self.time_sync {
+            Some(time_sync) => time_sync.now()?,
+            None => get_timestamp()?,
+        };
+        params.insert("apiKey".to_string(), self.keys.api_key()?.expose().to_string());
+        params.insert("timestamp".to_string(), timestamp.to_string());
+        if self.recv_window > 0 {
+            params
+                .entry("recvWindow".to_string())
+                .or_insert_with(|| self.recv_window.to_string());
+        }
+        // The signature payload is the query string of the parameters sorted by name
+        let payload = params
+            .iter()
+            .map(|(k, v)| format!("{k}={v}"))
+            .collect::<Vec<_>>()
+            .join("&");
+        params.insert("signature".to_string(), self.keys.signer()?.sign(&payload)?);
+        self.send(method, params).await
+    }
+
+    async fn send<T: DeserializeOwned>(&self, method: &str, params: BTreeMap<String, String>) -> Result<T> {
+        let id = self.inner.last_id.fetch_add(1, Ordering::Relaxed) + 1;
+        let request = serde_json::to_string(&WsApiRequest { id, method, params })?;
+        let (tx, rx) = oneshot::channel();
+        self.inner.pending.lock().unwrap().insert(id, tx);
+        if let Err(e) = self.inner.sink.lock().await.send(Message::Text(request)).await {
+            self.inner.pending.lock().unwrap().remove(&id);
+            return Err(e);
+        }
+        let response = match future::select(Box::pin(self.wait(id, rx)), Box::pin(runtime::sleep(self.timeout))).await {
+            Either::Left((reply, _)) => reply?,
+            Either::Right(_) => {
+                self.inner.pending.lock().unwrap().remove(&id);
+                return Err(Error::Msg(format!("No reply to {method} within {:?}", self.timeout)));
+            }
+        };
+        *self.inner.rate_limits.lock().unwrap() = response.rate_limits;
+        match (response.result, response.error) {
+            (_, Some(error)) => Err(handle_content_error(error)),
+            (Some(result), None) => Ok(serde_json::from_value(result)?),
+            (None, None) => Err(Error::Msg(format!("Empty reply with status {}", response.status))),
+        }
+    }
+
+    /// Wait for the reply to request `id`, reading the connection while no other request does
+    async fn wait(&self, id: u64, rx: oneshot::Receiver<Reply>) -> Reply {
+        let (mut stream, mut rx) = match future::select(rx, self.inner.stream.lock()).await {
+            Either::Left((reply, _)) => return reply.map_err(|_| disconnected())?,
+            Either::Right(locked) => locked,
+        };
+        // The reply may have been read by the previous reader
+        if let Ok(Some(reply)) = rx.try_recv() {
+            return reply;
+        }
+        loop {
+            let text = match stream.next().await {
+                Some(Ok(Message::Text(text))) => text,
+                Some(Ok(Message::Close(_))) | None => {
+                    tracing::warn!("websocket API connection closed");
+                    self.fail_pending();
+                    return Err(disconnected());
+                }
+                Some(Ok(_)) => continue,
+                Some(Err(e)) => {
+                    tracing::warn!(error = %e, "websocket API connection failed");
+                    self.fail_pending();
+                    return Err(e);
+                }
+            };
+            // Anything else than a reply, such as event messages, is ignored
+            let Ok(response) = serde_json::from_str::<WsApiResponse<Value>>(&text) else {
+                continue;
+            };
+            let sender = {
+                let mut pending = self.inner.pending.lock().unwrap();
+                match response.id {
+                    Some(reply_id) => pending.remove(&reply_id).map(|sender| (reply_id, sender)),
+                    // Requests rejected before their id is read are answered without id, which can only
+                    // be matched when a single request is in flight
+                    None if pending.len() == 1 => pending.drain().next(),
+                    None => {
+                        tracing::warn!(
+                            pending = pending.len(),
+                            "websocket API reply without id to one of several requests"
+                        );
+                        None
+                    }
+                }
+            };
+            match sender {
+                Some((reply_id, _)) if reply_id == id => return Ok(response),
+                Some((_, sender)) => {
+                    let _ = sender.send(Ok(response));
+                }
+                None => {}
+            }
+        }
+    }
+
+    fn fail_pending(&self) {
+        for (_, sender) in self.inner.pending.lock().unwrap().drain() {
+            let _ = sender.send(Err(disconnected()));
+        }
+    }
+
+    /// Close the connection
+    pub async fn disconnect(&self) -> Result<()> {
+        self.inner.sink.lock().await.close().await?;
+        Ok(())
+    }
+
+    /// Test connectivity
+    pub async fn ping(&self) -> Result<Value> { self.request::<_, ()>("ping", None).await }
+
+    /// Server time
+    pub async fn server_time(&self) -> Result<ServerTime> { self.request::<_, ()>("time", None).await }
+
+    /// Place an order
+    pub async fn place_order(&self, order: OrderRequest) -> Result<Transaction> {
+        order.valid()?;
+        self.signed_request("order.place", Some(order)).await
+    }
+
+    /// Place a test order, validated but not sent to the matching engine
+    pub async fn place_test_order(&self, order: OrderRequest) -> Result<Value> {
+        order.valid()?;
+        self.signed_request("order.test", Some(order)).await
+    }
+
+    /// Cancel an active order
+    pub async fn cancel_order(&self, o: OrderCancellation) -> Result<OrderCanceled> {
+        self.signed_request("order.cancel", Some(o)).await
+    }
+
+    /// Check an order's status
+    pub async fn order_status(&self, osr: OrderStatusRequest) -> Result<Order> {
+        self.signed_request("order.status", Some(osr)).await
+    }
+
+    /// Current open orders for ONE symbol
+    pub async fn get_open_orders<S>(&self, symbol: S) -> Result<Vec<Order>>
+    where
+        S: AsRef<str>,
+    {
+        self.signed_request("openOrders.status", Some(HashMap::from([("symbol", symbol.as_ref())])))
+            .await
+    }
+
+    /// General account information
+    pub async fn get_account(&self) -> Result<AccountInformation> {
+        self.signed_request::<_, ()>("account.status", None).await
+    }
+}
+
+/// Parameters as they would be sent in a rest query string, without the empty values of unset options
+fn to_params<P: serde::Serialize>(params: P) -> Result<BTreeMap<String, String>> {
+    let query = qs::to_string(&params)?;
+    Ok(url::form_urlencoded::parse(query.as_bytes())
+        .into_owned()
+        .filter(|(_, v)| !v.is_empty())
+        .collect())
+}
+
+fn disconnected() -> Error { Error::Msg("Websocket API connection closed".to_string()) }
@@ -1,5 +1,5 @@
-use crate::rest_model::{string_or_float, Asks, Bids, ExecutionType, OrderBook, OrderSide, OrderStatus, OrderType,
-                        TimeInForce};
+use crate::rest_model::{string_or_float, Asks, Bids, Decimal, ExecutionType, OrderBook, OrderSide, OrderStatus,
+                        OrderType, TimeInForce};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "e")]
@@ -28,9 +28,52 @@ pub enum WebsocketEvent {
     MarkPriceUpdate(Box<MarkPriceEvent>),
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+/// The connection of [`WebSockets`](crate::websockets::WebSockets) was re-established following the
+/// [`ReconnectPolicy`](crate::websockets::ReconnectPolicy), the streams were subscribed to again, see
+/// [`WebSockets::on_reconnect`](crate::websockets::WebSockets::on_reconnect)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReconnectedEvent {
+    /// Attempts it took to reconnect
+    pub attempts: u32,
+    /// Streams subscribed to after the reconnection
+    pub streams: Vec<String>,
+}
+
+/// Reply to a [`StreamRequest`]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct QueryResult {
-    pub result: Option<String>,
+    /// null for most methods, the list of streams for `LIST_SUBSCRIPTIONS`, the value for `GET_PROPERTY`
+    #[serde(default)]
+    pub result: Option<serde_json::Value>,
+    pub id: i64,
+    /// Set if the request was rejected
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<QueryError>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct QueryError {
+    pub code: i32,
+    pub msg: String,
+}
+
+/// Methods to manage the streams of an open connection
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum StreamMethod {
+    Subscribe,
+    Unsubscribe,
+    ListSubscriptions,
+    SetProperty,
+    GetProperty,
+}
+
+/// Request sent over an open stream connection, answered by a [`QueryResult`] with the same id
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct StreamRequest {
+    pub method: StreamMethod,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub params: Vec<serde_json::Value>,
     pub id: i64,
 }
 
@@ -194,32 +237,32 @@ pub struct Kline {
     pub last_trade_id: i64,
     #[serde(with = "string_or_float")]
     #[serde(rename = "o")]
-    pub open: f64,
+    pub open: Decimal,
     #[serde(with = "string_or_float")]
     #[serde(rename = "c")]
-    pub close: f64,
+    pub close: Decimal,
     #[serde(with = "string_or_float")]
     #[serde(rename = "h")]
-    pub high: f64,
+    pub high: Decimal,
     #[serde(with = "string_or_float")]
     #[serde(rename = "l")]
-    pub low: f64,
+    pub low: Decimal,
     #[serde(with = "string_or_float")]
     #[serde(rename = "v")]
-    pub volume: f64,
+    pub volume: Decimal,
     #[serde(rename = "n")]
     pub number_of_trades: i64,
     #[serde(rename = "x")]
     pub is_final_bar: bool,
     #[serde(with = "string_or_float")]
     #[serde(rename = "q")]
-    pub quote_volume: f64,
+    pub quote_volume: Decimal,
     #[serde(with = "string_or_float")]
     #[serde(rename = "V")]
-    pub active_buy_volume: f64,
+    pub active_buy_volume: Decimal,
     #[serde(with = "string_or_float")]
     #[serde(rename = "Q")]
-    pub active_volume_buy_quote: f64,
+    pub active_volume_buy_quote: Decimal,
     #[serde(skip, rename = "B")]
     pub ignore_me: String,
 }
@@ -235,6 +278,9 @@ pub struct DepthOrderBookEvent {
     pub first_update_id: u64,
     #[serde(rename = "u")]
     pub final_update_id: u64,
+    /// Final update id of the previous event, only sent by futures streams
+    #[serde(rename = "pu", default)]
+    pub previous_final_update_id: Option<u64>,
     #[serde(rename = "b")]
     pub bids: Vec<Bids>,
     #[serde(rename = "a")]
@@ -251,16 +297,16 @@ pub struct BookTickerEvent {
     pub symbol: String,
 
     #[serde(rename = "b", with = "string_or_float")]
-    pub best_bid: f64,
+    pub best_bid: Decimal,
 
     #[serde(rename = "B", with = "string_or_float")]
-    pub best_bid_qty: f64,
+    pub best_bid_qty: Decimal,
 
     #[serde(rename = "a", with = "string_or_float")]
-    pub best_ask: f64,
+    pub best_ask: Decimal,
 
     #[serde(rename = "A", with = "string_or_float")]
-    pub best_ask_qty: f64,
+    pub best_ask_qty: Decimal,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -367,10 +413,10 @@ pub struct EventBalance {
     pub asset: String,
     #[serde(rename = "f")]
     #[serde(with = "string_or_float")]
-    pub free: f64,
+    pub free: Decimal,
     #[serde(rename = "l")]
     #[serde(with = "string_or_float")]
-    pub locked: f64,
+    pub locked: Decimal,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -384,7 +430,7 @@ pub struct BalanceUpdate {
 
     #[serde(rename = "d")]
     #[serde(with = "string_or_float")]
-    pub delta: f64,
+    pub delta: Decimal,
 
     #[serde(alias = "T")]
     pub clear_time: u64,
@@ -407,16 +453,16 @@ pub struct OrderUpdate {
     pub time_in_force: TimeInForce,
     #[serde(rename = "q")]
     #[serde(with = "string_or_float")]
-    pub qty: f64,
+    pub qty: Decimal,
     #[serde(rename = "p")]
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Decimal,
     #[serde(rename = "P")]
     #[serde(with = "string_or_float")]
-    pub stop_price: f64,
+    pub stop_price: Decimal,
     #[serde(rename = "F")]
     #[serde(with = "string_or_float")]
-    pub iceberg_qty: f64,
+    pub iceberg_qty: Decimal,
     #[serde(rename = "g")]
     pub order_list_id: i64,
     #[serde(rename = "C")]
@@ -431,16 +477,16 @@ pub struct OrderUpdate {
     pub order_id: u64,
     #[serde(rename = "l")]
     #[serde(with = "string_or_float")]
-    pub qty_last_executed: f64,
+    pub qty_last_executed: Decimal,
     #[serde(rename = "z")]
     #[serde(with = "string_or_float")]
-    pub cumulative_filled_qty: f64,
+    pub cumulative_filled_qty: Decimal,
     #[serde(rename = "L")]
     #[serde(with = "string_or_float")]
-    pub last_executed_price: f64,
+    pub last_executed_price: Decimal,
     #[serde(rename = "n")]
     #[serde(with = "string_or_float")]
-    pub commission: f64,
+    pub commission: Decimal,
     #[serde(rename = "N")]
     pub commission_asset: Option<String>,
     #[serde(rename = "T")]
@@ -459,14 +505,14 @@ pub struct OrderUpdate {
     pub order_creation_time: u64,
     #[serde(rename = "Z")]
     #[serde(with = "string_or_float")]
-    pub cumulative_quote_asset_transacted_qty: f64,
+    pub cumulative_quote_asset_transacted_qty: Decimal,
     /// (i.e. lastPrice * lastQty)
     #[serde(rename = "Y")]
     #[serde(with = "string_or_float")]
-    pub last_quote_asset_transacted_qty: f64,
+    pub last_quote_asset_transacted_qty: Decimal,
     #[serde(rename = "Q")]
     #[serde(with = "string_or_float")]
-    pub quote_order_qty: f64,
+    pub quote_order_qty: Decimal,
 }
 
 /// For OCO Events
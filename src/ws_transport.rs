@@ -0,0 +1,298 @@
+//! Websocket connections
+//!
+//! Websockets are connected with tokio-tungstenite under the `tokio-runtime` feature, or with
+//! async-tungstenite over async-net and native-tls under the `smol-runtime` feature. Either way
+//! they are exposed as a [`WsConnection`] of [`Message`], so the public API does not depend on the
+//! websocket implementation.
+
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures::{Sink, Stream};
+use tracing::Instrument;
+
+use crate::config::Config;
+use crate::errors::*;
+
+/// Websocket frame
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Message {
+    Text(String),
+    Binary(Vec<u8>),
+    Ping(Vec<u8>),
+    Pong(Vec<u8>),
+    Close(Option<CloseFrame>),
+}
+
+/// Reason of a close frame
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CloseFrame {
+    /// Status code, 1000 for a normal closure
+    pub code: u16,
+    pub reason: String,
+}
+
+trait Socket: Stream<Item = Result<Message>> + Sink<Message, Error = Error> + Send {}
+
+impl<T: Stream<Item = Result<Message>> + Sink<Message, Error = Error> + Send> Socket for T {}
+
+/// Websocket connection, a [`Stream`] of received frames and a [`Sink`] of frames to send
+pub struct WsConnection {
+    socket: Pin<Box<dyn Socket>>,
+}
+
+impl WsConnection {
+    fn new<S: Socket + 'static>(socket: S) -> Self {
+        WsConnection {
+            socket: Box::pin(socket),
+        }
+    }
+}
+
+impl Stream for WsConnection {
+    type Item = Result<Message>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.socket.as_mut().poll_next(cx)
+    }
+}
+
+impl Sink<Message> for WsConnection {
+    type Error = Error;
+
+    fn poll_ready(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        self.socket.as_mut().poll_ready(cx)
+    }
+
+    fn start_send(mut self: Pin<&mut Self>, item: Message) -> Result<()> { self.socket.as_mut().start_send(item) }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        self.socket.as_mut().poll_flush(cx)
+    }
+
+    fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        self.socket.as_mut().poll_close(cx)
+    }
+}
+
+/// Convert between [`Message`] and the frames of a tungstenite version
+macro_rules! tungstenite_frames {
+    ($tungstenite:path) => {
+        use $tungstenite as tungstenite;
+
+        pub(super) fn from_tungstenite(message: tungstenite::Message) -> Option<Message> {
+            Some(match message {
+                tungstenite::Message::Text(text) => Message::Text(text.to_string()),
+                tungstenite::Message::Binary(data) => Message::Binary(data.to_vec()),
+                tungstenite::Message::Ping(data) => Message::Ping(data.to_vec()),
+                tungstenite::Message::Pong(data) => Message::Pong(data.to_vec()),
+                tungstenite::Message::Close(frame) => Message::Close(frame.map(|frame| CloseFrame {
+                    code: frame.code.into(),
+                    reason: frame.reason.to_string(),
+                })),
+                // Raw frames are only used to send
+                tungstenite::Message::Frame(_) => return None,
+            })
+        }
+
+        pub(super) fn into_tungstenite(message: Message) -> tungstenite::Message {
+            match message {
+                Message::Text(text) => tungstenite::Message::Text(text.into()),
+                Message::Binary(data) => tungstenite::Message::Binary(data.into()),
+                Message::Ping(data) => tungstenite::Message::Ping(data.into()),
+                Message::Pong(data) => tungstenite::Message::Pong(data.into()),
+                Message::Close(frame) => {
+                    tungstenite::Message::Close(frame.map(|frame| tungstenite::protocol::CloseFrame {
+                        code: frame.code.into(),
+                        reason: frame.reason.into(),
+                    }))
+                }
+            }
+        }
+
+        fn ws_error(e: tungstenite::Error) -> Error { Error::WebSocket(Box::new(e)) }
+
+        /// Adapt a tungstenite socket to [`Message`] and [`Error`]
+        fn adapt<S>(socket: S) -> WsConnection
+        where
+            S: Stream<Item = std::result::Result<tungstenite::Message, tungstenite::Error>>
+                + Sink<tungstenite::Message, Error = tungstenite::Error>
+                + Send
+                + 'static,
+        {
+            use futures::{future, SinkExt, StreamExt};
+            WsConnection::new(
+                socket
+                    .sink_map_err(ws_error)
+                    .with(|message| future::ready(Ok(into_tungstenite(message))))
+                    .filter_map(|message| future::ready(message.map(from_tungstenite).map_err(ws_error).transpose())),
+            )
+        }
+    };
+}
+
+#[cfg(feature = "tokio-runtime")]
+mod backend {
+    use super::*;
+
+    tungstenite_frames!(tokio_tungstenite::tungstenite);
+
+    pub(super) async fn connect(conf: &Config, url: &str) -> Result<WsConnection> {
+        let (socket, _) = match &conf.proxy {
+            None => tokio_tungstenite::connect_async(url).await,
+            Some(proxy) => {
+                let (host, port) = host_and_port(url)?;
+                let stream = proxy.connect(&host, port).await?;
+                tokio_tungstenite::client_async_tls(url, stream.into_inner()).await
+            }
+        }
+        .map_err(ws_error)?;
+        Ok(adapt(socket))
+    }
+}
+
+#[cfg(all(feature = "smol-runtime", not(feature = "tokio-runtime")))]
+mod backend {
+    use super::*;
+    use crate::runtime;
+
+    tungstenite_frames!(async_tungstenite::tungstenite);
+
+    pub(super) async fn connect(conf: &Config, url: &str) -> Result<WsConnection> {
+        let (host, port) = host_and_port(url)?;
+        let stream = match &conf.proxy {
+            None => runtime::connect_tcp(&host, port).await?,
+            Some(proxy) => proxy.connect(&host, port).await?,
+        };
+        if url.starts_with("wss:") {
+            let stream = async_native_tls::connect(host.as_str(), stream)
+                .await
+                .map_err(|e| Error::WebSocket(Box::new(e)))?;
+            let (socket, _) = async_tungstenite::client_async(url, stream).await.map_err(ws_error)?;
+            Ok(adapt(socket))
+        } else {
+            let (socket, _) = async_tungstenite::client_async(url, stream).await.map_err(ws_error)?;
+            Ok(adapt(socket))
+        }
+    }
+}
+
+fn host_and_port(url: &str) -> Result<(String, u16)> {
+    let parsed = url::Url::parse(url)?;
+    let host = parsed
+        .host_str()
+        .ok_or_else(|| Error::Msg(format!("missing host in {url}")))?;
+    Ok((host.to_string(), parsed.port_or_known_default().unwrap_or(443)))
+}
+
+/// `url` with the listen keys it may contain masked, for logs
+///
+/// Listen keys are the only stream names without `@`, they are masked in the path following `/ws/`
+/// and in the `streams` of combined streams.
+pub(crate) fn redact_url(url: &str) -> String {
+    let Ok(mut parsed) = url::Url::parse(url) else {
+        return "<invalid url>".to_string();
+    };
+    let mut after_ws = false;
+    let path = parsed
+        .path()
+        .split('/')
+        .map(|segment| {
+            let redacted = if after_ws { redact_stream(segment) } else { segment };
+            after_ws |= segment == "ws";
+            redacted
+        })
+        .collect::<Vec<_>>()
+        .join("/");
+    parsed.set_path(&path);
+    let query = parsed.query().map(|query| {
+        query
+            .split('&')
+            .map(|param| match param.split_once('=') {
+                Some(("streams", streams)) => {
+                    format!(
+                        "streams={}",
+                        streams.split('/').map(redact_stream).collect::<Vec<_>>().join("/")
+                    )
+                }
+                _ => param.to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join("&")
+    });
+    parsed.set_query(query.as_deref());
+    parsed.to_string()
+}
+
+fn redact_stream(stream: &str) -> &str {
+    if stream.is_empty() || stream.contains('@') {
+        stream
+    } else {
+        "***"
+    }
+}
+
+/// Connect a websocket to `url`, through the proxy of the configuration if any
+pub(crate) async fn connect_websocket(conf: &Config, url: &str) -> Result<WsConnection> {
+    let span = tracing::debug_span!(
+        "binance.ws.connect",
+        url = %redact_url(url),
+        proxy = conf.proxy.is_some()
+    );
+    async {
+        let result = backend::connect(conf, url).await;
+        match &result {
+            Ok(_) => tracing::debug!("websocket connected"),
+            Err(e) => tracing::warn!(error = %e, "websocket connection failed"),
+        }
+        result
+    }
+    .instrument(span)
+    .await
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn frames_round_trip() {
+        let frames = [
+            Message::Text("{}".to_string()),
+            Message::Ping(vec![1, 2]),
+            Message::Close(Some(CloseFrame {
+                code: 1000,
+                reason: "bye".to_string(),
+            })),
+        ];
+        for frame in frames {
+            assert_eq!(
+                backend::from_tungstenite(backend::into_tungstenite(frame.clone())),
+                Some(frame)
+            );
+        }
+    }
+
+    #[test]
+    fn redacted_urls() {
+        let listen_key = "pqia91ma19a5s61cv6a81va65sdf19v8a65a1a5s61cv6a81va65sdf19v8a65a1";
+        assert_eq!(
+            redact_url("wss://stream.binance.com:9443/ws/btcusdt@trade"),
+            "wss://stream.binance.com:9443/ws/btcusdt@trade"
+        );
+        assert_eq!(
+            redact_url(&format!("wss://stream.binance.com:9443/ws/{listen_key}")),
+            "wss://stream.binance.com:9443/ws/***"
+        );
+        assert_eq!(
+            redact_url(&format!(
+                "wss://stream.binance.com:9443/stream?streams=btcusdt@trade/{listen_key}/!ticker@arr"
+            )),
+            "wss://stream.binance.com:9443/stream?streams=btcusdt@trade/***/!ticker@arr"
+        );
+        assert_eq!(
+            redact_url("wss://ws-api.binance.com:443/ws-api/v3"),
+            "wss://ws-api.binance.com/ws-api/v3"
+        );
+    }
+}
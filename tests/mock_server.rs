@@ -0,0 +1,550 @@
+#![cfg(all(feature = "testing", feature = "futures_api"))]
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use binance::account::*;
+use binance::api::*;
+use binance::config::{Config, Environment};
+use binance::credentials::{CallbackCredentials, Credentials, SharedCredentials};
+use binance::errors::Error;
+use binance::failover::Failover;
+use binance::filters::SymbolFilters;
+use binance::futures::account::{FuturesAccount, OrderRequest as FuturesOrderRequest};
+use binance::general::General;
+use binance::market::Market;
+use binance::rate_limit::RetryPolicy;
+use binance::rest_model::*;
+use binance::testing::*;
+use binance::time_sync::TimeSync;
+use binance::userstream::UserStream;
+use binance::websockets::{trade_stream, ReconnectPolicy, WebSockets, WebsocketStream};
+use binance::ws_api::WsApiClient;
+use binance::ws_model::WebsocketEvent;
+use futures::StreamExt;
+use serde_json::json;
+
+fn account(server: &MockServer) -> Account {
+    Binance::new_with_config(
+        Some(MOCK_API_KEY.into()),
+        Some(MOCK_SECRET_KEY.into()),
+        &server.config(),
+    )
+}
+
+fn limit_order(price: &str) -> OrderRequest {
+    OrderRequest {
+        symbol: "BTCUSDT".to_string(),
+        quantity: Some("0.001".parse().unwrap()),
+        price: Some(price.parse().unwrap()),
+        order_type: OrderType::Limit,
+        side: OrderSide::Buy,
+        time_in_force: Some(TimeInForce::GTC),
+        ..OrderRequest::default()
+    }
+}
+
+#[tokio::test]
+async fn spot_order_lifecycle() {
+    let server = MockServer::start().await.unwrap();
+    let general: General = Binance::new_with_config(None, None, &server.config());
+    let info = general.exchange_info().await.unwrap();
+    let filters = SymbolFilters::from(&info.symbols[0]);
+    assert!(filters.check(&limit_order("19000.00"), None).is_ok());
+
+    let account = account(&server);
+    let placed = account.place_order(limit_order("19000.00")).await.unwrap();
+    assert_eq!(placed.status, OrderStatus::New);
+    let open = account.get_open_orders("BTCUSDT").await.unwrap();
+    assert_eq!(open.len(), 1);
+    let status = account
+        .order_status(OrderStatusRequest {
+            symbol: "BTCUSDT".to_string(),
+            order_id: Some(placed.order_id),
+            ..OrderStatusRequest::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(status.client_order_id, placed.client_order_id);
+    account
+        .cancel_order(OrderCancellation {
+            symbol: "BTCUSDT".to_string(),
+            order_id: Some(placed.order_id),
+            ..OrderCancellation::default()
+        })
+        .await
+        .unwrap();
+    assert!(account.get_open_orders("BTCUSDT").await.unwrap().is_empty());
+    assert_eq!(account.get_account().await.unwrap().balances.len(), 2);
+
+    let market: Market = Binance::new_with_config(None, None, &server.config());
+    server.set_price("BTCUSDT", "30000.00");
+    let price = market.get_price("BTCUSDT").await.unwrap();
+    assert_eq!(price.price, "30000.00".parse::<Decimal>().unwrap());
+}
+
+#[tokio::test]
+async fn rejects_invalid_signatures_and_timestamps() {
+    let server = MockServer::start().await.unwrap();
+    let wrong_key: Account =
+        Binance::new_with_config(Some(MOCK_API_KEY.into()), Some("wrong".into()), &server.config());
+    match wrong_key.get_account().await {
+        Err(Error::BinanceError { response }) => assert_eq!(response.code, -1022),
+        other => panic!("{other:?}"),
+    }
+
+    server.set_clock_offset(-60_000);
+    match account(&server).get_account().await {
+        Err(Error::BinanceError { response }) => assert_eq!(response.code, -1021),
+        other => panic!("{other:?}"),
+    }
+    let conf = server.config().set_time_sync(TimeSync::default());
+    let synced: Account = Binance::new_with_config(Some(MOCK_API_KEY.into()), Some(MOCK_SECRET_KEY.into()), &conf);
+    assert!(synced.get_account().await.is_ok());
+}
+
+#[tokio::test]
+async fn scripted_responses_and_user_stream() {
+    let server = MockServer::start().await.unwrap();
+    server.enqueue(
+        reqwest::Method::POST,
+        "/api/v3/order",
+        MockResponse::error(400, -2010, "Account has insufficient balance for requested action."),
+    );
+    match account(&server).place_order(limit_order("19000.00")).await {
+        Err(Error::BinanceError { response }) => assert_eq!(response.code, -2010),
+        other => panic!("{other:?}"),
+    }
+
+    let user_stream: UserStream = Binance::new_with_config(Some(MOCK_API_KEY.into()), None, &server.config());
+    let listen_key = user_stream.start().await.unwrap().listen_key;
+    user_stream.keep_alive(&listen_key).await.unwrap();
+    user_stream.close(&listen_key).await.unwrap();
+    assert!(user_stream.keep_alive(&listen_key).await.is_err());
+}
+
+#[tokio::test]
+async fn rate_limited_responses() {
+    let server = MockServer::start().await.unwrap();
+    let market: Market = Binance::new_with_config(None, None, &server.config());
+    server.enqueue(
+        reqwest::Method::GET,
+        "/api/v3/ticker/price",
+        MockResponse::error(
+            429,
+            -1003,
+            "Too many requests; current limit of IP is 6000 request weight per 1 MINUTE.",
+        )
+        .header("Retry-After", "7"),
+    );
+    match market.get_price("BTCUSDT").await {
+        Err(Error::RateLimited { retry_after, response }) => {
+            assert_eq!(retry_after, Some(Duration::from_secs(7)));
+            assert_eq!(response.unwrap().code, -1003);
+        }
+        other => panic!("{other:?}"),
+    }
+    server.enqueue(
+        reqwest::Method::GET,
+        "/api/v3/ticker/price",
+        MockResponse::error(418, -1003, "Way too many requests; IP banned until 1659146400000.")
+            .header("Retry-After", "600"),
+    );
+    match market.get_price("BTCUSDT").await {
+        Err(Error::IpBanned { until, .. }) => assert_eq!(until, Some(1659146400000)),
+        other => panic!("{other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn retries_rate_limited_get_requests() {
+    let server = MockServer::start().await.unwrap();
+    let conf = server.config().set_retry_policy(RetryPolicy {
+        max_retries: 2,
+        max_delay: Duration::from_secs(1),
+        default_delay: Duration::from_millis(10),
+    });
+    let account: Account = Binance::new_with_config(Some(MOCK_API_KEY.into()), Some(MOCK_SECRET_KEY.into()), &conf);
+    let too_many_requests = || MockResponse::error(429, -1003, "Too many requests.");
+    let count = |path: &str| server.requests().iter().filter(|r| r.path == path).count();
+
+    server.enqueue(reqwest::Method::GET, "/api/v3/account", too_many_requests());
+    server.enqueue(
+        reqwest::Method::GET,
+        "/api/v3/account",
+        too_many_requests().header("Retry-After", "0"),
+    );
+    assert!(account.get_account().await.is_ok());
+    assert_eq!(count("/api/v3/account"), 3);
+
+    for _ in 0..3 {
+        server.enqueue(reqwest::Method::GET, "/api/v3/openOrders", too_many_requests());
+    }
+    assert!(matches!(
+        account.get_open_orders("BTCUSDT").await,
+        Err(Error::RateLimited { .. })
+    ));
+    assert_eq!(count("/api/v3/openOrders"), 3);
+
+    // Bans longer than the maximum delay and requests other than GET are not retried
+    server.enqueue(
+        reqwest::Method::GET,
+        "/api/v3/ticker/price",
+        MockResponse::error(418, -1003, "Way too many requests; IP banned until 4102444800000."),
+    );
+    let market: Market = Binance::new_with_config(None, None, &conf);
+    assert!(matches!(market.get_price("BTCUSDT").await, Err(Error::IpBanned { .. })));
+    assert_eq!(count("/api/v3/ticker/price"), 1);
+    server.enqueue(reqwest::Method::POST, "/api/v3/order", too_many_requests());
+    assert!(account.place_order(limit_order("19000.00")).await.is_err());
+    assert_eq!(count("/api/v3/order"), 1);
+}
+
+#[tokio::test]
+async fn futures_orders() {
+    let server = MockServer::start().await.unwrap();
+    let account: FuturesAccount = Binance::new_with_config(
+        Some(MOCK_API_KEY.into()),
+        Some(MOCK_SECRET_KEY.into()),
+        &server.config(),
+    );
+    let order = FuturesOrderRequest {
+        symbol: "BTCUSDT".to_string(),
+        side: OrderSide::Sell,
+        order_type: binance::futures::rest_model::OrderType::Market,
+        quantity: Some("0.01".parse().unwrap()),
+        ..FuturesOrderRequest::default()
+    };
+    let transaction = account.place_order(order).await.unwrap();
+    assert_eq!(transaction.status, OrderStatus::Filled);
+    assert!(account.get_open_orders("BTCUSDT").await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn streams_events() {
+    let server = MockServer::start().await.unwrap();
+    let mut stream: WebsocketStream<WebsocketEvent> =
+        WebsocketStream::connect(&server.config(), &trade_stream("btcusdt"))
+            .await
+            .unwrap();
+    let trade = json!({
+        "e": "trade", "E": 1, "s": "BTCUSDT", "t": 1, "p": "20000.00", "q": "0.1", "b": 1, "a": 2, "T": 1, "m": true, "M": true
+    });
+    // The connection is registered once the handshake is handled by the server
+    while server.push_event(&trade_stream("btcusdt"), &trade) == 0 {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    match stream.next().await {
+        Some(Ok(WebsocketEvent::Trade(trade))) => assert_eq!(trade.symbol, "BTCUSDT"),
+        other => panic!("{other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn notifies_reconnections() {
+    let server = MockServer::start().await.unwrap();
+    let conf = server.config().set_ws_reconnect_policy(ReconnectPolicy {
+        initial_backoff: Duration::from_millis(10),
+        max_backoff: Duration::from_millis(100),
+        max_attempts: Some(5),
+        jitter: false,
+    });
+    let running = Arc::new(AtomicBool::new(true));
+    let reconnected = Arc::new(std::sync::Mutex::new(None));
+    let mut websockets: WebSockets<'_, WebsocketEvent> = WebSockets::new_with_options(|_| Ok(()), conf).on_reconnect({
+        let running = running.clone();
+        let reconnected = reconnected.clone();
+        move |event| {
+            *reconnected.lock().unwrap() = Some(event);
+            running.store(false, Ordering::Relaxed);
+            Ok(())
+        }
+    });
+    websockets.connect(&trade_stream("btcusdt")).await.unwrap();
+    let trade = json!({
+        "e": "trade", "E": 1, "s": "BTCUSDT", "t": 1, "p": "20000.00", "q": "0.1", "b": 1, "a": 2, "T": 1, "m": true, "M": true
+    });
+    while server.push_event(&trade_stream("btcusdt"), &trade) == 0 {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    server.disconnect_all();
+    tokio::time::timeout(Duration::from_secs(5), websockets.event_loop(&running))
+        .await
+        .unwrap()
+        .unwrap();
+    let event = reconnected.lock().unwrap().take().unwrap();
+    assert_eq!(event.attempts, 1);
+    assert_eq!(event.streams, vec![trade_stream("btcusdt")]);
+}
+
+#[tokio::test]
+async fn streams_coin_futures_events() {
+    let server = MockServer::start().await.unwrap();
+    let conf = server.config().set_coin_futures_ws_endpoint(server.ws_url());
+    let mut stream: WebsocketStream<WebsocketEvent> =
+        WebsocketStream::connect_coin_futures(&conf, &trade_stream("btcusd_perp"))
+            .await
+            .unwrap();
+    let trade = json!({
+        "e": "trade", "E": 1, "s": "BTCUSD_PERP", "t": 1, "p": "20000.00", "q": "1", "b": 1, "a": 2, "T": 1, "m": true, "M": true
+    });
+    while server.push_event(&trade_stream("btcusd_perp"), &trade) == 0 {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    match stream.next().await {
+        Some(Ok(WebsocketEvent::Trade(trade))) => assert_eq!(trade.symbol, "BTCUSD_PERP"),
+        other => panic!("{other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn facade_handles_share_state() {
+    let server = MockServer::start().await.unwrap();
+    server.set_clock_offset(-60_000);
+    let time_sync = TimeSync::default();
+    let binance = BinanceClient::new_with_config(
+        Some(MOCK_API_KEY.into()),
+        Some(MOCK_SECRET_KEY.into()),
+        &server.config().set_time_sync(time_sync.clone()),
+    );
+    assert!(binance.account().get_account().await.is_ok());
+    assert!(time_sync.offset().is_some());
+    assert!(binance
+        .futures_account()
+        .get_open_orders("BTCUSDT")
+        .await
+        .unwrap()
+        .is_empty());
+    assert_eq!(binance.market().get_price("BTCUSDT").await.unwrap().symbol, "BTCUSDT");
+}
+
+#[tokio::test]
+async fn response_metadata() {
+    let server = MockServer::start().await.unwrap();
+    for (weight, trace_id) in [("7", "b0b7e5e0"), ("8", "c1c8f6f1")] {
+        server.enqueue(
+            reqwest::Method::GET,
+            "/api/v3/ticker/price",
+            MockResponse::json(200, &json!({ "symbol": "BTCUSDT", "price": "20000.00" }))
+                .header("X-MBX-USED-WEIGHT-1M", weight)
+                .header("X-MBX-UUID", trace_id),
+        );
+    }
+    let market: Market = Binance::new_with_config(None, None, &server.config());
+    let clone = market.clone();
+    // Concurrent calls of clones of a handle each get the metadata of their own response
+    let ((first, first_meta), (second, second_meta)) = futures::join!(
+        with_meta(market.get_price("BTCUSDT")),
+        with_meta(clone.get_price("BTCUSDT"))
+    );
+    assert!(first.is_ok() && second.is_ok());
+    let (first_meta, second_meta) = (first_meta.unwrap(), second_meta.unwrap());
+    assert_eq!(first_meta.status, reqwest::StatusCode::OK);
+    let mut weights = [first_meta.used_weight["1m"], second_meta.used_weight["1m"]];
+    weights.sort();
+    assert_eq!(weights, [7, 8]);
+    let trace_id = if first_meta.used_weight["1m"] == 7 {
+        "b0b7e5e0"
+    } else {
+        "c1c8f6f1"
+    };
+    assert_eq!(first_meta.trace_id.as_deref(), Some(trace_id));
+}
+
+#[tokio::test]
+async fn refuses_endpoints_unsupported_by_the_environment() {
+    let server = MockServer::start().await.unwrap();
+    let conf = Config {
+        environment: Environment::DataOnly,
+        ..server.config()
+    };
+    let market: Market = Binance::new_with_config(None, None, &conf);
+    assert!(market.get_price("BTCUSDT").await.is_ok());
+    let account: Account = Binance::new_with_config(Some(MOCK_API_KEY.into()), Some(MOCK_SECRET_KEY.into()), &conf);
+    assert!(matches!(
+        account.get_account().await,
+        Err(Error::UnsupportedEndpoint { .. })
+    ));
+    let futures: FuturesAccount =
+        Binance::new_with_config(Some(MOCK_API_KEY.into()), Some(MOCK_SECRET_KEY.into()), &Config {
+            environment: Environment::BinanceUs,
+            ..server.config()
+        });
+    assert!(matches!(
+        futures.get_open_orders("BTCUSDT").await,
+        Err(Error::UnsupportedEndpoint { .. })
+    ));
+    assert!(server.requests().iter().all(|r| r.path == "/api/v3/ticker/price"));
+}
+
+#[tokio::test]
+async fn fails_over_without_resending_orders() {
+    let primary = MockServer::start().await.unwrap();
+    let secondary = MockServer::start().await.unwrap();
+    let failover = Failover::new([primary.rest_url(), secondary.rest_url()], Duration::from_secs(30));
+    let account: Account = Binance::new_with_config(
+        Some(MOCK_API_KEY.into()),
+        Some(MOCK_SECRET_KEY.into()),
+        &primary.config().set_failover(failover.clone()),
+    );
+
+    primary.enqueue(
+        reqwest::Method::POST,
+        "/api/v3/order",
+        MockResponse::error(503, -1007, "Timeout waiting for response from backend server."),
+    );
+    assert!(matches!(
+        account.place_order(limit_order("19000.00")).await,
+        Err(Error::ServiceUnavailable)
+    ));
+    assert!(secondary.requests().is_empty());
+    assert!(!failover.is_healthy(&primary.rest_url()));
+
+    // The primary host is set aside, requests go to the secondary host
+    assert!(account.get_open_orders("BTCUSDT").await.unwrap().is_empty());
+    assert_eq!(secondary.requests().len(), 1);
+
+    failover.mark_up(&primary.rest_url());
+    primary.enqueue(
+        reqwest::Method::GET,
+        "/api/v3/account",
+        MockResponse::error(500, -1000, "An unknown error occurred while processing the request."),
+    );
+    assert!(account.get_account().await.is_ok());
+    assert_eq!(secondary.requests().last().unwrap().path, "/api/v3/account");
+}
+
+#[tokio::test]
+async fn rotates_credentials() {
+    let server = MockServer::start().await.unwrap();
+    let credentials = SharedCredentials::new(CallbackCredentials::new(|| {
+        Ok(Credentials::new(MOCK_API_KEY, MOCK_SECRET_KEY))
+    }));
+    let account: Account = Binance::new_with_config(None, None, &server.config().set_credentials(credentials.clone()));
+    assert!(account.get_account().await.is_ok());
+
+    server.set_credentials("rotated_api_key", "rotated_secret_key");
+    assert!(account.get_account().await.is_err());
+    credentials.rotate(Credentials::new("rotated_api_key", "rotated_secret_key"));
+    assert!(account.get_account().await.is_ok());
+}
+
+async fn ws_api(conf: &Config) -> WsApiClient {
+    WsApiClient::connect(Some(MOCK_API_KEY.into()), Some(MOCK_SECRET_KEY.into()), conf)
+        .await
+        .unwrap()
+}
+
+fn requests_to(server: &MockServer, path: &str) -> usize {
+    server.requests().iter().filter(|request| request.path == path).count()
+}
+
+#[tokio::test]
+async fn ws_api_requests() {
+    let server = MockServer::start().await.unwrap();
+    let client = ws_api(&server.config()).await;
+    client.ping().await.unwrap();
+    let transaction = client.place_order(limit_order("19000.00")).await.unwrap();
+    assert_eq!(transaction.status, OrderStatus::New);
+    assert_eq!(
+        client.get_open_orders("BTCUSDT").await.unwrap()[0].order_id,
+        transaction.order_id
+    );
+    let request = server
+        .requests()
+        .into_iter()
+        .find(|r| r.method == reqwest::Method::POST)
+        .unwrap();
+    assert_eq!(request.path, "/api/v3/order");
+    assert_eq!(request.param("apiKey"), Some(MOCK_API_KEY));
+
+    // Requests rejected without id are matched to the only request in flight
+    server.enqueue_ws_api(
+        "ping",
+        &json!({ "id": null, "status": 400, "error": { "code": -1000, "msg": "Malformed request." } }),
+    );
+    match client.ping().await {
+        Err(Error::BinanceError { response }) => assert_eq!(response.code, -1000),
+        other => panic!("{other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn ws_api_concurrent_requests() {
+    let server = MockServer::start().await.unwrap();
+    let client = ws_api(&server.config()).await;
+    server.hold_ws_api_replies(3);
+    let (time, account, ping) = futures::join!(client.server_time(), client.get_account(), client.ping());
+    assert!(time.unwrap().server_time > 0);
+    assert_eq!(account.unwrap().balances.len(), 2);
+    assert!(ping.is_ok());
+}
+
+#[tokio::test]
+async fn ws_api_disconnections_and_timeouts() {
+    let server = MockServer::start().await.unwrap();
+    let client = ws_api(&server.config()).await;
+    server.hold_ws_api_replies(3);
+    let disconnect = async {
+        while requests_to(&server, "/api/v3/ping") < 2 {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        server.disconnect_all();
+    };
+    let (first, second, _) = futures::join!(client.ping(), client.ping(), disconnect);
+    assert!(first.is_err() && second.is_err());
+
+    let mut client = ws_api(&server.config()).await;
+    client.timeout = Duration::from_millis(100);
+    server.hold_ws_api_replies(2);
+    assert!(client.ping().await.is_err());
+}
+
+#[tokio::test]
+async fn ws_api_retries_rejected_timestamps() {
+    let server = MockServer::start().await.unwrap();
+    let client = ws_api(&server.config().set_time_sync(TimeSync::default())).await;
+    assert!(client.get_account().await.is_ok());
+    assert_eq!(requests_to(&server, "/api/v3/time"), 1);
+
+    // The clock is synchronized again when the server rejects the timestamp
+    server.set_clock_offset(-60_000);
+    assert!(client.get_account().await.is_ok());
+    assert_eq!(requests_to(&server, "/api/v3/time"), 2);
+    assert_eq!(requests_to(&server, "/api/v3/account"), 3);
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn blocking_handles() {
+    use binance::blocking;
+
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let server = runtime.block_on(MockServer::start()).unwrap();
+    let account: blocking::Account = Binance::new_with_config(
+        Some(MOCK_API_KEY.into()),
+        Some(MOCK_SECRET_KEY.into()),
+        &server.config(),
+    );
+    let placed = account.place_order(limit_order("19000.00")).unwrap();
+    assert_eq!(account.get_open_orders("BTCUSDT").unwrap()[0].order_id, placed.order_id);
+    server.set_price("BTCUSDT", "30000.00");
+    let market: blocking::Market = Binance::new_with_config(None, None, &server.config());
+    assert_eq!(
+        market.get_price("BTCUSDT").unwrap().price,
+        "30000.00".parse::<Decimal>().unwrap()
+    );
+    let (price, meta) = blocking::with_meta(|| market.get_price("BTCUSDT"));
+    assert!(price.is_ok());
+    assert_eq!(meta.unwrap().status, reqwest::StatusCode::OK);
+    // Handles are used from several threads at once
+    std::thread::scope(|scope| {
+        let threads: Vec<_> = (0..4)
+            .map(|_| scope.spawn(|| market.clone().get_price("BTCUSDT").unwrap()))
+            .collect();
+        for thread in threads {
+            assert_eq!(thread.join().unwrap().symbol, "BTCUSDT");
+        }
+    });
+}